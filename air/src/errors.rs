@@ -1,3 +1,5 @@
+use miden_core::Felt;
+
 use crate::trace::MIN_TRACE_LEN;
 
 // EXECUTION OPTIONS ERROR
@@ -14,3 +16,14 @@ pub enum ExecutionOptionsError {
     )]
     MaxCycleNumTooSmall(u32),
 }
+
+// TRACE ERROR
+// ================================================================================================
+
+#[derive(Debug, thiserror::Error)]
+pub enum TraceError {
+    #[error("invalid hasher chiplet address 0: hasher chiplet addresses start at 1")]
+    ZeroHasherAddress,
+    #[error("address {addr} does not refer to the first or last row of a hash cycle")]
+    InvalidHashCycleAddress { addr: Felt },
+}
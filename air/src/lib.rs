@@ -40,7 +40,7 @@ mod utils;
 // RE-EXPORTS
 // ================================================================================================
 
-pub use errors::ExecutionOptionsError;
+pub use errors::{ExecutionOptionsError, TraceError};
 pub use miden_core::{
     Felt, FieldElement, StarkField,
     utils::{DeserializationError, ToElements},
@@ -1,3 +1,4 @@
+use miden_core::Word;
 use winter_air::BatchingMethod;
 
 use super::{
@@ -67,6 +68,21 @@ impl ProvingOptions {
         BatchingMethod::Horner,
     );
 
+    /// Minimal proof parameters for quickly generating proofs in tests.
+    ///
+    /// Proofs generated with these parameters provide almost no security, and are accepted only
+    /// when the verifier is explicitly instructed to accept them.
+    pub const TESTING: WinterProofOptions = WinterProofOptions::new(
+        4,
+        8,
+        0,
+        FieldExtension::Quadratic,
+        8,
+        255,
+        BatchingMethod::Algebraic,
+        BatchingMethod::Algebraic,
+    );
+
     // CONSTRUCTORS
     // --------------------------------------------------------------------------------------------
 
@@ -156,6 +172,23 @@ impl ProvingOptions {
         }
     }
 
+    /// Creates a new preset instance of [ProvingOptions] which generates proofs as fast as possible
+    /// using the [ProvingOptions::TESTING] parameters and the BLAKE3 hash function.
+    ///
+    /// # Security
+    /// The resulting proofs are valid, but their conjectured security level is negligible. This
+    /// preset must only be used in tests; proofs generated with it are rejected by
+    /// `miden_verifier::verify()`, and can be verified only via
+    /// `miden_verifier::verify_with_options()` with the [ProvingOptions::TESTING] parameters
+    /// listed as acceptable.
+    pub fn for_testing() -> Self {
+        Self {
+            exec_options: ExecutionOptions::default(),
+            proof_options: Self::TESTING,
+            hash_fn: HashFunction::Blake3_192,
+        }
+    }
+
     /// Sets [ExecutionOptions] for this [ProvingOptions].
     ///
     /// This sets the maximum number of cycles a program is allowed to execute as well as
@@ -198,12 +231,20 @@ impl From<ProvingOptions> for WinterProofOptions {
 ///
 /// - `max_cycles` specifies the maximum number of cycles a program is allowed to execute.
 /// - `expected_cycles` specifies the number of cycles a program is expected to execute.
+/// - `max_stack_depth` specifies the maximum depth the operand stack is allowed to reach.
+/// - `max_advice_pops` specifies the maximum number of elements which can be popped from the advice
+///   stack.
+/// - `advice_seed` specifies the seed of the pseudo-random elements backing the advice stack.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct ExecutionOptions {
     max_cycles: u32,
     expected_cycles: u32,
+    max_stack_depth: usize,
+    max_advice_pops: Option<usize>,
+    advice_seed: Option<Word>,
     enable_tracing: bool,
     enable_debugging: bool,
+    enable_profiling: bool,
 }
 
 impl Default for ExecutionOptions {
@@ -211,8 +252,12 @@ impl Default for ExecutionOptions {
         ExecutionOptions {
             max_cycles: u32::MAX,
             expected_cycles: MIN_TRACE_LEN as u32,
+            max_stack_depth: usize::MAX,
+            max_advice_pops: None,
+            advice_seed: None,
             enable_tracing: false,
             enable_debugging: false,
+            enable_profiling: false,
         }
     }
 }
@@ -248,8 +293,12 @@ impl ExecutionOptions {
         Ok(ExecutionOptions {
             max_cycles,
             expected_cycles,
+            max_stack_depth: usize::MAX,
+            max_advice_pops: None,
+            advice_seed: None,
             enable_tracing,
             enable_debugging,
+            enable_profiling: false,
         })
     }
 
@@ -271,6 +320,44 @@ impl ExecutionOptions {
         self
     }
 
+    /// Enables recording the number of cycles spent in each call frame entered by a program.
+    ///
+    /// Call frames are entered by procedures invoked via `call`, `syscall`, `dyncall` or `dynexec`;
+    /// procedures invoked via `exec` are attributed to the frame from which they were invoked.
+    pub fn with_profiling(mut self) -> Self {
+        self.enable_profiling = true;
+        self
+    }
+
+    /// Sets the maximum depth the operand stack is allowed to reach during execution.
+    ///
+    /// The depth includes the items stored in the overflow tables of all execution contexts. By
+    /// default, the depth of the stack is not limited.
+    pub fn with_max_stack_depth(mut self, max_stack_depth: usize) -> Self {
+        self.max_stack_depth = max_stack_depth;
+        self
+    }
+
+    /// Sets the maximum total number of elements which can be popped from the advice stack during
+    /// execution.
+    ///
+    /// Once the limit is reached, any further attempt to pop elements from the advice stack
+    /// results in an error. By default, the number of pops is not limited.
+    pub fn with_max_advice_pops(mut self, max_advice_pops: usize) -> Self {
+        self.max_advice_pops = Some(max_advice_pops);
+        self
+    }
+
+    /// Backs the advice stack with deterministic pseudo-random elements derived from `seed`.
+    ///
+    /// Whenever the advice stack does not contain enough elements to satisfy a read, the missing
+    /// elements are drawn from a random coin seeded with `seed` instead of failing. This is useful
+    /// for fuzzing programs without having to construct their advice inputs.
+    pub fn with_advice_seed(mut self, seed: Word) -> Self {
+        self.advice_seed = Some(seed);
+        self
+    }
+
     // PUBLIC ACCESSORS
     // --------------------------------------------------------------------------------------------
 
@@ -288,6 +375,22 @@ impl ExecutionOptions {
         self.expected_cycles
     }
 
+    /// Returns the maximum depth the operand stack is allowed to reach during execution.
+    pub fn max_stack_depth(&self) -> usize {
+        self.max_stack_depth
+    }
+
+    /// Returns the maximum total number of elements which can be popped from the advice stack
+    /// during execution, or `None` if the number of pops is not limited.
+    pub fn max_advice_pops(&self) -> Option<usize> {
+        self.max_advice_pops
+    }
+
+    /// Returns the seed of the pseudo-random elements backing the advice stack, if any.
+    pub fn advice_seed(&self) -> Option<Word> {
+        self.advice_seed
+    }
+
     /// Returns a flag indicating whether the VM should execute `trace` instructions.
     pub fn enable_tracing(&self) -> bool {
         self.enable_tracing
@@ -297,4 +400,10 @@ impl ExecutionOptions {
     pub fn enable_debugging(&self) -> bool {
         self.enable_debugging
     }
+
+    /// Returns a flag indicating whether the VM should record the number of cycles spent in each
+    /// procedure invoked by a program.
+    pub fn enable_profiling(&self) -> bool {
+        self.enable_profiling
+    }
 }
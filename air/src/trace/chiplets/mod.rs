@@ -9,6 +9,7 @@ pub mod bitwise;
 pub mod hasher;
 pub mod kernel_rom;
 pub mod memory;
+pub mod utils;
 // CONSTANTS
 // ================================================================================================
 
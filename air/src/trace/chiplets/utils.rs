@@ -0,0 +1,148 @@
+use miden_core::{Felt, ONE};
+
+use super::hasher::HASH_CYCLE_LEN;
+use crate::{RowIndex, TraceError};
+
+// CHIPLETS BUS HELPERS
+// ================================================================================================
+
+/// Returns the unique label of a chiplet operation, given the values of the chiplet selector
+/// columns at the row where the operation is performed.
+///
+/// The label is computed as 1 plus the selector bits in reverse order, i.e.
+/// `s3 * 8 + s2 * 4 + s1 * 2 + s0 + 1`. For example, the hasher labels (e.g.,
+/// [`LINEAR_HASH_LABEL`](super::hasher::LINEAR_HASH_LABEL)) are the result of this function for
+/// `s0 = 0` and the hasher selectors in `s1..s3`.
+#[inline(always)]
+pub fn get_op_label(s0: Felt, s1: Felt, s2: Felt, s3: Felt) -> Felt {
+    s3.mul_small(1 << 3) + s2.mul_small(1 << 2) + s1.mul_small(2) + s0 + ONE
+}
+
+/// Returns the index of the hasher chiplet row with the specified address.
+///
+/// Addresses of the hasher chiplet start at 1, i.e., the row at index `i` has address `i + 1`.
+///
+/// # Panics
+/// Panics if `addr` is zero.
+pub fn addr_to_row_index(addr: Felt) -> RowIndex {
+    let addr = addr.as_int();
+    assert!(addr > 0, "hasher chiplet addresses start at 1");
+    RowIndex::from(addr as usize - 1)
+}
+
+/// Returns the index of the hash cycle containing the hasher chiplet row with the specified
+/// address.
+///
+/// # Panics
+/// Panics if `addr` is not a valid address (see [`try_addr_to_hash_cycle`]).
+pub fn addr_to_hash_cycle(addr: Felt) -> usize {
+    try_addr_to_hash_cycle(addr).unwrap_or_else(|err| panic!("{err}"))
+}
+
+/// Returns the index of the hash cycle containing the hasher chiplet row with the specified
+/// address.
+///
+/// # Errors
+/// Returns an error if `addr` is zero, or if the row with the specified address is neither the
+/// first nor the last row of its hash cycle, since the hasher chiplet only sends or receives
+/// messages at those rows.
+pub fn try_addr_to_hash_cycle(addr: Felt) -> Result<usize, TraceError> {
+    let row = addr.as_int().checked_sub(1).ok_or(TraceError::ZeroHasherAddress)? as usize;
+    let cycle_row = row % HASH_CYCLE_LEN;
+    if cycle_row != 0 && cycle_row != HASH_CYCLE_LEN - 1 {
+        return Err(TraceError::InvalidHashCycleAddress { addr });
+    }
+
+    Ok(row / HASH_CYCLE_LEN)
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use miden_core::{Felt, ONE, ZERO};
+
+    use super::{
+        HASH_CYCLE_LEN, addr_to_hash_cycle, addr_to_row_index, get_op_label, try_addr_to_hash_cycle,
+    };
+    use crate::{
+        RowIndex, TraceError,
+        trace::chiplets::{
+            bitwise::{BITWISE_AND_LABEL, BITWISE_XOR_LABEL},
+            hasher::{
+                LINEAR_HASH, LINEAR_HASH_LABEL, MP_VERIFY, MP_VERIFY_LABEL, MR_UPDATE_NEW,
+                MR_UPDATE_NEW_LABEL, MR_UPDATE_OLD, MR_UPDATE_OLD_LABEL, RETURN_HASH,
+                RETURN_HASH_LABEL, RETURN_STATE, RETURN_STATE_LABEL, Selectors,
+            },
+        },
+    };
+
+    #[test]
+    fn op_labels() {
+        let hasher_label = |[s1, s2, s3]: Selectors| get_op_label(ZERO, s1, s2, s3);
+        assert_eq!(hasher_label(LINEAR_HASH), Felt::from(LINEAR_HASH_LABEL));
+        assert_eq!(hasher_label(MP_VERIFY), Felt::from(MP_VERIFY_LABEL));
+        assert_eq!(hasher_label(MR_UPDATE_OLD), Felt::from(MR_UPDATE_OLD_LABEL));
+        assert_eq!(hasher_label(MR_UPDATE_NEW), Felt::from(MR_UPDATE_NEW_LABEL));
+        assert_eq!(hasher_label(RETURN_HASH), Felt::from(RETURN_HASH_LABEL));
+        assert_eq!(hasher_label(RETURN_STATE), Felt::from(RETURN_STATE_LABEL));
+
+        assert_eq!(get_op_label(ONE, ZERO, ZERO, ZERO), BITWISE_AND_LABEL);
+        assert_eq!(get_op_label(ONE, ZERO, ONE, ZERO), BITWISE_XOR_LABEL);
+    }
+
+    #[test]
+    fn addr_to_row_index_offsets_by_one() {
+        assert_eq!(addr_to_row_index(ONE), RowIndex::from(0_u32));
+        assert_eq!(addr_to_row_index(Felt::new(HASH_CYCLE_LEN as u64)), RowIndex::from(7_u32));
+    }
+
+    #[test]
+    #[should_panic(expected = "hasher chiplet addresses start at 1")]
+    fn addr_to_row_index_zero_address() {
+        addr_to_row_index(ZERO);
+    }
+
+    #[test]
+    fn addr_to_hash_cycle_first_and_last_rows() {
+        let len = HASH_CYCLE_LEN as u64;
+
+        // the first and last rows of the first cycle
+        assert_eq!(addr_to_hash_cycle(Felt::new(1)), 0);
+        assert_eq!(addr_to_hash_cycle(Felt::new(len)), 0);
+
+        // the first and last rows of the third cycle
+        assert_eq!(addr_to_hash_cycle(Felt::new(2 * len + 1)), 2);
+        assert_eq!(addr_to_hash_cycle(Felt::new(3 * len)), 2);
+    }
+
+    #[test]
+    fn try_addr_to_hash_cycle_rejects_invalid_addresses() {
+        let len = HASH_CYCLE_LEN as u64;
+        assert_eq!(try_addr_to_hash_cycle(Felt::new(1)).unwrap(), 0);
+        assert_eq!(try_addr_to_hash_cycle(Felt::new(2 * len)).unwrap(), 1);
+
+        assert!(matches!(try_addr_to_hash_cycle(ZERO), Err(TraceError::ZeroHasherAddress)));
+        for addr in 2..len {
+            let addr = Felt::new(addr);
+            assert!(matches!(
+                try_addr_to_hash_cycle(addr),
+                Err(TraceError::InvalidHashCycleAddress { addr: invalid }) if invalid == addr
+            ));
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "does not refer to the first or last row of a hash cycle")]
+    fn addr_to_hash_cycle_inner_row() {
+        // the second row of the first cycle
+        addr_to_hash_cycle(Felt::new(2));
+    }
+
+    #[test]
+    #[should_panic(expected = "does not refer to the first or last row of a hash cycle")]
+    fn addr_to_hash_cycle_penultimate_row() {
+        addr_to_hash_cycle(Felt::new(HASH_CYCLE_LEN as u64 - 1));
+    }
+}
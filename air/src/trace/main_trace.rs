@@ -616,4 +616,183 @@ impl MainTrace {
             && self.chiplet_selector_2(i) == ONE
             && self.chiplet_selector_3(i) == ONE
     }
+
+    // CHECKED ACCESSORS
+    // --------------------------------------------------------------------------------------------
+    // The accessors above panic if the requested row is out of bounds, e.g. when reading row
+    // `i + 1` at the last row of the trace. The accessors below return `None` instead.
+
+    /// Returns true if row i is within the bounds of this trace.
+    pub fn contains_row(&self, i: RowIndex) -> bool {
+        i.as_usize() < self.num_rows()
+    }
+
+    /// Returns the index of the row following row i, or `None` if row i is the last row of this
+    /// trace (or is out of bounds).
+    pub fn next_row(&self, i: RowIndex) -> Option<RowIndex> {
+        let next = i + 1;
+        self.contains_row(next).then_some(next)
+    }
+
+    /// Returns the value of the clk column at row i, or `None` if row i is out of bounds.
+    pub fn try_clk(&self, i: RowIndex) -> Option<Felt> {
+        self.contains_row(i).then(|| self.clk(i))
+    }
+
+    /// Returns the value of the fmp column at row i, or `None` if row i is out of bounds.
+    pub fn try_fmp(&self, i: RowIndex) -> Option<Felt> {
+        self.contains_row(i).then(|| self.fmp(i))
+    }
+
+    /// Returns the value of the ctx column at row i, or `None` if row i is out of bounds.
+    pub fn try_ctx(&self, i: RowIndex) -> Option<Felt> {
+        self.contains_row(i).then(|| self.ctx(i))
+    }
+
+    /// Returns the value in the block address column at row i, or `None` if row i is out of
+    /// bounds.
+    pub fn try_addr(&self, i: RowIndex) -> Option<Felt> {
+        self.contains_row(i).then(|| self.addr(i))
+    }
+
+    /// Returns the op code at row i, or `None` if row i is out of bounds.
+    pub fn try_get_op_code(&self, i: RowIndex) -> Option<Felt> {
+        self.contains_row(i).then(|| self.get_op_code(i))
+    }
+
+    /// Returns the decoder hasher state at row i, or `None` if row i is out of bounds.
+    pub fn try_decoder_hasher_state(&self, i: RowIndex) -> Option<[Felt; NUM_HASHER_COLUMNS]> {
+        self.contains_row(i).then(|| self.decoder_hasher_state(i))
+    }
+
+    /// Returns the value of the stack depth column at row i, or `None` if row i is out of bounds.
+    pub fn try_stack_depth(&self, i: RowIndex) -> Option<Felt> {
+        self.contains_row(i).then(|| self.stack_depth(i))
+    }
+
+    /// Returns the element at row i in a given stack trace column, or `None` if row i is out of
+    /// bounds.
+    pub fn try_stack_element(&self, column: usize, i: RowIndex) -> Option<Felt> {
+        self.contains_row(i).then(|| self.stack_element(column, i))
+    }
+
+    /// Returns the address of the top element in the stack overflow table at row i, or `None` if
+    /// row i is out of bounds.
+    pub fn try_parent_overflow_address(&self, i: RowIndex) -> Option<Felt> {
+        self.contains_row(i).then(|| self.parent_overflow_address(i))
+    }
+
+    /// Returns the chiplet hasher state at row i, or `None` if row i is out of bounds.
+    pub fn try_chiplet_hasher_state(&self, i: RowIndex) -> Option<[Felt; STATE_WIDTH]> {
+        self.contains_row(i).then(|| self.chiplet_hasher_state(i))
+    }
+
+    // DEBUGGING
+    // --------------------------------------------------------------------------------------------
+
+    /// Writes the clock cycle, the op code of the executed operation and the top 16 stack elements
+    /// of the specified rows to `writer` in CSV format, preceded by a header row.
+    ///
+    /// Rows beyond the end of the trace are ignored.
+    #[cfg(feature = "std")]
+    pub fn write_debug_csv<W: std::io::Write>(
+        &self,
+        mut writer: W,
+        rows: Range<usize>,
+    ) -> std::io::Result<()> {
+        use miden_core::stack::MIN_STACK_DEPTH;
+
+        write!(writer, "clk,op_code")?;
+        for i in 0..MIN_STACK_DEPTH {
+            write!(writer, ",s{i}")?;
+        }
+        writeln!(writer)?;
+
+        for row in rows.start..rows.end.min(self.num_rows()) {
+            let i = RowIndex::from(row);
+            write!(writer, "{},{}", self.clk(i).as_int(), self.get_op_code(i).as_int())?;
+            for column in 0..MIN_STACK_DEPTH {
+                write!(writer, ",{}", self.stack_element(column, i).as_int())?;
+            }
+            writeln!(writer)?;
+        }
+
+        Ok(())
+    }
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use alloc::{string::String, vec::Vec};
+
+    use miden_core::{Felt, ONE, ZERO};
+
+    use super::{
+        super::{CLK_COL_IDX, DECODER_TRACE_OFFSET, STACK_TRACE_OFFSET, TRACE_WIDTH},
+        ColMatrix, MainTrace,
+    };
+    use crate::RowIndex;
+
+    #[test]
+    fn write_debug_csv() {
+        let num_rows = 8;
+        let mut columns = vec![vec![ZERO; num_rows]; TRACE_WIDTH];
+        columns[CLK_COL_IDX] = (0..num_rows as u32).map(Felt::from).collect();
+        // the operation at row 2 has op code 0b101, and the stack starts with [7, 3]
+        columns[DECODER_TRACE_OFFSET + 1][2] = ONE;
+        columns[DECODER_TRACE_OFFSET + 3][2] = ONE;
+        columns[STACK_TRACE_OFFSET][2] = Felt::new(7);
+        columns[STACK_TRACE_OFFSET + 1][2] = Felt::new(3);
+        let trace = MainTrace::new(ColMatrix::new(columns), 7u32.into());
+
+        let mut csv = Vec::new();
+        trace.write_debug_csv(&mut csv, 1..3).unwrap();
+        let csv = String::from_utf8(csv).unwrap();
+        let lines = csv.lines().collect::<Vec<_>>();
+
+        assert_eq!(lines[0], "clk,op_code,s0,s1,s2,s3,s4,s5,s6,s7,s8,s9,s10,s11,s12,s13,s14,s15");
+        assert_eq!(lines[1], "1,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0");
+        assert_eq!(lines[2], "2,5,7,3,0,0,0,0,0,0,0,0,0,0,0,0,0,0");
+        assert_eq!(lines.len(), 3);
+
+        // rows beyond the end of the trace are ignored
+        let mut csv = Vec::new();
+        trace.write_debug_csv(&mut csv, 6..100).unwrap();
+        assert_eq!(String::from_utf8(csv).unwrap().lines().count(), 3);
+    }
+
+    #[test]
+    fn checked_accessors() {
+        let num_rows = 8;
+        let mut columns = vec![vec![ZERO; num_rows]; TRACE_WIDTH];
+        columns[CLK_COL_IDX] = (0..num_rows as u32).map(Felt::from).collect();
+        columns[STACK_TRACE_OFFSET + 2] = vec![Felt::new(5); num_rows];
+        let trace = MainTrace::new(ColMatrix::new(columns), 7u32.into());
+
+        let last_row = RowIndex::from(num_rows - 1);
+        let past_end = RowIndex::from(num_rows);
+
+        // rows within the trace are readable
+        assert!(trace.contains_row(last_row));
+        assert_eq!(trace.try_clk(last_row), Some(Felt::new(7)));
+        assert_eq!(trace.try_stack_element(2, last_row), Some(Felt::new(5)));
+        assert_eq!(trace.next_row(last_row - 1), Some(last_row));
+
+        // the row following the last one is not
+        assert!(!trace.contains_row(past_end));
+        assert_eq!(trace.next_row(last_row), None);
+        assert_eq!(trace.try_clk(past_end), None);
+        assert_eq!(trace.try_fmp(past_end), None);
+        assert_eq!(trace.try_ctx(past_end), None);
+        assert_eq!(trace.try_addr(past_end), None);
+        assert_eq!(trace.try_get_op_code(past_end), None);
+        assert_eq!(trace.try_decoder_hasher_state(past_end), None);
+        assert_eq!(trace.try_stack_depth(past_end), None);
+        assert_eq!(trace.try_stack_element(2, past_end), None);
+        assert_eq!(trace.try_parent_overflow_address(past_end), None);
+        assert_eq!(trace.try_chiplet_hasher_state(past_end), None);
+    }
 }
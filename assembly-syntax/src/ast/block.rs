@@ -36,6 +36,23 @@ impl Block {
         self.body.len()
     }
 
+    /// Returns the number of instructions in this block, including those in nested blocks.
+    ///
+    /// NOTE: Control flow ops (e.g. `if.true`) are not instructions themselves, and the body of a
+    /// `repeat` block is counted once, regardless of the number of iterations.
+    pub fn num_instructions(&self) -> usize {
+        self.body
+            .iter()
+            .map(|op| match op {
+                Op::If { then_blk, else_blk, .. } => {
+                    then_blk.num_instructions() + else_blk.num_instructions()
+                },
+                Op::While { body, .. } | Op::Repeat { body, .. } => body.num_instructions(),
+                Op::Inst(_) => 1,
+            })
+            .sum()
+    }
+
     /// Returns true if this block is empty
     pub fn is_empty(&self) -> bool {
         self.body.is_empty()
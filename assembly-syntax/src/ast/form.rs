@@ -2,7 +2,7 @@ use alloc::string::String;
 
 use miden_debug_types::{SourceSpan, Span, Spanned};
 
-use super::{AdviceMapEntry, Block, Constant, Export, Import};
+use super::{AdviceMapEntry, Block, Constant, Export, Ident, Import};
 
 /// This type represents the top-level forms of a Miden Assembly module
 #[derive(Debug, PartialEq, Eq)]
@@ -13,6 +13,11 @@ pub enum Form {
     Doc(Span<String>),
     /// An import from another module
     Import(Import),
+    /// An include of another source file, identified by the given path.
+    ///
+    /// Includes are resolved by the parser, which replaces this form with the forms of the
+    /// included file. Semantic analysis rejects any include which was left unresolved.
+    Include(Ident),
     /// A constant definition, possibly unresolved
     Constant(Constant),
     /// An executable block, represents a program entrypoint
@@ -60,6 +65,7 @@ impl Spanned for Form {
             Self::Import(Import { span, .. })
             | Self::Constant(Constant { span, .. })
             | Self::AdviceMapEntry(AdviceMapEntry { span, .. }) => *span,
+            Self::Include(path) => path.span(),
             Self::Begin(spanned) => spanned.span(),
             Self::Procedure(spanned) => spanned.span(),
         }
@@ -3,7 +3,7 @@ use core::fmt;
 
 use miden_debug_types::{SourceSpan, Span, Spanned};
 
-use crate::{Felt, ast::Ident};
+use crate::{Felt, LibraryPath, ast::Ident};
 
 /// An 8-bit unsigned immediate
 pub type ImmU8 = Immediate<u8>;
@@ -31,6 +31,21 @@ pub enum Immediate<T> {
     /// All immediates of this type are folded to `Value` during
     /// semantic analysis, once all constant definitions are evaluated.
     Constant(Ident),
+    /// A reference to a constant defined in an imported module, e.g. `consts::MAX`
+    ///
+    /// The module must be referenced by the name it was imported under in the current module.
+    ///
+    /// All immediates of this type are folded to `Value` during linking, once the module which
+    /// defines the constant is known.
+    ImportedConstant { module: Ident, name: Ident },
+    /// A reference to a constant defined in the module with the given fully-qualified path, e.g.
+    /// `std::math::consts::MAX`
+    ///
+    /// The module does not need to be imported in the current module.
+    ///
+    /// All immediates of this type are folded to `Value` during linking, once the module which
+    /// defines the constant is known.
+    AbsoluteConstantPath { path: LibraryPath, name: Ident },
 }
 
 /// All immediates
@@ -43,6 +58,12 @@ impl<T> Immediate<T> {
     pub fn with_span(self, span: SourceSpan) -> Self {
         match self {
             Self::Constant(id) => Self::Constant(id.with_span(span)),
+            Self::ImportedConstant { module, name } => {
+                Self::ImportedConstant { module, name: name.with_span(span) }
+            },
+            Self::AbsoluteConstantPath { path, name } => {
+                Self::AbsoluteConstantPath { path, name: name.with_span(span) }
+            },
             Self::Value(value) => Self::Value(Span::new(span, value.into_inner())),
         }
     }
@@ -54,6 +75,10 @@ impl<T> Immediate<T> {
     {
         match self {
             Self::Constant(id) => Immediate::Constant(id),
+            Self::ImportedConstant { module, name } => Immediate::ImportedConstant { module, name },
+            Self::AbsoluteConstantPath { path, name } => {
+                Immediate::AbsoluteConstantPath { path, name }
+            },
             Self::Value(value) => Immediate::Value(value.map(map)),
         }
     }
@@ -65,6 +90,12 @@ impl<T: Copy> Immediate<T> {
         match self {
             Self::Value(value) => value.into_inner(),
             Self::Constant(name) => panic!("tried to unwrap unresolved constant: '{name}'"),
+            Self::ImportedConstant { module, name } => {
+                panic!("tried to unwrap unresolved constant: '{module}::{name}'")
+            },
+            Self::AbsoluteConstantPath { path, name } => {
+                panic!("tried to unwrap unresolved constant: '{path}::{name}'")
+            },
         }
     }
 
@@ -72,6 +103,12 @@ impl<T: Copy> Immediate<T> {
         match self {
             Self::Value(value) => *value,
             Self::Constant(name) => panic!("tried to unwrap unresolved constant: '{name}'"),
+            Self::ImportedConstant { module, name } => {
+                panic!("tried to unwrap unresolved constant: '{module}::{name}'")
+            },
+            Self::AbsoluteConstantPath { path, name } => {
+                panic!("tried to unwrap unresolved constant: '{path}::{name}'")
+            },
         }
     }
 }
@@ -81,6 +118,12 @@ impl Immediate<Arc<str>> {
         match self {
             Self::Value(value) => value.clone().into_inner(),
             Self::Constant(name) => panic!("tried to unwrap unresolved constant: '{name}'"),
+            Self::ImportedConstant { module, name } => {
+                panic!("tried to unwrap unresolved constant: '{module}::{name}'")
+            },
+            Self::AbsoluteConstantPath { path, name } => {
+                panic!("tried to unwrap unresolved constant: '{path}::{name}'")
+            },
         }
     }
 }
@@ -89,7 +132,9 @@ impl<T> Spanned for Immediate<T> {
     fn span(&self) -> SourceSpan {
         match self {
             Self::Value(spanned) => spanned.span(),
-            Self::Constant(spanned) => spanned.span(),
+            Self::Constant(spanned)
+            | Self::ImportedConstant { name: spanned, .. }
+            | Self::AbsoluteConstantPath { name: spanned, .. } => spanned.span(),
         }
     }
 }
@@ -111,6 +156,13 @@ impl<T: Clone> Clone for Immediate<T> {
         match self {
             Self::Value(value) => Self::Value(value.clone()),
             Self::Constant(name) => Self::Constant(name.clone()),
+            Self::ImportedConstant { module, name } => Self::ImportedConstant {
+                module: module.clone(),
+                name: name.clone(),
+            },
+            Self::AbsoluteConstantPath { path, name } => {
+                Self::AbsoluteConstantPath { path: path.clone(), name: name.clone() }
+            },
         }
     }
 }
@@ -122,6 +174,14 @@ impl<T: PartialEq> PartialEq for Immediate<T> {
         match (self, other) {
             (Self::Value(l), Self::Value(r)) => l == r,
             (Self::Constant(l), Self::Constant(r)) => l == r,
+            (
+                Self::ImportedConstant { module: lm, name: ln },
+                Self::ImportedConstant { module: rm, name: rn },
+            ) => lm == rm && ln == rn,
+            (
+                Self::AbsoluteConstantPath { path: lp, name: ln },
+                Self::AbsoluteConstantPath { path: rp, name: rn },
+            ) => lp == rp && ln == rn,
             _ => false,
         }
     }
@@ -142,6 +202,12 @@ impl<T: fmt::Debug> fmt::Debug for Immediate<T> {
             Self::Value(value) if f.alternate() => write!(f, "Value({value:#?})"),
             Self::Value(value) => write!(f, "Value({value:?})"),
             Self::Constant(name) => write!(f, "Constant({name})"),
+            Self::ImportedConstant { module, name } => {
+                write!(f, "ImportedConstant({module}::{name})")
+            },
+            Self::AbsoluteConstantPath { path, name } => {
+                write!(f, "AbsoluteConstantPath({path}::{name})")
+            },
         }
     }
 }
@@ -151,6 +217,8 @@ impl<T: fmt::Display> fmt::Display for Immediate<T> {
         match self {
             Self::Value(value) => write!(f, "{value}"),
             Self::Constant(name) => write!(f, "{name}"),
+            Self::ImportedConstant { module, name } => write!(f, "{module}::{name}"),
+            Self::AbsoluteConstantPath { path, name } => write!(f, "{path}::{name}"),
         }
     }
 }
@@ -162,6 +230,8 @@ impl<T: crate::prettier::PrettyPrint> crate::prettier::PrettyPrint for Immediate
         match self {
             Self::Value(value) => value.render(),
             Self::Constant(name) => text(name),
+            Self::ImportedConstant { module, name } => text(format!("{module}::{name}")),
+            Self::AbsoluteConstantPath { path, name } => text(format!("{path}::{name}")),
         }
     }
 }
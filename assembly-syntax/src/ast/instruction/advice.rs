@@ -14,12 +14,17 @@ use miden_core::sys_events::SystemEvent;
 pub enum SystemEventNode {
     PushU64Div,
     PushFalconDiv,
+    PushSha256,
+    PushKeccak256,
+    PushBatchInv,
+    PushRandom,
     PushSmtPeek,
     PushMapVal,
     PushMapValN,
     HasMapKey,
     PushMtNode,
     InsertMem,
+    InsertMemHashed,
     InsertHdword,
     InsertHdwordWithDomain,
     InsertHperm,
@@ -31,12 +36,17 @@ impl From<&SystemEventNode> for SystemEvent {
         match value {
             PushU64Div => Self::U64Div,
             PushFalconDiv => Self::FalconDiv,
+            PushSha256 => Self::Sha256ToStack,
+            PushKeccak256 => Self::Keccak256ToStack,
+            PushBatchInv => Self::BatchInv,
+            PushRandom => Self::DrawRandom,
             PushSmtPeek => Self::SmtPeek,
             PushMapVal => Self::MapValueToStack,
             PushMapValN => Self::MapValueToStackN,
             HasMapKey => Self::HasMapKey,
             PushMtNode => Self::MerkleNodeToStack,
             InsertMem => Self::MemToMap,
+            InsertMemHashed => Self::HashedMemToMap,
             InsertHdword => Self::HdwordToMap,
             InsertHdwordWithDomain => Self::HdwordToMapWithDomain,
             InsertHperm => Self::HpermToMap,
@@ -55,12 +65,17 @@ impl fmt::Display for SystemEventNode {
         match self {
             Self::PushU64Div => write!(f, "push_u64div"),
             Self::PushFalconDiv => write!(f, "push_falcon_div"),
+            Self::PushSha256 => write!(f, "push_sha256"),
+            Self::PushKeccak256 => write!(f, "push_keccak256"),
+            Self::PushBatchInv => write!(f, "push_batch_inv"),
+            Self::PushRandom => write!(f, "push_random"),
             Self::PushSmtPeek => write!(f, "push_smtpeek"),
             Self::PushMapVal => write!(f, "push_mapval"),
             Self::PushMapValN => write!(f, "push_mapvaln"),
             Self::HasMapKey => write!(f, "has_mapkey"),
             Self::PushMtNode => write!(f, "push_mtnode"),
             Self::InsertMem => write!(f, "insert_mem"),
+            Self::InsertMemHashed => write!(f, "insert_mem_hashed"),
             Self::InsertHdword => write!(f, "insert_hdword"),
             Self::InsertHdwordWithDomain => write!(f, "insert_hdword_d"),
             Self::InsertHperm => writeln!(f, "insert_hperm"),
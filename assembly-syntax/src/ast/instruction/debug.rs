@@ -1,5 +1,7 @@
 use core::fmt;
 
+use miden_core::WatchedFieldOp;
+
 use crate::ast::{ImmU8, ImmU16, ImmU32};
 
 // DEBUG OPTIONS
@@ -10,12 +12,14 @@ use crate::ast::{ImmU8, ImmU16, ImmU32};
 pub enum DebugOptions {
     StackAll,
     StackTop(ImmU8),
+    StackHex(ImmU8),
     MemAll,
     MemInterval(ImmU32, ImmU32),
     LocalInterval(ImmU16, ImmU16),
     LocalRangeFrom(ImmU16),
     LocalAll,
     AdvStackTop(ImmU16),
+    WatchField(WatchedFieldOp, ImmU8),
 }
 
 impl crate::prettier::PrettyPrint for DebugOptions {
@@ -29,6 +33,7 @@ impl fmt::Display for DebugOptions {
         match self {
             Self::StackAll => write!(f, "stack"),
             Self::StackTop(n) => write!(f, "stack.{n}"),
+            Self::StackHex(n) => write!(f, "stack.hex.{n}"),
             Self::MemAll => write!(f, "mem"),
             Self::MemInterval(n, m) => write!(f, "mem.{n}.{m}"),
             Self::LocalAll => write!(f, "local"),
@@ -37,6 +42,7 @@ impl fmt::Display for DebugOptions {
                 write!(f, "local.{start}.{end}")
             },
             Self::AdvStackTop(n) => write!(f, "adv_stack.{n}"),
+            Self::WatchField(op, n) => write!(f, "watch_field.{op}.{n}"),
         }
     }
 }
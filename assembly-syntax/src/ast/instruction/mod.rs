@@ -26,6 +26,8 @@ pub enum Instruction {
     AssertEqWithError(ErrorMsg),
     AssertEqw,
     AssertEqwWithError(ErrorMsg),
+    AssertLt(ImmFelt),
+    AssertLtWithError(ImmFelt, ErrorMsg),
     Assertz,
     AssertzWithError(ErrorMsg),
     Add,
@@ -58,6 +60,7 @@ pub enum Instruction {
     Gt,
     Gte,
     IsOdd,
+    IsEven,
 
     // ----- ext2 operations ---------------------------------------------------------------------
     Ext2Add,
@@ -94,6 +97,7 @@ pub enum Instruction {
     U32OverflowingMulImm(ImmU32),
     U32OverflowingMadd,
     U32WrappingMadd,
+    U32CheckedMaddImm(ImmU32, ImmU32),
     U32Div,
     U32DivImm(ImmU32),
     U32Mod,
@@ -123,6 +127,10 @@ pub enum Instruction {
     U32Gte,
     U32Min,
     U32Max,
+    I32Lt,
+    I32Lte,
+    I32Gt,
+    I32Gte,
 
     // ----- stack manipulation ------------------------------------------------------------------
     Drop,
@@ -203,6 +211,7 @@ pub enum Instruction {
     CSwapW,
     CDrop,
     CDropW,
+    Select,
 
     // ----- input / output operations -----------------------------------------------------------
     Push(Immediate<IntValue>),
@@ -235,6 +244,10 @@ pub enum Instruction {
     LocStoreW(ImmU16),
 
     MemStream,
+    MemStreamImm(ImmU8),
+    MemCopy,
+    MemCopyImm(ImmU32),
+    MemCopyPtrImm(ImmU32, ImmU32, ImmU32),
     AdvPipe,
 
     AdvPush(ImmU8),
@@ -24,6 +24,13 @@ impl PrettyPrint for Instruction {
             Self::AssertEqwWithError(err_code) => flatten(
                 const_text("assert_eqw.err") + const_text("=") + text(format!("\"{err_code}\"")),
             ),
+            Self::AssertLt(value) => inst_with_felt_imm("assert_lt", value),
+            Self::AssertLtWithError(value, err_code) => flatten(
+                inst_with_felt_imm("assert_lt", value)
+                    + const_text(".err")
+                    + const_text("=")
+                    + text(format!("\"{err_code}\"")),
+            ),
             Self::Assertz => const_text("assertz"),
             Self::AssertzWithError(err_code) => flatten(
                 const_text("assertz.err") + const_text("=") + text(format!("\"{err_code}\"")),
@@ -58,6 +65,7 @@ impl PrettyPrint for Instruction {
             Self::Gt => const_text("gt"),
             Self::Gte => const_text("gte"),
             Self::IsOdd => const_text("is_odd"),
+            Self::IsEven => const_text("is_even"),
 
             // ----- ext2 operations --------------------------------------------------------------
             Self::Ext2Add => const_text("ext2add"),
@@ -100,6 +108,9 @@ impl PrettyPrint for Instruction {
             Self::U32OverflowingMulImm(value) => inst_with_imm("u32overflowing_mul", value),
             Self::U32OverflowingMadd => const_text("u32overflowing_madd"),
             Self::U32WrappingMadd => const_text("u32wrapping_madd"),
+            Self::U32CheckedMaddImm(a, b) => {
+                flatten(inst_with_imm("u32checked_madd", a) + const_text(".") + b.render())
+            },
             Self::U32Div => const_text("u32div"),
             Self::U32DivImm(value) => inst_with_imm("u32div", value),
             Self::U32Mod => const_text("u32mod"),
@@ -129,6 +140,10 @@ impl PrettyPrint for Instruction {
             Self::U32Gte => const_text("u32gte"),
             Self::U32Min => const_text("u32min"),
             Self::U32Max => const_text("u32max"),
+            Self::I32Lt => const_text("i32lt"),
+            Self::I32Lte => const_text("i32lte"),
+            Self::I32Gt => const_text("i32gt"),
+            Self::I32Gte => const_text("i32gte"),
 
             // ----- stack manipulation -----------------------------------------------------------
             Self::Drop => const_text("drop"),
@@ -209,6 +224,7 @@ impl PrettyPrint for Instruction {
             Self::CSwapW => const_text("cswapw"),
             Self::CDrop => const_text("cdrop"),
             Self::CDropW => const_text("cdropw"),
+            Self::Select => const_text("select"),
 
             // ----- input / output operations ----------------------------------------------------
             Self::Push(value) => inst_with_imm("push", value),
@@ -244,6 +260,16 @@ impl PrettyPrint for Instruction {
             Self::LocStoreW(value) => inst_with_imm("loc_storew", value),
 
             Self::MemStream => const_text("mem_stream"),
+            Self::MemStreamImm(value) => inst_with_imm("mem_stream", value),
+            Self::MemCopy => const_text("mem_copy"),
+            Self::MemCopyImm(value) => inst_with_imm("mem_copy", value),
+            Self::MemCopyPtrImm(src, dst, n) => flatten(
+                inst_with_imm("mem_copy", src)
+                    + const_text(".")
+                    + dst.render()
+                    + const_text(".")
+                    + n.render(),
+            ),
             Self::AdvPipe => const_text("adv_pipe"),
 
             Self::AdvPush(value) => inst_with_imm("adv_push", value),
@@ -352,6 +378,8 @@ fn inst_with_felt_imm(name: &'static str, imm: &Immediate<crate::Felt>) -> Docum
     let value = match imm {
         Immediate::Value(value) => display(*value),
         Immediate::Constant(name) => text(name),
+        Immediate::ImportedConstant { module, name } => text(format!("{module}::{name}")),
+        Immediate::AbsoluteConstantPath { path, name } => text(format!("{path}::{name}")),
     };
 
     flatten(const_text(name) + const_text(".") + value)
@@ -1,4 +1,4 @@
-use alloc::{boxed::Box, string::String, sync::Arc, vec::Vec};
+use alloc::{boxed::Box, collections::BTreeMap, string::String, sync::Arc, vec, vec::Vec};
 use core::fmt;
 
 use miden_core::{
@@ -9,12 +9,12 @@ use miden_debug_types::{SourceFile, SourceSpan, Span, Spanned};
 use miden_utils_diagnostics::Report;
 
 use super::{
-    DocString, Export, Import, LocalNameResolver, ProcedureIndex, ProcedureName,
-    QualifiedProcedureName, ResolvedProcedure,
+    DocString, Export, Import, InvocationTarget, InvokeKind, LocalNameResolver, Procedure,
+    ProcedureIndex, ProcedureName, QualifiedProcedureName, ResolvedProcedure,
 };
 use crate::{
     LibraryNamespace, LibraryPath,
-    ast::{AliasTarget, Ident},
+    ast::{AliasTarget, ConstantExpr, Ident},
     parser::ModuleParser,
     sema::SemanticAnalysisError,
 };
@@ -131,6 +131,11 @@ pub struct Module {
     pub(crate) procedures: Vec<Export>,
     /// AdviceMap that this module expects to be loaded in the host before executing.
     pub(crate) advice_map: AdviceMap,
+    /// The values of the constants defined in the module body.
+    ///
+    /// These are retained so that references to them from other modules can be folded during
+    /// linking.
+    pub(crate) constants: BTreeMap<Ident, ConstantExpr>,
 }
 
 /// Constants
@@ -158,6 +163,7 @@ impl Module {
             imports: Default::default(),
             procedures: Default::default(),
             advice_map: Default::default(),
+            constants: Default::default(),
         }
     }
 
@@ -322,6 +328,16 @@ impl Module {
         &self.advice_map
     }
 
+    /// Get an iterator over the constants defined in this module, and their values.
+    pub fn constants(&self) -> impl Iterator<Item = (&Ident, &ConstantExpr)> + '_ {
+        self.constants.iter()
+    }
+
+    /// Returns the value of the constant `name` defined in this module, if it exists.
+    pub fn get_constant(&self, name: &Ident) -> Option<&ConstantExpr> {
+        self.constants.get(name)
+    }
+
     /// Get an iterator over the procedures defined in this module.
     ///
     /// The entity returned is an [Export], which abstracts over locally-defined procedures and
@@ -335,6 +351,106 @@ impl Module {
         self.procedures.iter_mut()
     }
 
+    /// Returns the number of source-level instructions in the body of each procedure defined in
+    /// this module, keyed by procedure name.
+    ///
+    /// Instructions in nested blocks are included in the count, see [Block::num_instructions]. The
+    /// entrypoint of an executable module is included under the name `$main`, while re-exported
+    /// procedures are skipped, as they are defined in another module.
+    ///
+    /// NOTE: The counts are computed from the AST as written, i.e. before procedures invoked via
+    /// `exec` are inlined, and before instructions are expanded into VM operations. As such, they
+    /// do not reflect the size of the compiled MAST, nor the number of cycles it takes to execute
+    /// a procedure.
+    ///
+    /// [Block::num_instructions]: crate::ast::Block::num_instructions
+    pub fn procedure_source_instruction_counts(&self) -> BTreeMap<ProcedureName, usize> {
+        self.procedures
+            .iter()
+            .filter_map(|export| match export {
+                Export::Procedure(procedure) => {
+                    Some((procedure.name().clone(), procedure.body().num_instructions()))
+                },
+                Export::Alias(_) => None,
+            })
+            .collect()
+    }
+
+    /// Returns the procedures defined in this module in topological order, such that every
+    /// procedure appears after the local procedures it invokes via `exec` or `call`.
+    ///
+    /// Procedures which are not related by an invocation are returned in the order they are defined
+    /// in, and re-exported procedures are skipped, as they are defined in another module.
+    ///
+    /// This relies on the set of invoked procedures computed during semantic analysis, so it is
+    /// only meaningful for a module produced by the parser.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the procedures of this module invoke each other recursively.
+    pub fn procedures_topologically_sorted(
+        &self,
+    ) -> Result<Vec<&Procedure>, SemanticAnalysisError> {
+        #[derive(Copy, Clone, PartialEq, Eq)]
+        enum Mark {
+            Visiting,
+            Visited,
+        }
+
+        fn visit<'a>(
+            module: &'a Module,
+            index: usize,
+            marks: &mut [Option<Mark>],
+            sorted: &mut Vec<&'a Procedure>,
+        ) -> Result<(), SemanticAnalysisError> {
+            let Export::Procedure(procedure) = &module.procedures[index] else {
+                return Ok(());
+            };
+
+            marks[index] = Some(Mark::Visiting);
+            for invoke in procedure.invoked() {
+                if !matches!(invoke.kind, InvokeKind::Exec | InvokeKind::Call) {
+                    continue;
+                }
+                let callee = match &invoke.target {
+                    InvocationTarget::ProcedureName(name) => name,
+                    InvocationTarget::AbsoluteProcedurePath { name, path }
+                        if path == module.path() =>
+                    {
+                        name
+                    },
+                    _ => continue,
+                };
+                let Some(callee_index) = module.index_of(|p| p.name() == callee) else {
+                    continue;
+                };
+                match marks[callee_index.as_usize()] {
+                    Some(Mark::Visited) => (),
+                    Some(Mark::Visiting) => {
+                        return Err(SemanticAnalysisError::MutuallyRecursive {
+                            span: invoke.span(),
+                        });
+                    },
+                    None => visit(module, callee_index.as_usize(), marks, sorted)?,
+                }
+            }
+            marks[index] = Some(Mark::Visited);
+            sorted.push(procedure);
+
+            Ok(())
+        }
+
+        let mut marks = vec![None; self.procedures.len()];
+        let mut sorted = Vec::with_capacity(self.procedures.len());
+        for index in 0..self.procedures.len() {
+            if marks[index].is_none() {
+                visit(self, index, &mut marks, &mut sorted)?;
+            }
+        }
+
+        Ok(sorted)
+    }
+
     /// Returns procedures exported from this module.
     ///
     /// Each exported procedure is represented by its local procedure index and a fully qualified
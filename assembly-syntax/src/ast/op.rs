@@ -22,7 +22,16 @@ pub enum Op {
         else_blk: Block,
     } = 0,
     /// Represents a condition-controlled loop
-    While { span: SourceSpan, body: Block } = 1,
+    ///
+    /// Can be either `while.true`, or `while.true(max=N)`.
+    While {
+        span: SourceSpan,
+        body: Block,
+        /// An optional hint about the maximum number of iterations of this loop.
+        ///
+        /// This is a performance hint only: the loop is allowed to run for more iterations.
+        max_iterations: Option<u32>,
+    } = 1,
     /// Represents a counter-controlled loop.
     ///
     /// NOTE: The iteration count must be known at compile-time, so this is _not_ used for general
@@ -44,7 +53,12 @@ impl crate::prettier::PrettyPrint for Op {
             Self::If { then_blk, else_blk, .. } => {
                 text("if.true") + then_blk.render() + text("else") + else_blk.render() + text("end")
             },
-            Self::While { body, .. } => text("while.true") + body.render() + text("end"),
+            Self::While { body, max_iterations: None, .. } => {
+                text("while.true") + body.render() + text("end")
+            },
+            Self::While { body, max_iterations: Some(max), .. } => {
+                display(format!("while.true(max={max})")) + body.render() + text("end")
+            },
             Self::Repeat { count, body, .. } => {
                 display(format!("repeat.{count}")) + body.render() + text("end")
             },
@@ -77,7 +91,10 @@ impl PartialEq for Op {
                 Self::If { then_blk: lt, else_blk: le, .. },
                 Self::If { then_blk: rt, else_blk: re, .. },
             ) => lt == rt && le == re,
-            (Self::While { body: lbody, .. }, Self::While { body: rbody, .. }) => lbody == rbody,
+            (
+                Self::While { body: lbody, max_iterations: lmax, .. },
+                Self::While { body: rbody, max_iterations: rmax, .. },
+            ) => lbody == rbody && lmax == rmax,
             (
                 Self::Repeat { count: lcount, body: lbody, .. },
                 Self::Repeat { count: rcount, body: rbody, .. },
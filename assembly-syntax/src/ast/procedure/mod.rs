@@ -4,6 +4,7 @@ mod name;
 #[allow(clippy::module_inception)]
 mod procedure;
 mod resolver;
+mod signature;
 
 use alloc::string::String;
 
@@ -15,6 +16,7 @@ pub use self::{
     name::{ProcedureName, QualifiedProcedureName},
     procedure::{Procedure, Visibility},
     resolver::{LocalNameResolver, ResolvedProcedure},
+    signature::StackSignature,
 };
 use crate::ast::{AttributeSet, Invoke};
 
@@ -58,6 +60,12 @@ impl Export {
         }
     }
 
+    /// Returns the stack signature declared in the documentation of this procedure, if present
+    /// and well-formed.
+    pub fn stack_signature(&self) -> Option<StackSignature> {
+        self.docs().and_then(|docs| StackSignature::parse(docs).ok().flatten())
+    }
+
     /// Returns the attributes for this procedure.
     pub fn attributes(&self) -> Option<&AttributeSet> {
         match self {
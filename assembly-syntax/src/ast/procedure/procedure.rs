@@ -3,7 +3,7 @@ use core::fmt;
 
 use miden_debug_types::{SourceSpan, Span, Spanned};
 
-use super::ProcedureName;
+use super::{ProcedureName, StackSignature};
 use crate::ast::{Attribute, AttributeSet, Block, DocString, Invoke};
 
 // PROCEDURE VISIBILITY
@@ -145,6 +145,13 @@ impl Procedure {
         self.docs.as_ref().map(|docstring| docstring.as_spanned_str())
     }
 
+    /// Returns the stack signature declared in the documentation of this procedure, if present
+    /// and well-formed.
+    pub fn stack_signature(&self) -> Option<StackSignature> {
+        self.docs()
+            .and_then(|docs| StackSignature::parse(docs.into_inner()).ok().flatten())
+    }
+
     /// Get the attributes attached to this procedure
     #[inline]
     pub fn attributes(&self) -> &AttributeSet {
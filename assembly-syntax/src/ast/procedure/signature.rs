@@ -0,0 +1,65 @@
+/// The prefix of a docstring line which declares the stack signature of a procedure.
+const STACK_SIGNATURE_PREFIX: &str = "stack:";
+
+// STACK SIGNATURE
+// ================================================================================================
+
+/// The stack signature of a procedure, as declared in its docstring.
+///
+/// A stack signature is declared by a line of the procedure docstring of the following form:
+///
+/// ```text
+/// #! stack: [a, b] -> [c]
+/// ```
+///
+/// The number of elements on either side of the arrow determines the number of inputs the
+/// procedure consumes, and the number of outputs it produces. A trailing `...` may be used to
+/// denote the rest of the stack, and is not counted.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct StackSignature {
+    /// The number of elements consumed from the top of the operand stack.
+    pub inputs: usize,
+    /// The number of elements left on top of the operand stack.
+    pub outputs: usize,
+}
+
+impl StackSignature {
+    /// Parses the stack signature declared in `docs`.
+    ///
+    /// Returns `Ok(None)` if no signature is declared, and `Err` if the signature is malformed.
+    pub(crate) fn parse(docs: &str) -> Result<Option<Self>, ()> {
+        let Some(signature) =
+            docs.lines().find_map(|line| line.trim().strip_prefix(STACK_SIGNATURE_PREFIX))
+        else {
+            return Ok(None);
+        };
+        let (inputs, outputs) = signature.split_once("->").ok_or(())?;
+        let inputs = parse_stack_list(inputs)?;
+        let outputs = parse_stack_list(outputs)?;
+        Ok(Some(Self { inputs, outputs }))
+    }
+
+    /// Returns the net effect of a procedure with this signature on the depth of the operand
+    /// stack.
+    pub fn effect(&self) -> isize {
+        self.outputs as isize - self.inputs as isize
+    }
+}
+
+/// Parses a list of the form `[a, b, ...]`, and returns the number of named elements in it.
+fn parse_stack_list(list: &str) -> Result<usize, ()> {
+    let list = list
+        .trim()
+        .strip_prefix('[')
+        .and_then(|list| list.strip_suffix(']'))
+        .ok_or(())?;
+    let mut len = 0;
+    for item in list.split(',').map(str::trim) {
+        match item {
+            "" | "..." => continue,
+            item if item.contains(['[', ']']) => return Err(()),
+            _ => len += 1,
+        }
+    }
+    Ok(len)
+}
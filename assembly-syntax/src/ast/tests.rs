@@ -1,12 +1,13 @@
 use alloc::{string::ToString, sync::Arc, vec::Vec};
 
-use miden_debug_types::{SourceSpan, Span};
+use miden_debug_types::{SourceLanguage, SourceManager, SourceSpan, Span};
 use miden_utils_diagnostics::Report;
 use pretty_assertions::assert_eq;
 
 use crate::{
-    Felt, LibraryNamespace, LibraryPath, assert_diagnostic, assert_diagnostic_lines, ast::*,
-    parser::WordValue, regex, source_file, testing::SyntaxTestContext,
+    Felt, LibraryNamespace, LibraryPath, MemSourceResolver, SemanticAnalysisError, SourceResolver,
+    assert_diagnostic, assert_diagnostic_lines, ast::*, parser::WordValue, regex, source_file,
+    testing::SyntaxTestContext,
 };
 
 macro_rules! id {
@@ -16,8 +17,8 @@ macro_rules! id {
 }
 
 macro_rules! inst {
-    ($inst:ident($value:expr)) => {
-        Op::Inst(Span::unknown(Instruction::$inst($value)))
+    ($inst:ident($($value:expr),+)) => {
+        Op::Inst(Span::unknown(Instruction::$inst($($value),+)))
     };
 
     ($inst:ident) => {
@@ -110,7 +111,18 @@ macro_rules! if_true {
 
 macro_rules! while_true {
     ($body:expr) => {
-        Op::While { span: Default::default(), body: $body }
+        Op::While {
+            span: Default::default(),
+            body: $body,
+            max_iterations: None,
+        }
+    };
+    ($max:literal, $body:expr) => {
+        Op::While {
+            span: Default::default(),
+            body: $body,
+            max_iterations: Some($max),
+        }
     };
 }
 
@@ -377,6 +389,112 @@ fn test_ast_parsing_program_push() -> Result<(), Report> {
     Ok(())
 }
 
+#[test]
+fn test_ast_parsing_program_push_string() -> Result<(), Report> {
+    let context = SyntaxTestContext::new();
+
+    let source = source_file!(
+        &context,
+        r#"
+    begin
+        push."abc"
+        push."a\n\t\\\""
+    end"#
+    );
+    let forms = module!(begin!(
+        inst!(PushU8(b'a')),
+        inst!(PushU8(b'b')),
+        inst!(PushU8(b'c')),
+        inst!(PushU8(b'a')),
+        inst!(PushU8(b'\n')),
+        inst!(PushU8(b'\t')),
+        inst!(PushU8(b'\\')),
+        inst!(PushU8(b'"'))
+    ));
+
+    assert_eq!(context.parse_forms(source)?, forms);
+
+    // Push a string of more than 16 bytes
+    let source_too_long = source_file!(&context, r#"begin push."abcdefghijklmnopq" end"#);
+    assert_parse_diagnostic!(
+        source_too_long,
+        "too many operands for `push`: tried to push 17 elements, but only 16 can be pushed at one time"
+    );
+
+    // Push a string containing an unsupported escape sequence
+    let source_invalid_escape = source_file!(&context, r#"begin push."a\qb" end"#);
+    assert_parse_diagnostic!(
+        source_invalid_escape,
+        "invalid escape sequence in string literal: '\\q'"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_ast_parsing_program_assert_eqw() -> Result<(), Report> {
+    let context = SyntaxTestContext::new();
+
+    let source = source_file!(
+        &context,
+        r#"
+    begin
+        assert_eqw
+        assert_eqw.err="words differ"
+    end"#
+    );
+    let err_msg = Immediate::Value(Span::unknown(Arc::from("words differ")));
+    let forms = module!(begin!(inst!(AssertEqw), inst!(AssertEqwWithError(err_msg))));
+
+    assert_eq!(context.parse_forms(source)?, forms);
+
+    Ok(())
+}
+
+#[test]
+fn test_ast_parsing_program_assert_lt() -> Result<(), Report> {
+    let context = SyntaxTestContext::new();
+
+    let source = source_file!(
+        &context,
+        r#"
+    begin
+        assert_lt.10
+        assert_lt.18446744069414584320
+        assert_lt.10.err="out of range"
+    end"#
+    );
+    let err_msg = Immediate::Value(Span::unknown(Arc::from("out of range")));
+    let forms = module!(begin!(
+        inst!(AssertLt(Felt::new(10).into())),
+        inst!(AssertLt(Felt::new(18446744069414584320).into())),
+        inst!(AssertLtWithError(Felt::new(10).into(), err_msg))
+    ));
+
+    assert_eq!(context.parse_forms(source)?, forms);
+
+    Ok(())
+}
+
+#[test]
+fn assert_parsing_assert_lt_invalid_param() {
+    let context = SyntaxTestContext::default();
+    let source = source_file!(&context, "begin assert_lt.18446744069414584321 end");
+    assert_parse_diagnostic!(source, "invalid literal: value overflowed the field modulus");
+}
+
+#[test]
+fn test_ast_parsing_program_boolean_ops() -> Result<(), Report> {
+    let context = SyntaxTestContext::new();
+
+    let source = source_file!(&context, "begin not and or xor end");
+    let forms = module!(begin!(inst!(Not), inst!(And), inst!(Or), inst!(Xor)));
+
+    assert_eq!(context.parse_forms(source)?, forms);
+
+    Ok(())
+}
+
 #[test]
 fn test_ast_parsing_program_u32() -> Result<(), Report> {
     let context = SyntaxTestContext::new();
@@ -413,6 +531,38 @@ fn test_ast_parsing_program_u32() -> Result<(), Report> {
     Ok(())
 }
 
+#[test]
+fn test_ast_parsing_program_u32checked_madd() -> Result<(), Report> {
+    let context = SyntaxTestContext::new();
+
+    let source =
+        source_file!(&context, "begin u32checked_madd.3.4 u32checked_madd.0.4294967295 end");
+    let forms = module!(begin!(
+        inst!(U32CheckedMaddImm(3u32.into(), 4u32.into())),
+        inst!(U32CheckedMaddImm(0u32.into(), u32::MAX.into()))
+    ));
+
+    assert_eq!(context.parse_forms(source)?, forms);
+
+    Ok(())
+}
+
+#[test]
+fn test_ast_parsing_program_mem_copy() -> Result<(), Report> {
+    let context = SyntaxTestContext::new();
+
+    let source = source_file!(&context, "begin mem_copy mem_copy.3 mem_copy.0.40.3 end");
+    let forms = module!(begin!(
+        inst!(MemCopy),
+        inst!(MemCopyImm(3u32.into())),
+        inst!(MemCopyPtrImm(0u32.into(), 40u32.into(), 3u32.into()))
+    ));
+
+    assert_eq!(context.parse_forms(source)?, forms);
+
+    Ok(())
+}
+
 #[test]
 fn test_ast_parsing_program_proc() -> Result<(), Report> {
     let context = SyntaxTestContext::new();
@@ -442,6 +592,36 @@ fn test_ast_parsing_program_proc() -> Result<(), Report> {
     Ok(())
 }
 
+#[test]
+fn test_ast_parsing_program_proc_named_after_keyword() -> Result<(), Report> {
+    let context = SyntaxTestContext::new();
+
+    // instruction keywords can still be used as procedure names
+    let source = source_file!(
+        &context,
+        r#"
+    proc.is_even
+        push.1
+    end
+    proc.is_odd
+        push.2
+    end
+    begin
+        exec.is_even
+        exec.is_odd
+    end"#
+    );
+
+    let forms = module!(
+        proc!(is_even, 0, block!(inst!(PushU8(1)))),
+        proc!(is_odd, 0, block!(inst!(PushU8(2)))),
+        begin!(exec!(is_even), exec!(is_odd))
+    );
+    assert_eq!(context.parse_forms(source)?, forms);
+
+    Ok(())
+}
+
 #[test]
 fn test_ast_parsing_module() -> Result<(), Report> {
     let context = SyntaxTestContext::new();
@@ -471,16 +651,60 @@ fn test_ast_parsing_adv_injection() -> Result<(), Report> {
     use super::SystemEventNode::*;
 
     let context = SyntaxTestContext::new();
-    let source = source_file!(&context, "begin adv.push_u64div adv.push_mapval adv.insert_mem end");
+    let source = source_file!(
+        &context,
+        "begin adv.push_u64div adv.push_mapval adv.insert_mem adv.insert_mem_hashed end"
+    );
     let forms = module!(begin!(
         inst!(SysEvent(PushU64Div)),
         inst!(SysEvent(PushMapVal)),
-        inst!(SysEvent(InsertMem))
+        inst!(SysEvent(InsertMem)),
+        inst!(SysEvent(InsertMemHashed))
+    ));
+    assert_eq!(context.parse_forms(source)?, forms);
+    Ok(())
+}
+
+#[test]
+fn test_ast_parsing_emit() -> Result<(), Report> {
+    let context = SyntaxTestContext::new();
+    let source = source_file!(&context, "begin emit.0 emit.42 emit.4294967295 end");
+    let forms = module!(begin!(
+        inst!(Emit(0u32.into())),
+        inst!(Emit(42u32.into())),
+        inst!(Emit(u32::MAX.into()))
     ));
     assert_eq!(context.parse_forms(source)?, forms);
     Ok(())
 }
 
+#[test]
+fn assert_parsing_emit_invalid_param() {
+    let context = SyntaxTestContext::default();
+    let source = source_file!(&context, "begin emit.4294967296 end");
+    assert_parse_diagnostic!(source, "invalid literal: value overflowed the u32 range");
+}
+
+#[test]
+fn test_ast_parsing_trace() -> Result<(), Report> {
+    let context = SyntaxTestContext::new();
+    let source = source_file!(&context, "begin trace.0 trace.42 trace.4294967295 end");
+    let forms = module!(begin!(
+        inst!(Trace(0u32.into())),
+        inst!(Trace(42u32.into())),
+        inst!(Trace(u32::MAX.into()))
+    ));
+    assert_eq!(context.parse_forms(source)?, forms);
+    Ok(())
+}
+
+#[test]
+fn assert_parsing_trace_invalid_param() {
+    let context = SyntaxTestContext::default();
+    let source = source_file!(&context, "begin trace.4294967296 end");
+    assert_parse_diagnostic!(source, "invalid literal: value overflowed the u32 range");
+}
+
 #[test]
 fn test_ast_parsing_bitwise_counters() -> Result<(), Report> {
     let context = SyntaxTestContext::new();
@@ -629,6 +853,39 @@ fn test_ast_parsing_while_if_body() {
     assert_forms!(context, source, forms);
 }
 
+#[test]
+fn test_ast_parsing_while_with_iterations_hint() {
+    let context = SyntaxTestContext::new();
+    let source = source_file!(
+        &context,
+        "\
+    begin
+        push.1
+        while.true(max=1024)
+            mul
+        end
+    end
+    "
+    );
+
+    let forms = module!(begin!(inst!(PushU8(1)), while_true!(1024, block!(inst!(Mul)))));
+
+    assert_forms!(context, source, forms);
+
+    // the hint is preserved when formatting the module
+    let source = source_file!(&context, "begin while.true(max=1024) mul end end");
+    let module = Module::parse(
+        LibraryPath::new_from_components(LibraryNamespace::Exec, []),
+        ModuleKind::Executable,
+        source,
+    )
+    .unwrap_or_else(|err| panic!("{err}"));
+    assert_eq!(
+        module.to_string(),
+        "begin\n    while.true(max=1024)\n        mul\n    end\nend\n"
+    );
+}
+
 #[test]
 fn test_ast_parsing_attributes() -> Result<(), Report> {
     let context = SyntaxTestContext::new();
@@ -718,6 +975,55 @@ fn test_missing_import() {
     );
 }
 
+#[test]
+fn test_procedures_topologically_sorted() -> Result<(), Report> {
+    let context = SyntaxTestContext::new();
+    let source = source_file!(
+        &context,
+        r#"
+    export.a
+        exec.b
+    end
+
+    proc.b
+        exec.c
+    end
+
+    proc.c
+        add
+    end"#
+    );
+
+    let module = context.parse_module(source)?;
+    let sorted = module
+        .procedures_topologically_sorted()
+        .map_err(Report::new)?
+        .into_iter()
+        .map(|procedure| procedure.name().as_str())
+        .collect::<Vec<_>>();
+    assert_eq!(sorted, ["c", "b", "a"]);
+
+    let source = source_file!(
+        &context,
+        r#"
+    proc.a
+        exec.b
+    end
+
+    proc.b
+        exec.a
+    end"#
+    );
+
+    let module = context.parse_module(source)?;
+    assert!(matches!(
+        module.procedures_topologically_sorted(),
+        Err(SemanticAnalysisError::MutuallyRecursive { .. })
+    ));
+
+    Ok(())
+}
+
 // INVALID BODY TESTS
 // ================================================================================================
 
@@ -1089,6 +1395,37 @@ fn assert_parsing_line_extra_param() {
     );
 }
 
+#[test]
+fn assert_parsing_u32checked_madd_extra_param() {
+    let context = SyntaxTestContext::default();
+    let source = source_file!(
+        &context,
+        "\
+        begin
+          u32checked_madd.1.2.3
+        end"
+    );
+    assert_parse_diagnostic_lines!(
+        source,
+        "invalid syntax",
+        regex!(r#",-\[test[\d]+:2:30\]"#),
+        "1 | begin",
+        "2 |           u32checked_madd.1.2.3",
+        "  :                              |",
+        "  :                              `-- found a . here",
+        "3 |         end",
+        "  `----",
+        r#" help: expected primitive opcode (e.g. "add"), or "end", or control flow opcode (e.g. "if.true")"#
+    );
+}
+
+#[test]
+fn assert_parsing_u32checked_madd_invalid_param() {
+    let context = SyntaxTestContext::default();
+    let source = source_file!(&context, "begin u32checked_madd.1.4294967296 end");
+    assert_parse_diagnostic!(source, "invalid literal: value overflowed the u32 range");
+}
+
 #[test]
 fn assert_parsing_line_invalid_op() {
     let context = SyntaxTestContext::default();
@@ -1162,7 +1499,7 @@ fn assert_parsing_line_unexpected_token() {
         "  :     ^|^",
         "  :      `-- found a mul here",
         "  `----",
-        r#" help: expected "@", or "adv_map", or "begin", or "const", or "export", or "proc", or "use", or end of file, or doc comment"#
+        r#" help: expected "@", or "adv_map", or "begin", or "const", or "export", or "include", or "proc", or "use", or end of file, or doc comment"#
     );
 }
 
@@ -1369,3 +1706,240 @@ end"#
         r#" help: this constant does not resolve to a value of the right type"#
     );
 }
+
+// STACK EFFECT TESTS
+// ================================================================================================
+
+#[test]
+fn test_stack_signature_matches() {
+    let context = SyntaxTestContext::new().with_warnings_as_errors(true);
+    let source = source_file!(
+        &context,
+        "\
+    #! module doc
+
+    #! stack: [c, b, a, ...] -> [d, ...]
+    export.foo
+        if.true
+            mul
+        else
+            add
+        end
+        push.1
+        while.true
+            push.2 mul dup.0 push.100 lt
+        end
+    end
+
+    #! stack: [a] -> []
+    export.bar
+        exec.foo
+        push.1.2
+    end
+    "
+    );
+    let module = context.parse_module(source).unwrap();
+    assert_eq!(module.procedures().count(), 2);
+}
+
+#[test]
+fn test_stack_signature_mismatch() {
+    let context = SyntaxTestContext::new().with_warnings_as_errors(true);
+    let source = source_file!(
+        &context,
+        "\
+    #! module doc
+
+    #! stack: [b, a] -> [c]
+    export.foo
+        add push.1
+    end
+    "
+    );
+    assert_module_diagnostic_lines!(
+        context,
+        source,
+        "syntax error",
+        "help: see emitted diagnostics for details",
+        "Warning:   ! stack effect mismatch: the signature declares a net effect of -1, but the body has a net effect of 0",
+        regex!(r#",-\[test[\d]+:4:12\]"#),
+        "3 |     #! stack: [b, a] -> [c]",
+        "4 |     export.foo",
+        "  :            ^^^",
+        "5 |         add push.1",
+        "  `----",
+        "help: check that the body of this procedure matches its declared stack signature"
+    );
+}
+
+#[test]
+fn test_stack_signature_unbalanced_branches() {
+    let context = SyntaxTestContext::new().with_warnings_as_errors(true);
+    let source = source_file!(
+        &context,
+        "\
+    #! module doc
+
+    #! stack: [c, b, a] -> [d]
+    export.foo
+        if.true
+            add
+        else
+            drop drop
+        end
+    end
+    "
+    );
+    assert_module_diagnostic_lines!(
+        context,
+        source,
+        "syntax error",
+        "help: see emitted diagnostics for details",
+        "Warning:   ! inconsistent stack effect: the branches of this conditional have net effects of -1 and -2",
+        regex!(r#",-\[test[\d]+:5:9\]"#),
+        " 4 |         export.foo",
+        " 5 | ,->         if.true",
+        " 6 | |               add",
+        " 7 | |           else",
+        " 8 | |               drop drop",
+        " 9 | `->         end",
+        "10 |         end",
+        "   `----",
+        "help: both branches of a conditional should leave the operand stack at the same depth"
+    );
+}
+
+// INCLUDE TESTS
+// ================================================================================================
+
+#[test]
+fn test_include_resolves_forms() -> Result<(), Report> {
+    let context = SyntaxTestContext::new();
+    let included = context.source_manager().load(
+        SourceLanguage::Masm,
+        "included.masm".into(),
+        "proc.foo\n    add\nend\n".to_string(),
+    );
+    let source = source_file!(&context, "include.\"included.masm\"\n\nbegin\n    exec.foo\nend\n");
+
+    let mut parser = crate::parser::ModuleParser::new(ModuleKind::Executable);
+    parser.set_source_resolver(Arc::new(move |path: &str| {
+        (path == "included.masm").then(|| included.clone())
+    }));
+    let module =
+        parser.parse(LibraryPath::new_from_components(LibraryNamespace::Exec, []), source)?;
+
+    assert_eq!(module.procedures().count(), 2);
+    assert!(module.resolve(&ProcedureName::new("foo").unwrap()).is_some());
+    Ok(())
+}
+
+#[test]
+fn test_include_reports_errors_against_included_file() {
+    let context = SyntaxTestContext::new();
+    let included = context.source_manager().load(
+        SourceLanguage::Masm,
+        "included.masm".into(),
+        "proc.foo\n    add\n    use\nend\n".to_string(),
+    );
+    let source = source_file!(&context, "include.\"included.masm\"\n\nbegin\n    exec.foo\nend\n");
+
+    let mut parser = crate::parser::ModuleParser::new(ModuleKind::Executable);
+    parser.set_source_resolver(Arc::new(move |path: &str| {
+        (path == "included.masm").then(|| included.clone())
+    }));
+    let error = parser
+        .parse(LibraryPath::new_from_components(LibraryNamespace::Exec, []), source.clone())
+        .expect_err("expected diagnostic to be raised, but parsing succeeded");
+    assert_diagnostic_lines!(
+        error,
+        "invalid syntax",
+        regex!(r#",-\[included.masm:3:5\]"#),
+        "2 |     add",
+        "3 |     use",
+        "  :     ^|^",
+        "  :      `-- found a use here",
+        "4 | end",
+        "  `----",
+        r#" help: expected ".", or primitive opcode (e.g. "add"), or "end", or control flow opcode (e.g. "if.true")"#
+    );
+
+    let mut parser = crate::parser::ModuleParser::new(ModuleKind::Executable);
+    let error = parser
+        .parse(LibraryPath::new_from_components(LibraryNamespace::Exec, []), source)
+        .expect_err("expected diagnostic to be raised, but parsing succeeded");
+    assert_diagnostic!(error, "unable to resolve included file 'included.masm'");
+}
+
+#[test]
+fn test_include_reports_semantic_errors_against_included_file() {
+    let context = SyntaxTestContext::new();
+    let included = context.source_manager().load(
+        SourceLanguage::Masm,
+        "included.masm".into(),
+        "proc.foo\n    add\nend\n\nproc.foo\n    mul\nend\n".to_string(),
+    );
+    let source = source_file!(&context, "include.\"included.masm\"\n\nbegin\n    exec.foo\nend\n");
+
+    let mut parser = crate::parser::ModuleParser::new(ModuleKind::Executable);
+    parser.set_source_resolver(Arc::new(move |path: &str| {
+        (path == "included.masm").then(|| included.clone())
+    }));
+    let error = parser
+        .parse(LibraryPath::new_from_components(LibraryNamespace::Exec, []), source)
+        .expect_err("expected diagnostic to be raised, but parsing succeeded");
+    assert_diagnostic_lines!(
+        error,
+        "syntax error",
+        "help: see emitted diagnostics for details",
+        "in included file 'included.masm'",
+        "help: see emitted diagnostics for details",
+        "symbol conflict: found duplicate definitions of the same name",
+        regex!(r#",-\[included.masm:1:6\]"#),
+        "1 |     proc.foo",
+        "  :          ^|^",
+        "  :           `-- previously defined here",
+        "2 |         add",
+        "3 |     end",
+        "4 |",
+        "5 | ,-> proc.foo",
+        "6 | |       mul",
+        "7 | |-> end",
+        "  : `---- conflict occurs here",
+        "  `----"
+    );
+}
+
+#[test]
+fn test_include_resolved_by_mem_source_resolver() -> Result<(), Report> {
+    let context = SyntaxTestContext::new();
+    let math = context.source_manager().load(
+        SourceLanguage::Masm,
+        "math.masm".into(),
+        "proc.double\n    dup add\nend\n".to_string(),
+    );
+    let path = LibraryPath::new("dummy::math").unwrap();
+    let resolver = MemSourceResolver::new([(path.clone(), math.clone())]);
+    assert_eq!(resolver.resolve_module(&path).map(|source| source.id()), Some(math.id()));
+    assert!(resolver.resolve_module(&LibraryPath::new("dummy::util").unwrap()).is_none());
+
+    let source = source_file!(&context, "include.\"math.masm\"\n\nbegin\n    exec.double\nend\n");
+    let mut parser = crate::parser::ModuleParser::new(ModuleKind::Executable);
+    parser.set_source_resolver(Arc::new(resolver));
+    let module =
+        parser.parse(LibraryPath::new_from_components(LibraryNamespace::Exec, []), source)?;
+
+    assert!(module.resolve(&ProcedureName::new("double").unwrap()).is_some());
+    Ok(())
+}
+
+#[test]
+fn test_include_is_not_a_keyword() -> Result<(), Report> {
+    let context = SyntaxTestContext::new();
+    let source =
+        source_file!(&context, "proc.include\n    add\nend\n\nbegin\n    exec.include\nend\n");
+
+    let module = context.parse_program(source)?;
+    assert!(module.resolve(&ProcedureName::new("include").unwrap()).is_some());
+    Ok(())
+}
@@ -292,9 +292,8 @@ where
     use Instruction::*;
     let span = inst.span();
     match &**inst {
-        U32ShrImm(imm) | U32ShlImm(imm) | U32RotrImm(imm) | U32RotlImm(imm) | AdvPush(imm) => {
-            visitor.visit_immediate_u8(imm)
-        },
+        U32ShrImm(imm) | U32ShlImm(imm) | U32RotrImm(imm) | U32RotlImm(imm) | AdvPush(imm)
+        | MemStreamImm(imm) => visitor.visit_immediate_u8(imm),
         Locaddr(imm) | LocLoad(imm) | LocLoadW(imm) | LocStore(imm) | LocStoreW(imm) => {
             visitor.visit_immediate_u16(imm)
         },
@@ -308,6 +307,11 @@ where
         | MTreeVerifyWithError(code) => visitor.visit_immediate_error_message(code),
         AddImm(imm) | SubImm(imm) | MulImm(imm) | DivImm(imm) | ExpImm(imm) | EqImm(imm)
         | NeqImm(imm) => visitor.visit_immediate_felt(imm),
+        AssertLt(imm) => visitor.visit_immediate_felt(imm),
+        AssertLtWithError(imm, code) => {
+            visitor.visit_immediate_felt(imm)?;
+            visitor.visit_immediate_error_message(code)
+        },
         Push(imm) => visitor.visit_immediate_int_value(imm),
         U32WrappingAddImm(imm)
         | U32OverflowingAddImm(imm)
@@ -322,8 +326,18 @@ where
         | MemLoadWImm(imm)
         | MemStoreImm(imm)
         | MemStoreWImm(imm)
+        | MemCopyImm(imm)
         | Emit(imm)
         | Trace(imm) => visitor.visit_immediate_u32(imm),
+        U32CheckedMaddImm(imm1, imm2) => {
+            visitor.visit_immediate_u32(imm1)?;
+            visitor.visit_immediate_u32(imm2)
+        },
+        MemCopyPtrImm(imm1, imm2, imm3) => {
+            visitor.visit_immediate_u32(imm1)?;
+            visitor.visit_immediate_u32(imm2)?;
+            visitor.visit_immediate_u32(imm3)
+        },
         SysEvent(sys_event) => visitor.visit_system_event(Span::new(span, sys_event)),
         Exec(target) => visitor.visit_exec(target),
         Call(target) => visitor.visit_call(target),
@@ -358,6 +372,7 @@ where
         | Gt
         | Gte
         | IsOdd
+        | IsEven
         | Ext2Add
         | Ext2Sub
         | Ext2Mul
@@ -403,6 +418,10 @@ where
         | U32Gte
         | U32Min
         | U32Max
+        | I32Lt
+        | I32Lte
+        | I32Gt
+        | I32Gte
         | Drop
         | DropW
         | PadW
@@ -481,6 +500,7 @@ where
         | CSwapW
         | CDrop
         | CDropW
+        | Select
         | PushU8(_)
         | PushU16(_)
         | PushU32(_)
@@ -498,6 +518,7 @@ where
         | MemStore
         | MemStoreW
         | MemStream
+        | MemCopy
         | AdvPipe
         | AdvLoadW
         | Hash
@@ -529,7 +550,9 @@ where
     V: ?Sized + Visit<T>,
 {
     match options.into_inner() {
-        DebugOptions::StackTop(imm) => visitor.visit_immediate_u8(imm),
+        DebugOptions::StackTop(imm)
+        | DebugOptions::StackHex(imm)
+        | DebugOptions::WatchField(_, imm) => visitor.visit_immediate_u8(imm),
         DebugOptions::AdvStackTop(imm) => visitor.visit_immediate_u16(imm),
         DebugOptions::LocalRangeFrom(imm) => visitor.visit_immediate_u16(imm),
         DebugOptions::MemInterval(imm1, imm2) => {
@@ -886,9 +909,8 @@ where
     use Instruction::*;
     let span = inst.span();
     match &mut **inst {
-        U32ShrImm(imm) | U32ShlImm(imm) | U32RotrImm(imm) | U32RotlImm(imm) | AdvPush(imm) => {
-            visitor.visit_mut_immediate_u8(imm)
-        },
+        U32ShrImm(imm) | U32ShlImm(imm) | U32RotrImm(imm) | U32RotlImm(imm) | AdvPush(imm)
+        | MemStreamImm(imm) => visitor.visit_mut_immediate_u8(imm),
         Locaddr(imm) | LocLoad(imm) | LocLoadW(imm) | LocStore(imm) | LocStoreW(imm) => {
             visitor.visit_mut_immediate_u16(imm)
         },
@@ -902,6 +924,11 @@ where
         | MTreeVerifyWithError(code) => visitor.visit_mut_immediate_error_message(code),
         AddImm(imm) | SubImm(imm) | MulImm(imm) | DivImm(imm) | ExpImm(imm) | EqImm(imm)
         | NeqImm(imm) => visitor.visit_mut_immediate_felt(imm),
+        AssertLt(imm) => visitor.visit_mut_immediate_felt(imm),
+        AssertLtWithError(imm, code) => {
+            visitor.visit_mut_immediate_felt(imm)?;
+            visitor.visit_mut_immediate_error_message(code)
+        },
         Push(imm) => visitor.visit_mut_immediate_hex(imm),
         U32WrappingAddImm(imm)
         | U32OverflowingAddImm(imm)
@@ -916,8 +943,18 @@ where
         | MemLoadWImm(imm)
         | MemStoreImm(imm)
         | MemStoreWImm(imm)
+        | MemCopyImm(imm)
         | Emit(imm)
         | Trace(imm) => visitor.visit_mut_immediate_u32(imm),
+        U32CheckedMaddImm(imm1, imm2) => {
+            visitor.visit_mut_immediate_u32(imm1)?;
+            visitor.visit_mut_immediate_u32(imm2)
+        },
+        MemCopyPtrImm(imm1, imm2, imm3) => {
+            visitor.visit_mut_immediate_u32(imm1)?;
+            visitor.visit_mut_immediate_u32(imm2)?;
+            visitor.visit_mut_immediate_u32(imm3)
+        },
         SysEvent(sys_event) => visitor.visit_mut_system_event(Span::new(span, sys_event)),
         Exec(target) => visitor.visit_mut_exec(target),
         Call(target) => visitor.visit_mut_call(target),
@@ -952,6 +989,7 @@ where
         | Gt
         | Gte
         | IsOdd
+        | IsEven
         | Ext2Add
         | Ext2Sub
         | Ext2Mul
@@ -997,6 +1035,10 @@ where
         | U32Gte
         | U32Min
         | U32Max
+        | I32Lt
+        | I32Lte
+        | I32Gt
+        | I32Gte
         | Drop
         | DropW
         | PadW
@@ -1075,6 +1117,7 @@ where
         | CSwapW
         | CDrop
         | CDropW
+        | Select
         | PushU8(_)
         | PushU16(_)
         | PushU32(_)
@@ -1092,6 +1135,7 @@ where
         | MemStore
         | MemStoreW
         | MemStream
+        | MemCopy
         | AdvPipe
         | AdvLoadW
         | Hash
@@ -1129,7 +1173,9 @@ where
     V: ?Sized + VisitMut<T>,
 {
     match options.into_inner() {
-        DebugOptions::StackTop(imm) => visitor.visit_mut_immediate_u8(imm),
+        DebugOptions::StackTop(imm)
+        | DebugOptions::StackHex(imm)
+        | DebugOptions::WatchField(_, imm) => visitor.visit_mut_immediate_u8(imm),
         DebugOptions::AdvStackTop(imm) => visitor.visit_mut_immediate_u16(imm),
         DebugOptions::LocalRangeFrom(imm) => visitor.visit_mut_immediate_u16(imm),
         DebugOptions::MemInterval(imm1, imm2) => {
@@ -23,7 +23,7 @@ pub use self::{
         KernelLibrary, Library, LibraryError, LibraryNamespace, LibraryPath, LibraryPathComponent,
         PathError, Version, VersionError,
     },
-    parser::{ModuleParser, ParsingError},
+    parser::{MemSourceResolver, ModuleParser, ParsingError, SourceResolver},
 };
 pub use self::{
     parse::{Parse, ParseOptions},
@@ -1,6 +1,7 @@
 use miden_core::errors::KernelError;
 
 use crate::{
+    LibraryNamespace,
     ast::QualifiedProcedureName,
     diagnostics::{Diagnostic, miette},
 };
@@ -20,4 +21,8 @@ pub enum LibraryError {
     KernelConversion(KernelError),
     #[error("invalid export: no procedure root for {procedure_path} procedure")]
     NoProcedureRootForExport { procedure_path: QualifiedProcedureName },
+    #[error("invalid library namespace: '{namespace}' is reserved")]
+    ReservedNamespace { namespace: LibraryNamespace },
+    #[error("duplicate export: {procedure_path} is exported more than once")]
+    DuplicateExport { procedure_path: QualifiedProcedureName },
 }
@@ -11,7 +11,7 @@ use miden_core::{
     utils::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable},
 };
 
-use crate::ast::QualifiedProcedureName;
+use crate::{ast::QualifiedProcedureName, debuginfo::SourceSpan};
 
 mod error;
 mod module;
@@ -27,6 +27,19 @@ pub use self::{
     path::{LibraryPath, LibraryPathComponent, PathError},
 };
 
+// CONSTANTS
+// ================================================================================================
+
+/// Magic string for detecting that a file is a binary-encoded [`Library`].
+const MAGIC: &[u8; 5] = b"MASL\0";
+
+/// The format version of a binary-encoded [`Library`].
+///
+/// If future modifications are made to this format, the version should be incremented by 1. This
+/// is independent of the format version of the encoded [`MastForest`], which is checked
+/// separately.
+const VERSION: [u8; 3] = [0, 0, 0];
+
 // LIBRARY
 // ================================================================================================
 
@@ -34,7 +47,7 @@ pub use self::{
 ///
 /// A library exports a set of one or more procedures. Currently, all exported procedures belong
 /// to the same top-level namespace.
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone)]
 pub struct Library {
     /// The content hash of this library, formed by hashing the roots of all exports in
     /// lexicographical order (by digest, not procedure name)
@@ -51,8 +64,27 @@ pub struct Library {
     exports: BTreeMap<QualifiedProcedureName, MastNodeId>,
     /// The MAST forest underlying this library.
     mast_forest: Arc<MastForest>,
+    /// The source spans of the exported procedures, if this library was assembled from source.
+    ///
+    /// Source spans are not serialized, and are not taken into account when comparing libraries.
+    source_spans: BTreeMap<QualifiedProcedureName, SourceSpan>,
+    /// The number of outputs declared by the stack signatures of the exported procedures, for
+    /// those exports which declare one.
+    ///
+    /// This is metadata only, and is not taken into account when comparing libraries.
+    output_arities: BTreeMap<QualifiedProcedureName, usize>,
 }
 
+impl PartialEq for Library {
+    fn eq(&self, other: &Self) -> bool {
+        self.digest == other.digest
+            && self.exports == other.exports
+            && self.mast_forest == other.mast_forest
+    }
+}
+
+impl Eq for Library {}
+
 impl AsRef<Library> for Library {
     #[inline(always)]
     fn as_ref(&self) -> &Library {
@@ -84,7 +116,93 @@ impl Library {
 
         let digest = compute_content_hash(&exports, &mast_forest);
 
-        Ok(Self { digest, exports, mast_forest })
+        Ok(Self {
+            digest,
+            exports,
+            mast_forest,
+            source_spans: BTreeMap::new(),
+            output_arities: BTreeMap::new(),
+        })
+    }
+
+    /// Produces a new library where the exported procedures are associated with the provided
+    /// source spans.
+    ///
+    /// Spans provided for procedures which are not exported from this library are ignored.
+    pub fn with_source_spans(
+        mut self,
+        source_spans: impl IntoIterator<Item = (QualifiedProcedureName, SourceSpan)>,
+    ) -> Self {
+        self.source_spans.extend(
+            source_spans
+                .into_iter()
+                .filter(|(proc_name, _)| self.exports.contains_key(proc_name)),
+        );
+        self
+    }
+
+    /// Produces a new library where the exported procedures are associated with the provided
+    /// output arities, i.e. the number of elements they leave on top of the operand stack.
+    ///
+    /// Arities provided for procedures which are not exported from this library are ignored.
+    pub fn with_output_arities(
+        mut self,
+        output_arities: impl IntoIterator<Item = (QualifiedProcedureName, usize)>,
+    ) -> Self {
+        self.output_arities.extend(
+            output_arities
+                .into_iter()
+                .filter(|(proc_name, _)| self.exports.contains_key(proc_name)),
+        );
+        self
+    }
+
+    /// Produces a new library where the top-level namespace of every exported procedure is
+    /// replaced with `namespace`, e.g. to vendor a library which would otherwise collide with
+    /// another library using the same namespace.
+    ///
+    /// Procedures in the [`MastForest`] refer to each other by MAST root rather than by path, so
+    /// the forest, and thus the MAST roots of all procedures, are unchanged.
+    ///
+    /// # Errors
+    /// Returns an error if `namespace` is reserved, or if two exports of this library would have
+    /// the same fully qualified name after renaming, which is only possible if this library
+    /// exports procedures from more than one namespace.
+    pub fn with_namespace(self, namespace: LibraryNamespace) -> Result<Self, LibraryError> {
+        if namespace.is_reserved() {
+            return Err(LibraryError::ReservedNamespace { namespace });
+        }
+
+        let rename = |mut proc_name: QualifiedProcedureName| {
+            proc_name.module.set_namespace(namespace.clone());
+            proc_name
+        };
+
+        let mut exports = BTreeMap::new();
+        for (proc_name, node_id) in self.exports {
+            let proc_name = rename(proc_name);
+            if exports.contains_key(&proc_name) {
+                return Err(LibraryError::DuplicateExport { procedure_path: proc_name });
+            }
+            exports.insert(proc_name, node_id);
+        }
+        let source_spans = self
+            .source_spans
+            .into_iter()
+            .map(|(proc_name, span)| (rename(proc_name), span))
+            .collect();
+        let output_arities = self
+            .output_arities
+            .into_iter()
+            .map(|(proc_name, arity)| (rename(proc_name), arity))
+            .collect();
+
+        Ok(Self {
+            exports,
+            source_spans,
+            output_arities,
+            ..self
+        })
     }
 
     /// Produces a new library with the existing [`MastForest`] and where all key/values in the
@@ -125,6 +243,20 @@ impl Library {
         *self.exports.get(proc_name).expect("procedure not exported from the library")
     }
 
+    /// Returns the source span of the specified exported procedure, or `None` if the procedure is
+    /// not exported from this library, or if this library does not retain source information
+    /// (e.g., because it was deserialized from its compiled form).
+    pub fn source_span(&self, proc_name: &QualifiedProcedureName) -> Option<SourceSpan> {
+        self.source_spans.get(proc_name).copied()
+    }
+
+    /// Returns the number of outputs declared by the stack signature of the specified exported
+    /// procedure, or `None` if the procedure is not exported from this library, or does not
+    /// declare a stack signature.
+    pub fn output_arity(&self, proc_name: &QualifiedProcedureName) -> Option<usize> {
+        self.output_arities.get(proc_name).copied()
+    }
+
     /// Returns true if the specified exported procedure is re-exported from a dependency.
     pub fn is_reexport(&self, proc_name: &QualifiedProcedureName) -> bool {
         self.exports
@@ -180,9 +312,38 @@ impl Library {
     }
 }
 
+/// The serialization format of [`Library`] is as follows:
+///
+/// (Metadata)
+/// - `MAGIC`
+/// - `VERSION`
+///
+/// (MAST forest)
+/// - `mast_forest` (`MastForest`)
+///
+/// (Exports)
+/// - `exports_len` (`usize`)
+/// - For each export:
+///   - `name` (`QualifiedProcedureName`)
+///   - `node_id` (`u32`)
+///
+/// (Output arities)
+/// - `output_arities_len` (`usize`)
+/// - For each export which declares an output arity:
+///   - `name` (`QualifiedProcedureName`)
+///   - `arity` (`usize`)
 impl Serializable for Library {
     fn write_into<W: ByteWriter>(&self, target: &mut W) {
-        let Self { digest: _, exports, mast_forest } = self;
+        let Self {
+            digest: _,
+            exports,
+            mast_forest,
+            source_spans: _,
+            output_arities,
+        } = self;
+
+        target.write_bytes(MAGIC);
+        target.write_bytes(&VERSION);
 
         mast_forest.write_into(target);
 
@@ -192,11 +353,32 @@ impl Serializable for Library {
             proc_name.name.write_into(target);
             target.write_u32(proc_node_id.as_u32());
         }
+
+        target.write_usize(output_arities.len());
+        for (proc_name, &arity) in output_arities {
+            proc_name.module.write_into(target);
+            proc_name.name.write_into(target);
+            target.write_usize(arity);
+        }
     }
 }
 
 impl Deserializable for Library {
     fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let magic: [u8; 5] = source.read_array()?;
+        if magic != *MAGIC {
+            return Err(DeserializationError::InvalidValue(format!(
+                "Invalid library magic bytes. Expected '{:?}', got '{:?}'",
+                *MAGIC, magic
+            )));
+        }
+        let version: [u8; 3] = source.read_array()?;
+        if version != VERSION {
+            return Err(DeserializationError::InvalidValue(format!(
+                "Unsupported library version. Got '{version:?}', but only '{VERSION:?}' is supported",
+            )));
+        }
+
         let mast_forest = Arc::new(MastForest::read_from(source)?);
 
         let num_exports = source.read_usize()?;
@@ -213,9 +395,29 @@ impl Deserializable for Library {
             exports.insert(proc_name, proc_node_id);
         }
 
+        let num_output_arities = source.read_usize()?;
+        let mut output_arities = BTreeMap::new();
+        for _ in 0..num_output_arities {
+            let proc_module = source.read()?;
+            let proc_name = source.read()?;
+            let proc_name = QualifiedProcedureName::new(proc_module, proc_name);
+            if !exports.contains_key(&proc_name) {
+                return Err(DeserializationError::InvalidValue(format!(
+                    "output arity specified for procedure '{proc_name}' which is not exported"
+                )));
+            }
+            output_arities.insert(proc_name, source.read_usize()?);
+        }
+
         let digest = compute_content_hash(&exports, &mast_forest);
 
-        Ok(Self { digest, exports, mast_forest })
+        Ok(Self {
+            digest,
+            exports,
+            mast_forest,
+            source_spans: BTreeMap::new(),
+            output_arities,
+        })
     }
 }
 
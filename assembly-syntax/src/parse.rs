@@ -12,6 +12,7 @@ use crate::{
     ast::{Module, ModuleKind},
     diagnostics::{IntoDiagnostic, NamedSource, Report, SourceCode, WrapErr, report},
     library::{LibraryNamespace, LibraryPath},
+    parser::SourceResolver,
 };
 
 // PARSE OPTIONS
@@ -34,6 +35,10 @@ pub struct ParseOptions {
     /// then the path will consist of just a namespace; using the value of `namespace` if provided,
     /// or deriving one from `kind`.
     pub path: Option<LibraryPath>,
+    /// The resolver used for `include."<path>"` directives in the parsed source.
+    ///
+    /// If unset, any `include` directive results in an error.
+    pub source_resolver: Option<Arc<dyn SourceResolver>>,
 }
 
 impl Default for ParseOptions {
@@ -42,6 +47,7 @@ impl Default for ParseOptions {
             kind: ModuleKind::Executable,
             warnings_as_errors: false,
             path: None,
+            source_resolver: None,
         }
     }
 }
@@ -191,6 +197,9 @@ impl Parse for Arc<SourceFile> {
         };
         let mut parser = Module::parser(options.kind);
         parser.set_warnings_as_errors(options.warnings_as_errors);
+        if let Some(resolver) = options.source_resolver {
+            parser.set_source_resolver(resolver);
+        }
         parser.parse(path, source_file)
     }
 }
@@ -243,6 +252,9 @@ impl Parse for Box<str> {
         let name = Uri::from(path.path().into_owned().into_boxed_str());
         let mut parser = Module::parser(options.kind);
         parser.set_warnings_as_errors(options.warnings_as_errors);
+        if let Some(resolver) = options.source_resolver {
+            parser.set_source_resolver(resolver);
+        }
         let content = SourceContent::new(SourceLanguage::Masm, name.clone(), self);
         let source_file = source_manager.load_from_raw_parts(name, content);
         parser.parse(path, source_file)
@@ -342,6 +354,9 @@ where
         let source_file = source_manager.load_from_raw_parts(name, content);
         let mut parser = Module::parser(options.kind);
         parser.set_warnings_as_errors(options.warnings_as_errors);
+        if let Some(resolver) = options.source_resolver {
+            parser.set_source_resolver(resolver);
+        }
         parser.parse(path, source_file)
     }
 }
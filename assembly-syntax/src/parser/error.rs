@@ -242,6 +242,19 @@ pub enum ParsingError {
         span: SourceSpan,
         count: usize,
     },
+    #[error("cannot push an empty string")]
+    #[diagnostic()]
+    EmptyPushString {
+        #[label]
+        span: SourceSpan,
+    },
+    #[error("invalid escape sequence in string literal: '\\{escape}'")]
+    #[diagnostic(help("supported escape sequences are '\\n', '\\t', '\\\\' and '\\\"'"))]
+    InvalidEscape {
+        #[label]
+        span: SourceSpan,
+        escape: char,
+    },
     #[error("expected a fully-qualified module path, e.g. `std::u64`")]
     UnqualifiedImport {
         #[label]
@@ -283,6 +296,22 @@ pub enum ParsingError {
         #[label]
         span: SourceSpan,
     },
+    #[error("unable to resolve included file '{path}'")]
+    #[diagnostic(help(
+        "make sure the file exists, and that an include resolver was provided to the parser"
+    ))]
+    UnresolvedInclude {
+        #[label]
+        span: SourceSpan,
+        path: String,
+    },
+    #[error("cyclic include of '{path}'")]
+    #[diagnostic(help("the file is already being included by one of the files including it"))]
+    CyclicInclude {
+        #[label]
+        span: SourceSpan,
+        path: String,
+    },
 }
 
 impl ParsingError {
@@ -316,6 +345,9 @@ impl PartialEq for ParsingError {
                 Self::ImmediateOutOfRange { range: r, .. },
             ) => l == r,
             (Self::PushOverflow { count: l, .. }, Self::PushOverflow { count: r, .. }) => l == r,
+            (Self::InvalidEscape { escape: l, .. }, Self::InvalidEscape { escape: r, .. }) => {
+                l == r
+            },
             (
                 Self::UnrecognizedToken { token: ltok, expected: lexpect, .. },
                 Self::UnrecognizedToken { token: rtok, expected: rexpect, .. },
@@ -434,11 +434,9 @@ impl<'input> Lexer<'input> {
                 '\\' => {
                     is_identifier = false;
                     self.skip();
-                    match self.read() {
-                        '"' | '\n' => {
-                            self.skip();
-                        },
-                        _ => (),
+                    // skip the escaped character, so that e.g. `\\` does not escape the next one
+                    if self.read() != '\0' {
+                        self.skip();
                     }
                 },
                 '"' => {
@@ -0,0 +1,103 @@
+use alloc::{collections::BTreeMap, sync::Arc};
+use core::fmt;
+
+use miden_debug_types::SourceFile;
+
+use crate::LibraryPath;
+
+// SOURCE RESOLVER
+// ================================================================================================
+
+/// A resolver of the source files referenced by Miden Assembly code, i.e. the files spliced into
+/// a module by `include."<path>"` directives, and the modules imported by `use` directives.
+///
+/// Any closure of the form `Fn(&str) -> Option<Arc<SourceFile>>` is a [SourceResolver] which only
+/// resolves includes.
+pub trait SourceResolver: Send + Sync {
+    /// Returns the source file referenced by `include."<path>"`, or `None` if the path cannot be
+    /// resolved.
+    fn resolve_include(&self, path: &str) -> Option<Arc<SourceFile>> {
+        let _ = path;
+        None
+    }
+
+    /// Returns the source file of the module with the specified path, or `None` if the module is
+    /// not known to this resolver.
+    fn resolve_module(&self, path: &LibraryPath) -> Option<Arc<SourceFile>> {
+        let _ = path;
+        None
+    }
+}
+
+impl<F> SourceResolver for F
+where
+    F: Fn(&str) -> Option<Arc<SourceFile>> + Send + Sync,
+{
+    fn resolve_include(&self, path: &str) -> Option<Arc<SourceFile>> {
+        self(path)
+    }
+}
+
+impl fmt::Debug for dyn SourceResolver {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("SourceResolver")
+    }
+}
+
+// MEMORY SOURCE RESOLVER
+// ================================================================================================
+
+/// A resolver of module paths to the source files of the corresponding modules, which are stored
+/// in memory.
+///
+/// This allows multi-module programs and libraries to be assembled without access to a file
+/// system, and is available in `no_std` environments. Includes are resolved to the stored source
+/// file whose URI is equal to the included path.
+#[derive(Debug, Clone, Default)]
+pub struct MemSourceResolver {
+    sources: BTreeMap<LibraryPath, Arc<SourceFile>>,
+}
+
+impl MemSourceResolver {
+    /// Returns a new [MemSourceResolver] instantiated with the provided module sources.
+    pub fn new(sources: impl IntoIterator<Item = (LibraryPath, Arc<SourceFile>)>) -> Self {
+        Self { sources: sources.into_iter().collect() }
+    }
+
+    /// Inserts the source file of the module with the specified path into this resolver.
+    ///
+    /// Returns the previously inserted source file of the module, if any.
+    pub fn insert(
+        &mut self,
+        path: LibraryPath,
+        source: Arc<SourceFile>,
+    ) -> Option<Arc<SourceFile>> {
+        self.sources.insert(path, source)
+    }
+
+    /// Returns an iterator over the paths of the modules known to this resolver, together with
+    /// their source files, ordered by path.
+    pub fn iter(&self) -> impl Iterator<Item = (&LibraryPath, &Arc<SourceFile>)> {
+        self.sources.iter()
+    }
+
+    /// Returns the number of modules known to this resolver.
+    pub fn len(&self) -> usize {
+        self.sources.len()
+    }
+
+    /// Returns true if this resolver does not contain any modules.
+    pub fn is_empty(&self) -> bool {
+        self.sources.is_empty()
+    }
+}
+
+impl SourceResolver for MemSourceResolver {
+    fn resolve_include(&self, path: &str) -> Option<Arc<SourceFile>> {
+        self.sources.values().find(|source| source.uri().as_str() == path).cloned()
+    }
+
+    fn resolve_module(&self, path: &LibraryPath) -> Option<Arc<SourceFile>> {
+        self.sources.get(path).cloned()
+    }
+}
@@ -16,17 +16,19 @@ lalrpop_util::lalrpop_mod!(
 
 mod error;
 mod lexer;
+mod mem_resolver;
 mod scanner;
 mod token;
 
 use alloc::{boxed::Box, collections::BTreeSet, string::ToString, sync::Arc, vec::Vec};
 
-use miden_debug_types::{SourceFile, SourceLanguage, SourceManager, Uri};
+use miden_debug_types::{SourceFile, SourceLanguage, SourceManager, Spanned, Uri};
 use miden_utils_diagnostics::Report;
 
 pub use self::{
     error::{BinErrorKind, HexErrorKind, LiteralErrorKind, ParsingError},
     lexer::Lexer,
+    mem_resolver::{MemSourceResolver, SourceResolver},
     scanner::Scanner,
     token::{BinEncodedValue, DocumentationType, IntValue, Token, WordValue},
 };
@@ -68,6 +70,10 @@ pub struct ModuleParser {
     interned: BTreeSet<Arc<str>>,
     /// When true, all warning diagnostics are promoted to error severity
     warnings_as_errors: bool,
+    /// The resolver used for `include` directives, if any.
+    ///
+    /// When not set, any `include` directive results in an error.
+    source_resolver: Option<Arc<dyn SourceResolver>>,
 }
 
 impl ModuleParser {
@@ -77,6 +83,7 @@ impl ModuleParser {
             kind,
             interned: Default::default(),
             warnings_as_errors: false,
+            source_resolver: None,
         }
     }
 
@@ -85,6 +92,15 @@ impl ModuleParser {
         self.warnings_as_errors = yes;
     }
 
+    /// Configure this parser to resolve `include."<path>"` directives using `resolver`.
+    ///
+    /// The forms of an included file are parsed from the source file returned by `resolver`, and
+    /// take the place of the `include` directive. They retain their own source spans, so that
+    /// parsing errors in an included file are reported against that file.
+    pub fn set_source_resolver(&mut self, resolver: Arc<dyn SourceResolver>) {
+        self.source_resolver = Some(resolver);
+    }
+
     /// Parse a [ast::Module] from `source`, and give it the provided `path`.
     pub fn parse(
         &mut self,
@@ -93,7 +109,18 @@ impl ModuleParser {
     ) -> Result<Box<ast::Module>, Report> {
         let forms = parse_forms_internal(source.clone(), &mut self.interned)
             .map_err(|err| Report::new(err).with_source_code(source.clone()))?;
-        sema::analyze(source, self.kind, path, forms, self.warnings_as_errors).map_err(Report::new)
+        let mut include_stack = vec![source.uri().clone()];
+        let mut included_files = Vec::new();
+        let forms = resolve_includes(
+            &source,
+            forms,
+            self.source_resolver.as_deref(),
+            &mut include_stack,
+            &mut included_files,
+            &mut self.interned,
+        )?;
+        sema::analyze(source, self.kind, path, forms, included_files, self.warnings_as_errors)
+            .map_err(Report::new)
     }
 
     /// Parse a [ast::Module], `name`, from `path`.
@@ -164,6 +191,58 @@ fn parse_forms_internal(
         .map_err(|err| ParsingError::from_parse_error(source_id, err))
 }
 
+/// Replaces every `include` form in `forms` with the forms of the included file, recursively.
+///
+/// `include_stack` contains the URIs of the files currently being included, starting with the
+/// root file, and is used to detect cyclic includes. Every file that gets included is appended to
+/// `included_files`.
+fn resolve_includes(
+    source: &Arc<SourceFile>,
+    forms: Vec<ast::Form>,
+    resolver: Option<&dyn SourceResolver>,
+    include_stack: &mut Vec<Uri>,
+    included_files: &mut Vec<Arc<SourceFile>>,
+    interned: &mut BTreeSet<Arc<str>>,
+) -> Result<Vec<ast::Form>, Report> {
+    let mut resolved = Vec::with_capacity(forms.len());
+    for form in forms {
+        let ast::Form::Include(path) = form else {
+            resolved.push(form);
+            continue;
+        };
+
+        let span = path.span();
+        let included = resolver
+            .and_then(|resolver| resolver.resolve_include(path.as_str()))
+            .ok_or_else(|| {
+                Report::new(ParsingError::UnresolvedInclude { span, path: path.to_string() })
+                    .with_source_code(source.clone())
+            })?;
+        if include_stack.contains(included.uri()) {
+            return Err(Report::new(ParsingError::CyclicInclude { span, path: path.to_string() })
+                .with_source_code(source.clone()));
+        }
+
+        let included_forms = parse_forms_internal(included.clone(), interned)
+            .map_err(|err| Report::new(err).with_source_code(included.clone()))?;
+        include_stack.push(included.uri().clone());
+        let included_forms = resolve_includes(
+            &included,
+            included_forms,
+            resolver,
+            include_stack,
+            included_files,
+            interned,
+        )?;
+        include_stack.pop();
+        included_files.push(included);
+
+        resolved.extend(included_forms);
+    }
+
+    Ok(resolved)
+}
+
 // DIRECTORY PARSER
 // ================================================================================================
 
@@ -325,6 +404,33 @@ mod module_walker {
     }
 }
 
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns the UTF-8 bytes of the contents of the string literal `value`, with the escape
+/// sequences `\n`, `\t`, `\\` and `\"` replaced by the characters they denote.
+///
+/// Returns the character following the backslash of the first unsupported escape sequence, if any.
+fn unescape_string(value: &str) -> Result<Vec<u8>, char> {
+    let mut bytes = Vec::with_capacity(value.len());
+    let mut chars = value.chars();
+    while let Some(c) = chars.next() {
+        let c = match c {
+            '\\' => match chars.next() {
+                Some('n') => '\n',
+                Some('t') => '\t',
+                Some(c @ ('\\' | '"')) => c,
+                Some(c) => return Err(c),
+                None => return Err('\\'),
+            },
+            c => c,
+        };
+        let mut buf = [0; 4];
+        bytes.extend_from_slice(c.encode_utf8(&mut buf).as_bytes());
+    }
+    Ok(bytes)
+}
+
 // TESTS
 // ================================================================================================
 
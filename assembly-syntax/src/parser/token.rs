@@ -196,6 +196,7 @@ pub enum Token<'input> {
     InsertHdwordWithDomain,
     InsertHperm,
     InsertMem,
+    InsertMemHashed,
     AdvLoadw,
     AdvPipe,
     AdvPush,
@@ -208,11 +209,16 @@ pub enum Token<'input> {
     PushSmtget,
     PushU64Div,
     PushFalconDiv,
+    PushSha256,
+    PushKeccak256,
+    PushBatchInv,
+    PushRandom,
     And,
     Assert,
     Assertz,
     AssertEq,
     AssertEqw,
+    AssertLt,
     ArithmeticCircuitEval,
     Begin,
     Breakpoint,
@@ -254,13 +260,20 @@ pub enum Token<'input> {
     Gte,
     Hash,
     HasMapkey,
+    Hex,
     HornerBase,
     HornerExt,
     Hperm,
     Hmerge,
     If,
+    I32Gt,
+    I32Gte,
+    I32Lt,
+    I32Lte,
     ILog2,
+    Include,
     Inv,
+    IsEven,
     IsOdd,
     Local,
     Locaddr,
@@ -271,6 +284,7 @@ pub enum Token<'input> {
     Lt,
     Lte,
     Mem,
+    MemCopy,
     MemLoad,
     MemLoadw,
     MemStore,
@@ -297,6 +311,7 @@ pub enum Token<'input> {
     Push,
     Repeat,
     Sdepth,
+    Select,
     Stack,
     Sub,
     Swap,
@@ -311,6 +326,7 @@ pub enum Token<'input> {
     U32Assert2,
     U32Assertw,
     U32Cast,
+    U32CheckedMadd,
     U32Div,
     U32Divmod,
     U32Gt,
@@ -345,6 +361,7 @@ pub enum Token<'input> {
     U32WrappingMul,
     U32WrappingSub,
     U32Xor,
+    WatchField,
     While,
     Xor,
     At,
@@ -386,6 +403,7 @@ impl fmt::Display for Token<'_> {
             Token::InsertHdwordWithDomain => write!(f, "insert_hdword_d"),
             Token::InsertHperm => write!(f, "insert_hperm"),
             Token::InsertMem => write!(f, "insert_mem"),
+            Token::InsertMemHashed => write!(f, "insert_mem_hashed"),
             Token::AdvLoadw => write!(f, "adv_loadw"),
             Token::AdvPipe => write!(f, "adv_pipe"),
             Token::AdvPush => write!(f, "adv_push"),
@@ -397,11 +415,16 @@ impl fmt::Display for Token<'_> {
             Token::PushSmtget => write!(f, "push_smtget"),
             Token::PushU64Div => write!(f, "push_u64div"),
             Token::PushFalconDiv => write!(f, "push_falcon_div"),
+            Token::PushSha256 => write!(f, "push_sha256"),
+            Token::PushKeccak256 => write!(f, "push_keccak256"),
+            Token::PushBatchInv => write!(f, "push_batch_inv"),
+            Token::PushRandom => write!(f, "push_random"),
             Token::And => write!(f, "and"),
             Token::Assert => write!(f, "assert"),
             Token::Assertz => write!(f, "assertz"),
             Token::AssertEq => write!(f, "assert_eq"),
             Token::AssertEqw => write!(f, "assert_eqw"),
+            Token::AssertLt => write!(f, "assert_lt"),
             Token::ArithmeticCircuitEval => write!(f, "arithmetic_circuit_eval"),
             Token::Begin => write!(f, "begin"),
             Token::Breakpoint => write!(f, "breakpoint"),
@@ -443,11 +466,18 @@ impl fmt::Display for Token<'_> {
             Token::Gte => write!(f, "gte"),
             Token::Hash => write!(f, "hash"),
             Token::HasMapkey => write!(f, "has_mapkey"),
+            Token::Hex => write!(f, "hex"),
             Token::Hperm => write!(f, "hperm"),
             Token::Hmerge => write!(f, "hmerge"),
             Token::If => write!(f, "if"),
+            Token::I32Gt => write!(f, "i32gt"),
+            Token::I32Gte => write!(f, "i32gte"),
+            Token::I32Lt => write!(f, "i32lt"),
+            Token::I32Lte => write!(f, "i32lte"),
             Token::ILog2 => write!(f, "ilog2"),
+            Token::Include => write!(f, "include"),
             Token::Inv => write!(f, "inv"),
+            Token::IsEven => write!(f, "is_even"),
             Token::IsOdd => write!(f, "is_odd"),
             Token::Local => write!(f, "local"),
             Token::Locaddr => write!(f, "locaddr"),
@@ -458,6 +488,7 @@ impl fmt::Display for Token<'_> {
             Token::Lt => write!(f, "lt"),
             Token::Lte => write!(f, "lte"),
             Token::Mem => write!(f, "mem"),
+            Token::MemCopy => write!(f, "mem_copy"),
             Token::MemLoad => write!(f, "mem_load"),
             Token::MemLoadw => write!(f, "mem_loadw"),
             Token::MemStore => write!(f, "mem_store"),
@@ -486,6 +517,7 @@ impl fmt::Display for Token<'_> {
             Token::HornerExt => write!(f, "horner_eval_ext"),
             Token::Repeat => write!(f, "repeat"),
             Token::Sdepth => write!(f, "sdepth"),
+            Token::Select => write!(f, "select"),
             Token::Stack => write!(f, "stack"),
             Token::Sub => write!(f, "sub"),
             Token::Swap => write!(f, "swap"),
@@ -500,6 +532,7 @@ impl fmt::Display for Token<'_> {
             Token::U32Assert2 => write!(f, "u32assert2"),
             Token::U32Assertw => write!(f, "u32assertw"),
             Token::U32Cast => write!(f, "u32cast"),
+            Token::U32CheckedMadd => write!(f, "u32checked_madd"),
             Token::U32Div => write!(f, "u32div"),
             Token::U32Divmod => write!(f, "u32divmod"),
             Token::U32Gt => write!(f, "u32gt"),
@@ -534,6 +567,7 @@ impl fmt::Display for Token<'_> {
             Token::U32WrappingMul => write!(f, "u32wrapping_mul"),
             Token::U32WrappingSub => write!(f, "u32wrapping_sub"),
             Token::U32Xor => write!(f, "u32xor"),
+            Token::WatchField => write!(f, "watch_field"),
             Token::While => write!(f, "while"),
             Token::Xor => write!(f, "xor"),
             Token::At => write!(f, "@"),
@@ -581,6 +615,7 @@ impl<'input> Token<'input> {
                 | Token::InsertHdwordWithDomain
                 | Token::InsertHperm
                 | Token::InsertMem
+                | Token::InsertMemHashed
                 | Token::AdvLoadw
                 | Token::AdvPipe
                 | Token::AdvPush
@@ -593,11 +628,16 @@ impl<'input> Token<'input> {
                 | Token::PushSmtget
                 | Token::PushU64Div
                 | Token::PushFalconDiv
+                | Token::PushSha256
+                | Token::PushKeccak256
+                | Token::PushBatchInv
+                | Token::PushRandom
                 | Token::And
                 | Token::Assert
                 | Token::Assertz
                 | Token::AssertEq
                 | Token::AssertEqw
+                | Token::AssertLt
                 | Token::ArithmeticCircuitEval
                 | Token::Breakpoint
                 | Token::Caller
@@ -631,12 +671,18 @@ impl<'input> Token<'input> {
                 | Token::Gt
                 | Token::Gte
                 | Token::Hash
+                | Token::Hex
                 | Token::Hperm
                 | Token::Hmerge
                 | Token::HornerBase
                 | Token::HornerExt
+                | Token::I32Gt
+                | Token::I32Gte
+                | Token::I32Lt
+                | Token::I32Lte
                 | Token::ILog2
                 | Token::Inv
+                | Token::IsEven
                 | Token::IsOdd
                 | Token::Local
                 | Token::Locaddr
@@ -647,6 +693,7 @@ impl<'input> Token<'input> {
                 | Token::Lt
                 | Token::Lte
                 | Token::Mem
+                | Token::MemCopy
                 | Token::MemLoad
                 | Token::MemLoadw
                 | Token::MemStore
@@ -672,6 +719,7 @@ impl<'input> Token<'input> {
                 | Token::Push
                 | Token::Repeat
                 | Token::Sdepth
+                | Token::Select
                 | Token::Stack
                 | Token::Sub
                 | Token::Swap
@@ -684,6 +732,7 @@ impl<'input> Token<'input> {
                 | Token::U32Assert2
                 | Token::U32Assertw
                 | Token::U32Cast
+                | Token::U32CheckedMadd
                 | Token::U32Div
                 | Token::U32Divmod
                 | Token::U32Gt
@@ -718,6 +767,7 @@ impl<'input> Token<'input> {
                 | Token::U32WrappingMul
                 | Token::U32WrappingSub
                 | Token::U32Xor
+                | Token::WatchField
                 | Token::Xor
         )
     }
@@ -731,6 +781,7 @@ impl<'input> Token<'input> {
         ("insert_hdword_d", Token::InsertHdwordWithDomain),
         ("insert_hperm", Token::InsertHperm),
         ("insert_mem", Token::InsertMem),
+        ("insert_mem_hashed", Token::InsertMemHashed),
         ("adv_loadw", Token::AdvLoadw),
         ("adv_pipe", Token::AdvPipe),
         ("adv_push", Token::AdvPush),
@@ -743,11 +794,16 @@ impl<'input> Token<'input> {
         ("push_smtget", Token::PushSmtget),
         ("push_u64div", Token::PushU64Div),
         ("push_falcon_div", Token::PushFalconDiv),
+        ("push_sha256", Token::PushSha256),
+        ("push_keccak256", Token::PushKeccak256),
+        ("push_batch_inv", Token::PushBatchInv),
+        ("push_random", Token::PushRandom),
         ("and", Token::And),
         ("assert", Token::Assert),
         ("assertz", Token::Assertz),
         ("assert_eq", Token::AssertEq),
         ("assert_eqw", Token::AssertEqw),
+        ("assert_lt", Token::AssertLt),
         ("begin", Token::Begin),
         ("breakpoint", Token::Breakpoint),
         ("caller", Token::Caller),
@@ -788,11 +844,18 @@ impl<'input> Token<'input> {
         ("gte", Token::Gte),
         ("hash", Token::Hash),
         ("has_mapkey", Token::HasMapkey),
+        ("hex", Token::Hex),
         ("hperm", Token::Hperm),
         ("hmerge", Token::Hmerge),
         ("if", Token::If),
+        ("i32gt", Token::I32Gt),
+        ("i32gte", Token::I32Gte),
+        ("i32lt", Token::I32Lt),
+        ("i32lte", Token::I32Lte),
         ("ilog2", Token::ILog2),
+        ("include", Token::Include),
         ("inv", Token::Inv),
+        ("is_even", Token::IsEven),
         ("is_odd", Token::IsOdd),
         ("local", Token::Local),
         ("locaddr", Token::Locaddr),
@@ -803,6 +866,7 @@ impl<'input> Token<'input> {
         ("lt", Token::Lt),
         ("lte", Token::Lte),
         ("mem", Token::Mem),
+        ("mem_copy", Token::MemCopy),
         ("mem_load", Token::MemLoad),
         ("mem_loadw", Token::MemLoadw),
         ("mem_store", Token::MemStore),
@@ -831,6 +895,7 @@ impl<'input> Token<'input> {
         ("horner_eval_ext", Token::HornerExt),
         ("repeat", Token::Repeat),
         ("sdepth", Token::Sdepth),
+        ("select", Token::Select),
         ("stack", Token::Stack),
         ("sub", Token::Sub),
         ("swap", Token::Swap),
@@ -845,6 +910,7 @@ impl<'input> Token<'input> {
         ("u32assert2", Token::U32Assert2),
         ("u32assertw", Token::U32Assertw),
         ("u32cast", Token::U32Cast),
+        ("u32checked_madd", Token::U32CheckedMadd),
         ("u32div", Token::U32Div),
         ("u32divmod", Token::U32Divmod),
         ("u32gt", Token::U32Gt),
@@ -879,6 +945,7 @@ impl<'input> Token<'input> {
         ("u32wrapping_mul", Token::U32WrappingMul),
         ("u32wrapping_sub", Token::U32WrappingSub),
         ("u32xor", Token::U32Xor),
+        ("watch_field", Token::WatchField),
         ("while", Token::While),
         ("xor", Token::Xor),
     ];
@@ -17,6 +17,8 @@ pub struct AnalysisContext {
     procedures: BTreeSet<ProcedureName>,
     errors: Vec<SemanticAnalysisError>,
     source_file: Arc<SourceFile>,
+    /// The files included into the module via `include` directives
+    included_files: Vec<Arc<SourceFile>>,
     warnings_as_errors: bool,
 }
 
@@ -27,10 +29,15 @@ impl AnalysisContext {
             procedures: Default::default(),
             errors: Default::default(),
             source_file,
+            included_files: Default::default(),
             warnings_as_errors: false,
         }
     }
 
+    pub fn set_included_files(&mut self, included_files: Vec<Arc<SourceFile>>) {
+        self.included_files = included_files;
+    }
+
     pub fn set_warnings_as_errors(&mut self, yes: bool) {
         self.warnings_as_errors = yes;
     }
@@ -65,10 +72,9 @@ impl AnalysisContext {
             },
             Err(err) => {
                 self.errors.push(err);
-                let errors = core::mem::take(&mut self.errors);
                 Err(SyntaxError {
                     source_file: self.source_file.clone(),
-                    errors,
+                    errors: self.take_errors(),
                 })
             },
         }
@@ -79,9 +85,12 @@ impl AnalysisContext {
             ConstantExpr::Literal(_) | ConstantExpr::String(_) => Ok((*value).clone()),
             ConstantExpr::Word(_) => Ok((*value).clone()),
             ConstantExpr::Var(name) => self.get_constant(name).cloned(),
-            ConstantExpr::BinaryOp { op, lhs, rhs, .. } => {
+            ConstantExpr::BinaryOp { span, op, lhs, rhs } => {
                 let rhs = self.const_eval(rhs)?.expect_literal();
                 let lhs = self.const_eval(lhs)?.expect_literal();
+                if matches!(op, ConstantOp::Div | ConstantOp::IntDiv) && rhs.as_int() == 0 {
+                    return Err(SemanticAnalysisError::DivisionByZero { span: *span });
+                }
                 let felt = match op {
                     ConstantOp::Add => lhs + rhs,
                     ConstantOp::Sub => lhs - rhs,
@@ -106,6 +115,11 @@ impl AnalysisContext {
         }
     }
 
+    /// Get an iterator over the constants defined so far, with their values fully evaluated
+    pub fn constants(&self) -> impl Iterator<Item = &Constant> {
+        self.constants.values()
+    }
+
     /// Get the error message bound to `name`
     ///
     /// Returns `Err` if the symbol is undefined
@@ -135,18 +149,18 @@ impl AnalysisContext {
         if self.has_errors() {
             Err(SyntaxError {
                 source_file: self.source_file.clone(),
-                errors: core::mem::take(&mut self.errors),
+                errors: self.take_errors(),
             })
         } else {
             Ok(())
         }
     }
 
-    pub fn into_result(self) -> Result<(), SyntaxError> {
+    pub fn into_result(mut self) -> Result<(), SyntaxError> {
         if self.has_errors() {
             Err(SyntaxError {
                 source_file: self.source_file.clone(),
-                errors: self.errors,
+                errors: self.take_errors(),
             })
         } else {
             self.emit_warnings();
@@ -154,15 +168,41 @@ impl AnalysisContext {
         }
     }
 
+    /// Takes the errors gathered so far, grouping those which occurred in an included file under
+    /// a [SemanticAnalysisError::InIncludedFile], so that they are rendered against that file.
+    fn take_errors(&mut self) -> Vec<SemanticAnalysisError> {
+        let errors = core::mem::take(&mut self.errors);
+        if self.included_files.is_empty() {
+            return errors;
+        }
+
+        let mut grouped = Vec::with_capacity(errors.len());
+        let mut included = BTreeMap::<usize, Vec<SemanticAnalysisError>>::new();
+        for error in errors {
+            let source_id = error.span().map(|span| span.source_id());
+            match self.included_files.iter().position(|file| Some(file.id()) == source_id) {
+                Some(index) => included.entry(index).or_default().push(error),
+                None => grouped.push(error),
+            }
+        }
+        grouped.extend(included.into_iter().map(|(index, errors)| {
+            SemanticAnalysisError::InIncludedFile {
+                source_file: self.included_files[index].clone(),
+                errors,
+            }
+        }));
+        grouped
+    }
+
     #[cfg(feature = "std")]
-    fn emit_warnings(self) {
+    fn emit_warnings(mut self) {
         use crate::diagnostics::Report;
 
         if !self.errors.is_empty() {
             // Emit warnings to stderr
             let warning = Report::from(super::errors::SyntaxWarning {
-                source_file: self.source_file,
-                errors: self.errors,
+                source_file: self.source_file.clone(),
+                errors: self.take_errors(),
             });
             std::eprintln!("{warning}");
         }
@@ -47,6 +47,20 @@ pub enum SemanticAnalysisError {
         "ensure you define an entrypoint somewhere in the body with `begin`..`end`"
     ))]
     MissingEntrypoint,
+    #[error("in included file '{}'", source_file.uri())]
+    #[diagnostic(help("see emitted diagnostics for details"))]
+    InIncludedFile {
+        #[source_code]
+        source_file: Arc<SourceFile>,
+        #[related]
+        errors: Vec<SemanticAnalysisError>,
+    },
+    #[error("unresolved include")]
+    #[diagnostic(help("includes must be resolved by the parser before semantic analysis"))]
+    UnresolvedInclude {
+        #[label]
+        span: SourceSpan,
+    },
     #[error("invalid module: unexpected entrypoint definition")]
     #[diagnostic(help("library modules cannot contain `begin`..`end` blocks"))]
     UnexpectedEntrypoint {
@@ -152,6 +166,14 @@ pub enum SemanticAnalysisError {
         #[label]
         span: SourceSpan,
     },
+    #[error("invalid recursive procedure call")]
+    #[diagnostic(help(
+        "this call is part of a cycle of calls between procedures in this module, which is not allowed"
+    ))]
+    MutuallyRecursive {
+        #[label]
+        span: SourceSpan,
+    },
     #[error("invalid immediate: value is larger than expected range")]
     #[diagnostic()]
     ImmediateOverflow {
@@ -194,11 +216,101 @@ pub enum SemanticAnalysisError {
         #[label]
         span: SourceSpan,
     },
+    #[error("invalid constant expression: division by zero")]
+    #[diagnostic()]
+    DivisionByZero {
+        #[label]
+        span: SourceSpan,
+    },
     #[error("advmap key already defined")]
     AdvMapKeyAlreadyDefined {
         #[label]
         span: SourceSpan,
     },
+    #[error("invalid stack signature")]
+    #[diagnostic(
+        severity(Warning),
+        help("stack signatures must be of the form `stack: [a, b, ...] -> [c, ...]`")
+    )]
+    InvalidStackSignature {
+        #[label]
+        span: SourceSpan,
+    },
+    #[error(
+        "stack effect mismatch: the signature declares a net effect of {expected}, but the body has a net effect of {actual}"
+    )]
+    #[diagnostic(
+        severity(Warning),
+        help("check that the body of this procedure matches its declared stack signature")
+    )]
+    StackEffectMismatch {
+        #[label]
+        span: SourceSpan,
+        expected: isize,
+        actual: isize,
+    },
+    #[error(
+        "inconsistent stack effect: the branches of this conditional have net effects of {then_effect} and {else_effect}"
+    )]
+    #[diagnostic(
+        severity(Warning),
+        help("both branches of a conditional should leave the operand stack at the same depth")
+    )]
+    UnbalancedBranches {
+        #[label]
+        span: SourceSpan,
+        then_effect: isize,
+        else_effect: isize,
+    },
+    #[error("inconsistent stack effect: the body of this loop has a net effect of {body_effect}")]
+    #[diagnostic(
+        severity(Warning),
+        help(
+            "the body of a loop should push exactly one element onto the operand stack, the condition for the next iteration"
+        )
+    )]
+    UnbalancedLoop {
+        #[label]
+        span: SourceSpan,
+        body_effect: isize,
+    },
+}
+
+impl SemanticAnalysisError {
+    /// Returns the primary source span of this error, if it has one
+    pub fn span(&self) -> Option<SourceSpan> {
+        match self {
+            Self::MissingEntrypoint | Self::InIncludedFile { .. } => None,
+            Self::UnresolvedInclude { span }
+            | Self::UnexpectedEntrypoint { span }
+            | Self::MultipleEntrypoints { span, .. }
+            | Self::UnexpectedExport { span }
+            | Self::SymbolConflict { span, .. }
+            | Self::SymbolUndefined { span }
+            | Self::UnusedImport { span }
+            | Self::MissingImport { span }
+            | Self::ImportConflict { span, .. }
+            | Self::ReexportFromKernel { span }
+            | Self::SyscallInKernel { span }
+            | Self::CallInKernel { span }
+            | Self::CallerInKernel { span }
+            | Self::InvalidSyscallTarget { span }
+            | Self::InvalidRecursiveCall { span }
+            | Self::SelfRecursive { span }
+            | Self::MutuallyRecursive { span }
+            | Self::ImmediateOverflow { span }
+            | Self::LimitExceeded { span, .. }
+            | Self::UnusedDocstring { span }
+            | Self::ImportDocstring { span }
+            | Self::InvalidConstant { span }
+            | Self::DivisionByZero { span }
+            | Self::AdvMapKeyAlreadyDefined { span }
+            | Self::InvalidStackSignature { span }
+            | Self::StackEffectMismatch { span, .. }
+            | Self::UnbalancedBranches { span, .. }
+            | Self::UnbalancedLoop { span, .. } => Some(*span),
+        }
+    }
 }
 
 /// Represents a system limit that was exceeded
@@ -12,7 +12,7 @@ use alloc::{
 use miden_core::{Word, crypto::hash::Rpo256};
 use miden_debug_types::{SourceFile, Span, Spanned};
 
-use self::passes::{ConstEvalVisitor, VerifyInvokeTargets};
+use self::passes::{ConstEvalVisitor, VerifyInvokeTargets, VerifyStackEffect};
 pub use self::{
     context::AnalysisContext,
     errors::{SemanticAnalysisError, SyntaxError},
@@ -29,14 +29,19 @@ use crate::{LibraryPath, ast::*, parser::WordValue};
 ///   * Constants referenced by name are replaced with the value of that constant.
 ///   * Calls to imported procedures are resolved concretely
 /// * Semantic analysis is performed on the module to validate it
+///
+/// `included_files` are the files whose forms were spliced into `forms` by `include` directives,
+/// and are used to report diagnostics against the file they originate from.
 pub fn analyze(
     source: Arc<SourceFile>,
     kind: ModuleKind,
     path: LibraryPath,
     forms: Vec<Form>,
+    included_files: Vec<Arc<SourceFile>>,
     warnings_as_errors: bool,
 ) -> Result<Box<Module>, SyntaxError> {
     let mut analyzer = AnalysisContext::new(source.clone());
+    analyzer.set_included_files(included_files);
     analyzer.set_warnings_as_errors(warnings_as_errors);
 
     let mut module = Box::new(Module::new(kind, path).with_span(source.source_span()));
@@ -57,6 +62,10 @@ pub fn analyze(
             Form::Constant(constant) => {
                 analyzer.define_constant(constant.with_docs(docs.take()))?;
             },
+            Form::Include(path) => {
+                docs.take();
+                analyzer.error(SemanticAnalysisError::UnresolvedInclude { span: path.span() });
+            },
             Form::Import(import) => {
                 if let Some(docs) = docs.take() {
                     analyzer.error(SemanticAnalysisError::ImportDocstring { span: docs.span() });
@@ -115,6 +124,13 @@ pub fn analyze(
 
     analyzer.has_failed()?;
 
+    // Retain the values of all constants, so that other modules can refer to them
+    module.constants.extend(
+        analyzer
+            .constants()
+            .map(|constant| (constant.name.clone(), constant.value.clone())),
+    );
+
     // Run procedure checks
     visit_procedures(&mut module, &mut analyzer)?;
 
@@ -151,7 +167,7 @@ fn visit_procedures(
 
                 // Evaluate all named immediates to their concrete values
                 {
-                    let mut visitor = ConstEvalVisitor::new(analyzer);
+                    let mut visitor = ConstEvalVisitor::new(analyzer, module);
                     let _ = visitor.visit_mut_procedure(&mut procedure);
                 }
 
@@ -170,6 +186,10 @@ fn visit_procedures(
                     );
                     let _ = visitor.visit_mut_procedure(&mut procedure);
                 }
+
+                // Finally, check the procedure body against its declared stack signature, if any
+                VerifyStackEffect::new(analyzer).verify_procedure(&procedure);
+
                 module.procedures.push(Export::Procedure(procedure));
             },
             Export::Alias(alias) => {
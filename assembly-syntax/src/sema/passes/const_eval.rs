@@ -11,23 +11,43 @@ use crate::{
 };
 
 /// This visitor evaluates all constant expressions and folds them to literals.
+///
+/// References to constants defined in other modules cannot be folded here, as the definition is
+/// not known until link time. Instead, we verify that a module referenced by its import name has
+/// been imported, and mark that import as used. These references are folded by the linker.
 pub struct ConstEvalVisitor<'analyzer> {
     analyzer: &'analyzer mut AnalysisContext,
+    module: &'analyzer mut Module,
 }
 
 impl<'analyzer> ConstEvalVisitor<'analyzer> {
-    pub fn new(analyzer: &'analyzer mut AnalysisContext) -> Self {
-        Self { analyzer }
+    pub fn new(analyzer: &'analyzer mut AnalysisContext, module: &'analyzer mut Module) -> Self {
+        Self { analyzer, module }
     }
 }
 
 impl ConstEvalVisitor<'_> {
+    fn verify_import(&mut self, module: &Ident) -> ControlFlow<()> {
+        match self.module.resolve_import_mut(module) {
+            Some(import) => {
+                import.uses += 1;
+            },
+            None => {
+                self.analyzer
+                    .error(SemanticAnalysisError::MissingImport { span: module.span() });
+            },
+        }
+        ControlFlow::Continue(())
+    }
+
     fn eval_const<T>(&mut self, imm: &mut Immediate<T>) -> ControlFlow<()>
     where
         T: TryFrom<u64>,
     {
         match imm {
             Immediate::Value(_) => ControlFlow::Continue(()),
+            Immediate::ImportedConstant { module, .. } => self.verify_import(module),
+            Immediate::AbsoluteConstantPath { .. } => ControlFlow::Continue(()),
             Immediate::Constant(name) => {
                 let span = name.span();
                 match self.analyzer.get_constant(name) {
@@ -66,6 +86,8 @@ impl VisitMut for ConstEvalVisitor<'_> {
     ) -> ControlFlow<()> {
         match imm {
             Immediate::Value(_) => ControlFlow::Continue(()),
+            Immediate::ImportedConstant { module, .. } => self.verify_import(module),
+            Immediate::AbsoluteConstantPath { .. } => ControlFlow::Continue(()),
             Immediate::Constant(name) => {
                 let span = name.span();
                 match self.analyzer.get_error(name) {
@@ -83,6 +105,8 @@ impl VisitMut for ConstEvalVisitor<'_> {
     fn visit_mut_immediate_felt(&mut self, imm: &mut Immediate<Felt>) -> ControlFlow<()> {
         match imm {
             Immediate::Value(_) => ControlFlow::Continue(()),
+            Immediate::ImportedConstant { module, .. } => self.verify_import(module),
+            Immediate::AbsoluteConstantPath { .. } => ControlFlow::Continue(()),
             Immediate::Constant(name) => {
                 let span = name.span();
                 match self.analyzer.get_constant(name) {
@@ -102,6 +126,8 @@ impl VisitMut for ConstEvalVisitor<'_> {
     fn visit_mut_immediate_hex(&mut self, imm: &mut Immediate<IntValue>) -> ControlFlow<()> {
         match imm {
             Immediate::Value(_) => ControlFlow::Continue(()),
+            Immediate::ImportedConstant { module, .. } => self.verify_import(module),
+            Immediate::AbsoluteConstantPath { .. } => ControlFlow::Continue(()),
             Immediate::Constant(name) => {
                 let span = name.span();
                 match self.analyzer.get_constant(name) {
@@ -1,4 +1,8 @@
 mod const_eval;
 mod verify_invoke;
+mod verify_stack_effect;
 
-pub use self::{const_eval::ConstEvalVisitor, verify_invoke::VerifyInvokeTargets};
+pub use self::{
+    const_eval::ConstEvalVisitor, verify_invoke::VerifyInvokeTargets,
+    verify_stack_effect::VerifyStackEffect,
+};
@@ -0,0 +1,219 @@
+use miden_debug_types::{Span, Spanned};
+
+use crate::{
+    ast::*,
+    parser::IntValue,
+    sema::{AnalysisContext, SemanticAnalysisError},
+};
+
+/// This pass checks the body of a procedure against its declared [StackSignature], if it has one.
+///
+/// The analysis is conservative: it only tracks the depth of the operand stack, and gives up
+/// silently as soon as it encounters an instruction whose stack effect cannot be determined
+/// statically (e.g. `exec` or `dynexec`). Where it can reason about the body, it reports:
+///
+/// * Conditionals whose branches leave the operand stack at different depths
+/// * `while.true` loops whose body does not leave exactly one extra element on the stack (the
+///   condition for the next iteration)
+/// * A net stack effect of the body which does not match the declared signature
+///
+/// All of these are reported as warnings.
+pub struct VerifyStackEffect<'a> {
+    analyzer: &'a mut AnalysisContext,
+}
+
+impl<'a> VerifyStackEffect<'a> {
+    pub fn new(analyzer: &'a mut AnalysisContext) -> Self {
+        Self { analyzer }
+    }
+
+    pub fn verify_procedure(&mut self, procedure: &Procedure) {
+        let Some(docs) = procedure.docs() else {
+            return;
+        };
+        let expected = match StackSignature::parse(docs.into_inner()) {
+            Ok(Some(signature)) => signature.effect(),
+            Ok(None) => return,
+            Err(()) => {
+                self.analyzer
+                    .error(SemanticAnalysisError::InvalidStackSignature { span: docs.span() });
+                return;
+            },
+        };
+
+        let Some(actual) = self.block_effect(procedure.body()) else {
+            return;
+        };
+        if actual != expected {
+            self.analyzer.error(SemanticAnalysisError::StackEffectMismatch {
+                span: procedure.name().span(),
+                expected,
+                actual,
+            });
+        }
+    }
+
+    /// Returns the net stack effect of `block`, or `None` if it cannot be determined.
+    fn block_effect(&mut self, block: &Block) -> Option<isize> {
+        let mut effect = 0;
+        for op in block.iter() {
+            effect += self.op_effect(op)?;
+        }
+        Some(effect)
+    }
+
+    fn op_effect(&mut self, op: &Op) -> Option<isize> {
+        match op {
+            Op::If { span, then_blk, else_blk } => {
+                let then_effect = self.block_effect(then_blk)?;
+                let else_effect = self.block_effect(else_blk)?;
+                if then_effect != else_effect {
+                    self.analyzer.error(SemanticAnalysisError::UnbalancedBranches {
+                        span: *span,
+                        then_effect,
+                        else_effect,
+                    });
+                    return None;
+                }
+                // the condition is consumed before entering either branch
+                Some(then_effect - 1)
+            },
+            Op::While { span, body, .. } => {
+                // the condition is consumed on entry, and the body must push the condition for
+                // the next iteration
+                let body_effect = self.block_effect(body)?;
+                if body_effect != 1 {
+                    self.analyzer
+                        .error(SemanticAnalysisError::UnbalancedLoop { span: *span, body_effect });
+                    return None;
+                }
+                Some(-1)
+            },
+            Op::Repeat { count, body, .. } => {
+                let body_effect = self.block_effect(body)?;
+                Some(body_effect * (*count as isize))
+            },
+            Op::Inst(inst) => instruction_effect(inst),
+        }
+    }
+}
+
+/// Returns the net effect of `inst` on the depth of the operand stack, or `None` if it cannot be
+/// determined statically.
+fn instruction_effect(inst: &Span<Instruction>) -> Option<isize> {
+    use Instruction::*;
+
+    let effect = match &**inst {
+        Nop | Breakpoint | Debug(_) | Emit(_) | Trace(_) | SysEvent(_) => 0,
+        Assert
+        | AssertWithError(_)
+        | Assertz
+        | AssertzWithError(_)
+        | AssertLt(_)
+        | AssertLtWithError(..) => -1,
+        AssertEq | AssertEqWithError(_) => -2,
+        AssertEqw | AssertEqwWithError(_) => -8,
+
+        // ----- field operations ------------------------------------------------------------------
+        Add | Sub | Mul | Div | Exp | ExpBitLength(_) | And | Or | Xor | Eq | Neq | Lt | Lte
+        | Gt | Gte => -1,
+        AddImm(_) | SubImm(_) | MulImm(_) | DivImm(_) | ExpImm(_) | EqImm(_) | NeqImm(_) | Neg
+        | ILog2 | Inv | Incr | Pow2 | Not | IsOdd | IsEven => 0,
+        Eqw => 1,
+
+        // ----- ext2 operations -------------------------------------------------------------------
+        Ext2Add | Ext2Sub | Ext2Mul | Ext2Div => -2,
+        Ext2Neg | Ext2Inv => 0,
+
+        // ----- u32 operations --------------------------------------------------------------------
+        U32Test | U32TestW | U32Split => 1,
+        U32Assert
+        | U32AssertWithError(_)
+        | U32Assert2
+        | U32Assert2WithError(_)
+        | U32AssertW
+        | U32AssertWWithError(_)
+        | U32Cast => 0,
+        U32WrappingAdd | U32WrappingSub | U32WrappingMul | U32OverflowingAdd3
+        | U32OverflowingMadd | U32Div | U32Mod | U32And | U32Or | U32Xor | U32Shr | U32Shl
+        | U32Rotr | U32Rotl | U32Lt | U32Lte | U32Gt | U32Gte | U32Min | U32Max | I32Lt
+        | I32Lte | I32Gt | I32Gte => -1,
+        U32WrappingAdd3 | U32WrappingMadd => -2,
+        U32OverflowingAdd | U32OverflowingSub | U32OverflowingMul | U32DivMod => 0,
+        U32WrappingAddImm(_)
+        | U32WrappingSubImm(_)
+        | U32WrappingMulImm(_)
+        | U32CheckedMaddImm(..)
+        | U32DivImm(_)
+        | U32ModImm(_)
+        | U32Not
+        | U32ShrImm(_)
+        | U32ShlImm(_)
+        | U32RotrImm(_)
+        | U32RotlImm(_)
+        | U32Popcnt
+        | U32Ctz
+        | U32Clz
+        | U32Clo
+        | U32Cto => 0,
+        U32OverflowingAddImm(_)
+        | U32OverflowingSubImm(_)
+        | U32OverflowingMulImm(_)
+        | U32DivModImm(_) => 1,
+
+        // ----- stack manipulation ----------------------------------------------------------------
+        Drop => -1,
+        DropW => -4,
+        PadW | DupW0 | DupW1 | DupW2 | DupW3 => 4,
+        Dup0 | Dup1 | Dup2 | Dup3 | Dup4 | Dup5 | Dup6 | Dup7 | Dup8 | Dup9 | Dup10 | Dup11
+        | Dup12 | Dup13 | Dup14 | Dup15 => 1,
+        Swap1 | Swap2 | Swap3 | Swap4 | Swap5 | Swap6 | Swap7 | Swap8 | Swap9 | Swap10 | Swap11
+        | Swap12 | Swap13 | Swap14 | Swap15 | SwapW1 | SwapW2 | SwapW3 | SwapDw | MovUp2
+        | MovUp3 | MovUp4 | MovUp5 | MovUp6 | MovUp7 | MovUp8 | MovUp9 | MovUp10 | MovUp11
+        | MovUp12 | MovUp13 | MovUp14 | MovUp15 | MovUpW2 | MovUpW3 | MovDn2 | MovDn3 | MovDn4
+        | MovDn5 | MovDn6 | MovDn7 | MovDn8 | MovDn9 | MovDn10 | MovDn11 | MovDn12 | MovDn13
+        | MovDn14 | MovDn15 | MovDnW2 | MovDnW3 => 0,
+        CSwap | CSwapW => -1,
+        CDrop | Select => -2,
+        CDropW => -5,
+
+        // ----- input / output operations ---------------------------------------------------------
+        Push(Immediate::Value(value)) => match value.inner() {
+            IntValue::Word(_) => 4,
+            _ => 1,
+        },
+        Push(_) => return None,
+        PushU8(_) | PushU16(_) | PushU32(_) | PushFelt(_) => 1,
+        PushWord(_) => 4,
+        PushU8List(values) => values.len() as isize,
+        PushU16List(values) => values.len() as isize,
+        PushU32List(values) => values.len() as isize,
+        PushFeltList(values) => values.len() as isize,
+        Locaddr(_) | Sdepth | Clk | MemLoadImm(_) | LocLoad(_) => 1,
+        Caller | MemLoad | MemLoadWImm(_) | LocLoadW(_) | MemStoreWImm(_) | LocStoreW(_)
+        | MemStream | AdvPipe | AdvLoadW => 0,
+        MemLoadW | MemStoreImm(_) | LocStore(_) | MemStoreW => -1,
+        MemStore => -2,
+        MemStreamImm(Immediate::Value(n)) => 4 * n.into_inner() as isize,
+        MemCopy => -3,
+        MemCopyImm(_) => -2,
+        MemCopyPtrImm(..) => 0,
+        AdvPush(Immediate::Value(n)) => n.into_inner() as isize,
+        MemStreamImm(_) | AdvPush(_) => return None,
+
+        // ----- cryptographic operations ----------------------------------------------------------
+        Hash | HPerm | MTreeVerify | MTreeVerifyWithError(_) => 0,
+        HMerge | MTreeMerge => -4,
+        MTreeGet => 2,
+        MTreeSet => -2,
+
+        // ----- STARK proof verification ----------------------------------------------------------
+        FriExt2Fold4 | HornerBase | HornerExt | ArithmeticCircuitEval => 0,
+
+        // ----- exec / call -----------------------------------------------------------------------
+        ProcRef(_) => 4,
+        Exec(_) | Call(_) | SysCall(_) | DynExec | DynCall => return None,
+    };
+
+    Some(effect)
+}
@@ -1,11 +1,19 @@
-use alloc::{collections::BTreeMap, string::ToString, sync::Arc, vec::Vec};
+use alloc::{
+    collections::{BTreeMap, BTreeSet},
+    string::{String, ToString},
+    sync::Arc,
+    vec::Vec,
+};
 
 use miden_assembly_syntax::{
-    KernelLibrary, Library, LibraryNamespace, LibraryPath, Parse, ParseOptions,
-    SemanticAnalysisError,
-    ast::{self, Export, InvocationTarget, InvokeKind, ModuleKind, QualifiedProcedureName},
+    KernelLibrary, Library, LibraryNamespace, LibraryPath, MemSourceResolver, Parse, ParseOptions,
+    SemanticAnalysisError, SourceResolver,
+    ast::{
+        self, Export, InvocationTarget, InvokeKind, ModuleKind, ProcedureName,
+        QualifiedProcedureName,
+    },
     debuginfo::{DefaultSourceManager, SourceManager, SourceSpan, Spanned},
-    diagnostics::{RelatedLabel, Report},
+    diagnostics::{RelatedLabel, Report, report},
 };
 use miden_core::{
     AssemblyOp, Decorator, Felt, Kernel, Operation, Program, WORD_SIZE, Word,
@@ -19,6 +27,7 @@ use crate::{
         CallerInfo, LinkLibrary, LinkLibraryKind, Linker, LinkerError, ModuleLink, ProcedureLink,
         ResolvedTarget,
     },
+    locals,
     mast_forest_builder::MastForestBuilder,
 };
 
@@ -78,8 +87,18 @@ pub struct Assembler {
     linker: Linker,
     /// Whether to treat warning diagnostics as errors
     warnings_as_errors: bool,
+    /// The resolver used for `include` directives in the modules parsed by this assembler
+    source_resolver: Option<Arc<dyn SourceResolver>>,
     /// Whether the assembler enables extra debugging information.
     in_debug_mode: bool,
+    /// The MAST roots of the procedures which may be invoked, if restricted.
+    call_allowlist: Option<BTreeSet<Word>>,
+    /// The maximum number of operations per basic block, if limited.
+    max_ops_per_basic_block: Option<usize>,
+    /// Whether peephole optimizations are applied to the operations of basic blocks.
+    optimize: bool,
+    /// The prefix prepended to the names of the procedures exported from assembled libraries.
+    export_prefix: Option<String>,
 }
 
 impl Default for Assembler {
@@ -90,7 +109,12 @@ impl Default for Assembler {
             source_manager,
             linker,
             warnings_as_errors: false,
+            source_resolver: None,
             in_debug_mode: false,
+            call_allowlist: None,
+            max_ops_per_basic_block: None,
+            optimize: false,
+            export_prefix: None,
         }
     }
 }
@@ -105,7 +129,12 @@ impl Assembler {
             source_manager,
             linker,
             warnings_as_errors: false,
+            source_resolver: None,
             in_debug_mode: false,
+            call_allowlist: None,
+            max_ops_per_basic_block: None,
+            optimize: false,
+            export_prefix: None,
         }
     }
 
@@ -127,16 +156,111 @@ impl Assembler {
     ///
     /// When true, any warning diagnostics that are emitted will be promoted to errors.
     pub fn with_warnings_as_errors(mut self, yes: bool) -> Self {
+        self.linker.set_warnings_as_errors(yes);
         self.warnings_as_errors = yes;
         self
     }
 
+    /// Sets the resolver used to locate the source files referenced by the modules assembled by
+    /// this assembler.
+    ///
+    /// The resolver is used for `include."<path>"` directives, and by the linker to load imported
+    /// modules which were not otherwise provided to the assembler, e.g. via
+    /// [`Self::compile_and_statically_link`]. Modules loaded this way are compiled as library
+    /// modules, and statically linked.
+    ///
+    /// Without a resolver, assembling a module which contains an `include` directive fails.
+    pub fn with_source_resolver(mut self, resolver: Arc<dyn SourceResolver>) -> Self {
+        self.linker.set_source_resolver(resolver.clone());
+        self.source_resolver = Some(resolver);
+        self
+    }
+
     /// Puts the assembler into the debug mode.
     pub fn with_debug_mode(mut self, yes: bool) -> Self {
         self.in_debug_mode = yes;
         self
     }
 
+    /// Restricts the procedures that can be invoked by the assembled code to those whose MAST root
+    /// is in `allowlist`.
+    ///
+    /// When set, every `exec`, `call` and `syscall` is checked against `allowlist` once its target
+    /// has been resolved, and assembly fails if the MAST root of the target is not in the
+    /// allowlist. Invocations of procedures whose definition is unknown to the assembler are
+    /// rejected as well, as they cannot be verified. Procedure references (i.e. `procref`) are
+    /// not checked.
+    pub fn with_call_allowlist(mut self, allowlist: BTreeSet<Word>) -> Self {
+        self.call_allowlist = Some(allowlist);
+        self
+    }
+
+    /// Sets the maximum number of aliases which can be followed when resolving a procedure name to
+    /// its definition.
+    ///
+    /// Cyclic aliases are always rejected, but very long chains of re-exported procedures are
+    /// accepted as long as they do not exceed this limit, which defaults to 256. Assembly fails if
+    /// resolving a procedure name requires following more aliases than that.
+    pub fn with_max_resolution_depth(mut self, depth: usize) -> Self {
+        self.linker.set_max_resolution_depth(depth);
+        self
+    }
+
+    /// Sets the maximum number of operations which are placed into a single basic block.
+    ///
+    /// By default, straight-line code is compiled into a single SPAN block, with its operation
+    /// batches separated by RESPAN operations. When a limit is set, longer sequences of operations
+    /// are instead split into several SPAN blocks of at most `max_ops` operations each.
+    ///
+    /// Note that this is not a limit on the size of operation batches: the split blocks are joined
+    /// together via JOIN blocks, and thus the MAST roots of the affected procedures (and of the
+    /// program) differ from those produced without the limit. The behavior of the procedures is
+    /// not affected.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `max_ops` is zero.
+    pub fn with_max_ops_per_basic_block(mut self, max_ops: usize) -> Self {
+        assert!(max_ops > 0, "the maximum number of operations per basic block must be non-zero");
+        self.max_ops_per_basic_block = Some(max_ops);
+        self
+    }
+
+    /// Sets whether the assembler applies peephole optimizations to the code it generates.
+    ///
+    /// When enabled, operation sequences with no effect on the VM state, such as a `PUSH` or `PAD`
+    /// immediately followed by a `DROP`, or two consecutive `SWAP` operations, are removed from
+    /// basic blocks. Optimizations never span more than one basic block and never remove an
+    /// operation which has decorators attached to it.
+    ///
+    /// Additionally, procedure locals which are declared but never referenced are removed, and
+    /// references to the remaining locals are renumbered, reducing the memory allocated for the
+    /// locals of a procedure. Procedures which take the address of a local via `locaddr` keep all
+    /// of their locals.
+    ///
+    /// Optimizations are disabled by default.
+    pub fn with_optimizations(mut self, yes: bool) -> Self {
+        self.optimize = yes;
+        self
+    }
+
+    /// Prepends `prefix` to the name of every procedure exported from the libraries assembled by
+    /// this assembler, e.g. `foo::bar` is exported as `foo::vendor_bar` for the prefix `vendor_`.
+    ///
+    /// Only the exported names are affected: invocations within the assembled modules are still
+    /// written using the original names, and the MAST of the procedures is unchanged.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `prefix` is not a valid procedure name on its own. Assembly fails if a
+    /// prefixed name is not a valid procedure name, e.g. because it is too long.
+    pub fn with_export_prefix(mut self, prefix: &str) -> Result<Self, Report> {
+        ProcedureName::new(prefix)
+            .map_err(|err| report!("invalid export prefix '{}': {}", prefix, err))?;
+        self.export_prefix = Some(prefix.to_string());
+        Ok(self)
+    }
+
     /// Sets the debug mode flag of the assembler
     pub fn set_debug_mode(&mut self, yes: bool) {
         self.in_debug_mode = yes;
@@ -169,6 +293,7 @@ impl Assembler {
                     &self.source_manager,
                     ParseOptions {
                         warnings_as_errors: self.warnings_as_errors,
+                        source_resolver: self.source_resolver.clone(),
                         ..ParseOptions::for_library()
                     },
                 )
@@ -211,6 +336,35 @@ impl Assembler {
         Ok(())
     }
 
+    /// Compiles all Miden Assembly modules known to the provided in-memory `sources`, and then
+    /// statically links them into the final artifact.
+    ///
+    /// Each module is given the path under which its source file is stored in `sources`. Unlike
+    /// [`Self::compile_and_statically_link_from_dir`], this does not require access to a file
+    /// system, and so is available in `no_std` environments.
+    pub fn compile_and_statically_link_from_sources(
+        &mut self,
+        sources: &MemSourceResolver,
+    ) -> Result<(), Report> {
+        let modules = sources
+            .iter()
+            .map(|(path, source)| {
+                source.clone().parse_with_options(
+                    &self.source_manager,
+                    ParseOptions {
+                        warnings_as_errors: self.warnings_as_errors,
+                        source_resolver: self.source_resolver.clone(),
+                        path: Some(path.clone()),
+                        ..ParseOptions::for_library()
+                    },
+                )
+            })
+            .collect::<Result<Vec<_>, Report>>()?;
+
+        self.linker.link_modules(modules)?;
+        Ok(())
+    }
+
     /// Links the final artifact against `library`.
     ///
     /// The way in which procedures referenced in `library` will be linked by the final artifact is
@@ -350,6 +504,7 @@ impl Assembler {
                     &self.source_manager,
                     ParseOptions {
                         warnings_as_errors: self.warnings_as_errors,
+                        source_resolver: self.source_resolver.clone(),
                         ..ParseOptions::for_library()
                     },
                 )
@@ -413,6 +568,7 @@ impl Assembler {
             ParseOptions {
                 path: Some(LibraryPath::new_from_components(LibraryNamespace::Kernel, [])),
                 warnings_as_errors: self.warnings_as_errors,
+                source_resolver: self.source_resolver.clone(),
                 ..ParseOptions::for_kernel()
             },
         )?;
@@ -460,6 +616,10 @@ impl Assembler {
             }
         });
         let mut mast_forest_builder = MastForestBuilder::new(staticlibs)?;
+        mast_forest_builder.set_max_ops_per_basic_block(self.max_ops_per_basic_block);
+        mast_forest_builder.set_optimizations(self.optimize);
+        let mut source_spans = Vec::new();
+        let mut output_arities = Vec::new();
         let mut exports = {
             let mut exports = BTreeMap::new();
 
@@ -478,6 +638,11 @@ impl Assembler {
                         .get_procedure(gid)
                         .expect("compilation succeeded but root not found in cache")
                         .body_node_id();
+                    let fqn = self.prefix_export(fqn)?;
+                    source_spans.push((fqn.clone(), ast_module[proc_idx].span()));
+                    if let Some(signature) = ast_module[proc_idx].stack_signature() {
+                        output_arities.push((fqn.clone(), signature.outputs));
+                    }
                     exports.insert(fqn, proc_root_node_id);
                 }
             }
@@ -492,7 +657,31 @@ impl Assembler {
             }
         }
 
-        Ok(Library::new(mast_forest.into(), exports)?)
+        Ok(Library::new(mast_forest.into(), exports)?
+            .with_source_spans(source_spans)
+            .with_output_arities(output_arities))
+    }
+
+    /// Returns `fqn` with the export prefix of this assembler prepended to its name, if any.
+    fn prefix_export(&self, fqn: QualifiedProcedureName) -> Result<QualifiedProcedureName, Report> {
+        let Some(prefix) = self.export_prefix.as_deref() else {
+            return Ok(fqn);
+        };
+
+        let name =
+            ProcedureName::new_with_span(fqn.name.span(), format!("{prefix}{}", fqn.name.as_str()))
+                .map_err(|err| report!("invalid name for exported procedure '{}': {}", fqn, err))?;
+        Ok(QualifiedProcedureName { name, ..fqn })
+    }
+
+    /// Starts assembling a [`Program`] incrementally, returning a [`ProgramBuilder`] to which
+    /// library modules can be added one at a time.
+    ///
+    /// This produces the same program as statically linking all of the modules with
+    /// [`Self::compile_and_statically_link_all`] and then calling [`Self::assemble_program`]. See
+    /// [`ProgramBuilder`] for why this does not reduce the memory used during assembly.
+    pub fn begin(self) -> ProgramBuilder {
+        ProgramBuilder { assembler: self }
     }
 
     /// Compiles the provided module into a [`Program`]. The resulting program can be executed on
@@ -507,6 +696,7 @@ impl Assembler {
             kind: ModuleKind::Executable,
             warnings_as_errors: self.warnings_as_errors,
             path: Some(LibraryPath::from(LibraryNamespace::Exec)),
+            source_resolver: self.source_resolver.clone(),
         };
 
         let program = source.parse_with_options(&self.source_manager, options)?;
@@ -532,6 +722,8 @@ impl Assembler {
             }
         });
         let mut mast_forest_builder = MastForestBuilder::new(staticlibs)?;
+        mast_forest_builder.set_max_ops_per_basic_block(self.max_ops_per_basic_block);
+        mast_forest_builder.set_optimizations(self.optimize);
 
         mast_forest_builder
             .merge_advice_map(self.linker[module_index].unwrap_ast().advice_map())?;
@@ -682,10 +874,24 @@ impl Assembler {
         // Make sure the current procedure context is available during codegen
         let gid = proc_ctx.id();
 
-        let num_locals = proc_ctx.num_locals();
-
         let wrapper_proc = self.linker.get_procedure_unsafe(gid);
         let proc = wrapper_proc.unwrap_ast().unwrap_procedure();
+
+        // When optimizing, drop the locals which are never referenced by the procedure body
+        let optimized_body = if self.optimize {
+            locals::eliminate_dead_locals(proc.body(), proc_ctx.num_locals())
+        } else {
+            None
+        };
+        let body = match optimized_body {
+            Some((ref body, num_locals)) => {
+                proc_ctx = proc_ctx.with_num_locals(num_locals);
+                body
+            },
+            None => proc.body(),
+        };
+
+        let num_locals = proc_ctx.num_locals();
         let proc_body_id = if num_locals > 0 {
             // For procedures with locals, we need to update fmp register before and after the
             // procedure body is executed. Specifically:
@@ -697,9 +903,27 @@ impl Assembler {
                 prologue: vec![Operation::Push(locals_frame), Operation::FmpUpdate],
                 epilogue: vec![Operation::Push(-locals_frame), Operation::FmpUpdate],
             };
-            self.compile_body(proc.iter(), &mut proc_ctx, Some(wrapper), mast_forest_builder)?
+            self.compile_body(body.iter(), &mut proc_ctx, Some(wrapper), mast_forest_builder)?
         } else {
-            self.compile_body(proc.iter(), &mut proc_ctx, None, mast_forest_builder)?
+            self.compile_body(body.iter(), &mut proc_ctx, None, mast_forest_builder)?
+        };
+
+        // In debug mode, bracket the procedure body with scope decorators so that hosts can
+        // attribute the executed code to this procedure.
+        let proc_body_id = if self.in_debug_mode() {
+            let name: Arc<str> = proc_ctx.name().to_string().into();
+            let enter_decorator_id = mast_forest_builder
+                .ensure_decorator(Decorator::ProcScope { name: name.clone(), enter: true })?;
+            let exit_decorator_id = mast_forest_builder
+                .ensure_decorator(Decorator::ProcScope { name, enter: false })?;
+
+            mast_forest_builder.ensure_decorated_node(
+                proc_body_id,
+                &[enter_decorator_id],
+                &[exit_decorator_id],
+            )?
+        } else {
+            proc_body_id
         };
 
         let proc_body_node = mast_forest_builder
@@ -819,7 +1043,7 @@ impl Assembler {
                     }
                 },
 
-                Op::While { body, span } => {
+                Op::While { body, span, max_iterations } => {
                     if let Some(basic_block_id) = block_builder.make_basic_block()? {
                         body_node_ids.push(basic_block_id);
                     }
@@ -844,7 +1068,10 @@ impl Assembler {
                         let location = proc_ctx.source_manager().location(*span).ok();
                         let context_name = proc_ctx.name().to_string();
                         let num_cycles = 0;
-                        let op = "while.true".to_string();
+                        let op = match max_iterations {
+                            Some(max) => format!("while.true(max={max})"),
+                            None => "while.true".to_string(),
+                        };
                         let should_break = false;
                         let op =
                             AssemblyOp::new(location, context_name, num_cycles, op, should_break);
@@ -856,6 +1083,17 @@ impl Assembler {
                             .append_before_enter(loop_node_id, &[decorator_id]);
                     }
 
+                    // The iteration hint does not affect the MAST root, and is passed on to the
+                    // processor via a decorator attached to the loop node.
+                    if let Some(max_iterations) = max_iterations {
+                        let decorator_id = block_builder
+                            .mast_forest_builder_mut()
+                            .ensure_decorator(Decorator::LoopHint(*max_iterations))?;
+                        block_builder
+                            .mast_forest_builder_mut()
+                            .append_before_enter(loop_node_id, &[decorator_id]);
+                    }
+
                     body_node_ids.push(loop_node_id);
                 },
             }
@@ -921,7 +1159,19 @@ impl Assembler {
             kind,
         };
         let resolved = self.linker.resolve_target(&caller, target)?;
-        match resolved {
+        let is_restricted = self.call_allowlist.is_some() && !matches!(kind, InvokeKind::ProcRef);
+        if let ResolvedTarget::Phantom(mast_root) = resolved {
+            if is_restricted {
+                return Err(Report::new(LinkerError::PhantomCallTargetNotAllowed {
+                    span: target.span(),
+                    source_file: self.source_manager.get(target.span().source_id()).ok(),
+                    kind,
+                    mast_root,
+                }));
+            }
+        }
+
+        let node_id = match resolved {
             ResolvedTarget::Phantom(mast_root) => self.ensure_valid_procedure_mast_root(
                 kind,
                 target.span(),
@@ -946,7 +1196,21 @@ impl Assembler {
                     },
                 }
             },
+        }?;
+
+        if let Some(allowlist) = self.call_allowlist.as_ref().filter(|_| is_restricted) {
+            let mast_root = mast_forest_builder[node_id].digest();
+            if !allowlist.contains(&mast_root) {
+                return Err(Report::new(LinkerError::CallTargetNotAllowed {
+                    span: target.span(),
+                    source_file: self.source_manager.get(target.span().source_id()).ok(),
+                    kind,
+                    mast_root,
+                }));
+            }
         }
+
+        Ok(node_id)
     }
 
     /// Verifies the validity of the MAST root as a procedure root hash, and adds it to the forest.
@@ -963,8 +1227,16 @@ impl Assembler {
         // Get the procedure from the assembler
         let current_source_file = self.source_manager.get(span.source_id()).ok();
 
-        // If the procedure is cached and is a system call, ensure that the call is valid.
-        match mast_forest_builder.find_procedure_by_mast_root(&mast_root) {
+        // If the procedure is cached and is a system call, ensure that the call is valid. Since
+        // multiple procedures may share the MAST root, prefer the one which is a kernel procedure.
+        let cached_proc = match kind {
+            InvokeKind::SysCall => mast_forest_builder
+                .find_procedures_by_mast_root(&mast_root)
+                .find(|proc| proc.visibility().is_syscall())
+                .or_else(|| mast_forest_builder.find_procedure_by_mast_root(&mast_root)),
+            _ => mast_forest_builder.find_procedure_by_mast_root(&mast_root),
+        };
+        match cached_proc {
             Some(proc) if matches!(kind, InvokeKind::SysCall) => {
                 // Verify if this is a syscall, that the callee is a kernel procedure
                 //
@@ -1014,6 +1286,45 @@ impl Assembler {
     }
 }
 
+// PROGRAM BUILDER
+// ================================================================================================
+
+/// Assembles a [`Program`] from modules which are provided one at a time.
+///
+/// A [`ProgramBuilder`] is obtained via [`Assembler::begin`]. Each module passed to
+/// [`Self::add_module`] is parsed and handed to the linker immediately, so the caller does not need
+/// to keep its own copy of the module around once it has been added.
+///
+/// This only changes the shape of the API, and does not bound the memory used during assembly:
+/// the linker keeps the parsed AST of every added module until the program is finished, as
+/// procedures are resolved and compiled against the full set of modules at that point, and the
+/// assembler's [`SourceManager`] retains the source text of every module in order to render
+/// diagnostics.
+pub struct ProgramBuilder {
+    assembler: Assembler,
+}
+
+impl ProgramBuilder {
+    /// Compiles `module` and statically links it into the program being built.
+    ///
+    /// The given module must be a library module, or an error will be returned.
+    pub fn add_module(&mut self, module: impl Parse) -> Result<&mut Self, Report> {
+        self.assembler.compile_and_statically_link(module)?;
+        Ok(self)
+    }
+
+    /// Compiles the provided executable module against all of the modules added so far, and
+    /// returns the resulting [`Program`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if parsing or compilation of the specified program fails, or if the source
+    /// doesn't have an entrypoint.
+    pub fn finish(self, program: impl Parse) -> Result<Program, Report> {
+        self.assembler.assemble_program(program)
+    }
+}
+
 // HELPERS
 // ================================================================================================
 
@@ -1,4 +1,4 @@
-use alloc::{borrow::Borrow, string::ToString, sync::Arc, vec::Vec};
+use alloc::{borrow::Borrow, string::ToString, sync::Arc, vec, vec::Vec};
 
 use miden_assembly_syntax::{ast::Instruction, debuginfo::Span, diagnostics::Report};
 use miden_core::{
@@ -177,6 +177,10 @@ impl BasicBlockBuilder<'_> {
     /// This consumes all operations in the builder, but does not touch the operations in the
     /// epilogue of the builder.
     pub fn make_basic_block(&mut self) -> Result<Option<MastNodeId>, Report> {
+        if self.mast_forest_builder.optimizations_enabled() {
+            self.remove_redundant_ops();
+        }
+
         if !self.ops.is_empty() {
             let ops = self.ops.drain(..).collect();
             let decorators = if !self.decorators.is_empty() {
@@ -233,6 +237,60 @@ impl BasicBlockBuilder<'_> {
     }
 }
 
+/// Optimizations
+impl BasicBlockBuilder<'_> {
+    /// Removes pairs of adjacent operations which have no effect on the VM state from the
+    /// operations currently in this builder.
+    ///
+    /// The removed pairs are `PUSH`/`PAD` followed by `DROP`, and `SWAP` followed by `SWAP`.
+    /// Removing a pair may make another pair adjacent, in which case it is removed as well.
+    ///
+    /// Operations which have a decorator attached to them, as well as all operations covered by
+    /// an `AsmOp` decorator, are never removed, so that debug information remains accurate.
+    fn remove_redundant_ops(&mut self) {
+        let mut pinned = vec![false; self.ops.len()];
+        for &(op_idx, decorator_id) in self.decorators.iter() {
+            // the cycle count of an `AsmOp` decorator is not known until its instruction has been
+            // fully lowered, in which case all remaining operations may belong to it
+            let num_ops = match &self.mast_forest_builder[decorator_id] {
+                Decorator::AsmOp(assembly_op) if assembly_op.num_cycles() == 0 => pinned.len(),
+                Decorator::AsmOp(assembly_op) => assembly_op.num_cycles() as usize,
+                _ => 1,
+            };
+            let end = op_idx.saturating_add(num_ops).min(pinned.len());
+            pinned[op_idx.min(end)..end].iter_mut().for_each(|pin| *pin = true);
+        }
+
+        // the original indices of the operations which are kept
+        let mut kept: Vec<usize> = Vec::with_capacity(self.ops.len());
+        for (op_idx, op) in self.ops.iter().enumerate() {
+            if let Some(&prev_idx) = kept.last() {
+                let is_redundant = matches!(
+                    (self.ops[prev_idx], op),
+                    (Operation::Push(_) | Operation::Pad, Operation::Drop)
+                        | (Operation::Swap, Operation::Swap)
+                );
+                if is_redundant && !pinned[prev_idx] && !pinned[op_idx] {
+                    kept.pop();
+                    continue;
+                }
+            }
+            kept.push(op_idx);
+        }
+
+        if kept.len() == self.ops.len() {
+            return;
+        }
+
+        // decorators are attached to the operation at their index, so each decorator index is
+        // shifted by the number of operations removed before it
+        for (op_idx, _) in self.decorators.iter_mut() {
+            *op_idx = kept.partition_point(|&kept_idx| kept_idx < *op_idx);
+        }
+        self.ops = kept.into_iter().map(|op_idx| self.ops[op_idx]).collect();
+    }
+}
+
 /// Holds either the node id of a basic block, or a list of decorators that are currently not
 /// attached to any node.
 pub enum BasicBlockOrDecorators {
@@ -17,6 +17,7 @@ pub fn compile_options(
     let compiled = match options {
         Ast::StackAll => Vm::StackAll,
         Ast::StackTop(n) => Vm::StackTop(n.expect_value()),
+        Ast::StackHex(n) => Vm::StackHex(n.expect_value()),
         Ast::MemAll => Vm::MemAll,
         Ast::MemInterval(start, end) => Vm::MemInterval(start.expect_value(), end.expect_value()),
         Ast::LocalInterval(start, end) => {
@@ -32,6 +33,7 @@ pub fn compile_options(
             Vm::LocalInterval(0, end_exclusive - 1, proc_ctx.num_locals())
         },
         Ast::AdvStackTop(n) => Vm::AdvStackTop(n.expect_value()),
+        Ast::WatchField(op, n) => Vm::WatchField(*op, n.expect_value()),
     };
 
     Ok(compiled)
@@ -33,6 +33,16 @@ pub fn assertw(span_builder: &mut BasicBlockBuilder, err_code: Felt) {
     ]);
 }
 
+/// Asserts that the value at the top of the stack is strictly less than an immediate value, with
+/// both values compared as integers in the range [0, p).
+///
+/// VM cycles: 16 cycles
+pub fn assert_lt_imm(span_builder: &mut BasicBlockBuilder, imm: Felt, err_code: Felt) {
+    span_builder.push_op(Push(imm));
+    lt(span_builder);
+    span_builder.push_op(Assert(err_code));
+}
+
 // BASIC ARITHMETIC OPERATIONS
 // ================================================================================================
 
@@ -309,6 +319,32 @@ pub fn ilog2(block_builder: &mut BasicBlockBuilder) {
     block_builder.push_ops(ops);
 }
 
+// SELECTION OPERATIONS
+// ================================================================================================
+
+/// Appends a sequence of operations which selects one of two values based on a binary condition,
+/// without branching.
+///
+/// The stack is expected to be arranged as [c, b, a, ...], where c must be a binary value. The
+/// result is computed as a + c * (b - a), so that b is selected if c = 1, and a otherwise. The
+/// sequence fails if c is not a binary value.
+///
+/// VM cycles: 12 cycles
+pub fn select(span_builder: &mut BasicBlockBuilder) {
+    #[rustfmt::skip]
+    let ops = [
+        // assert that c is binary, i.e. that c * c = c (6 cycles)
+        Dup0, Dup0, Mul, Dup1, Eq, Assert(ZERO),
+        // => [c, b, a, ...]
+
+        // compute a + c * (b - a) (6 cycles)
+        Swap, Dup2, Neg, Add, Mul, Add,
+        // => [d, ...]
+    ];
+
+    span_builder.push_ops(ops);
+}
+
 // COMPARISON OPERATIONS
 // ================================================================================================
 
@@ -452,6 +488,14 @@ pub fn is_odd(span_builder: &mut BasicBlockBuilder) {
     span_builder.push_ops([U32split, Drop, Pad, Incr, U32and]);
 }
 
+/// Checks if the top element in the stack is an even number or not.
+///
+/// Vm cycles: 6
+pub fn is_even(span_builder: &mut BasicBlockBuilder) {
+    is_odd(span_builder);
+    span_builder.push_op(Not);
+}
+
 // COMPARISON OPERATION HELPER FUNCTIONS
 // ================================================================================================
 
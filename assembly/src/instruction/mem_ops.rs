@@ -2,10 +2,10 @@ use miden_assembly_syntax::{
     debuginfo::{SourceSpan, Spanned},
     diagnostics::{RelatedLabel, Report},
 };
-use miden_core::{Felt, Operation::*};
+use miden_core::{Felt, ONE, Operation::*, WORD_SIZE, ZERO, mast::MastNodeId};
 
-use super::{BasicBlockBuilder, push_felt, push_u32_value};
-use crate::ProcedureContext;
+use super::{BasicBlockBuilder, push_felt, push_u32_value, u32_ops};
+use crate::{MEM_STREAM_WORD_LIMIT, ProcedureContext};
 
 // INSTRUCTION PARSERS
 // ================================================================================================
@@ -114,6 +114,165 @@ pub fn mem_write_imm(
     Ok(())
 }
 
+/// Appends operations to the span needed to execute a `mem_stream.n` instruction. This reads `n`
+/// consecutive words from memory starting at the address on top of the stack, and leaves the
+/// address advanced past the last word read on top of the stack:
+///
+/// [a, ...] -> [a + 4n, W_{n-1}, ..., W_1, W_0, ...]
+///
+/// where W_i is the word stored at address a + 4i.
+///
+/// Pairs of words are read with MSTREAM, and a trailing odd word is read with MLOADW.
+///
+/// VM cycles per operation:
+/// - 20 cycles per pair of words
+/// - 9 cycles for a trailing odd word
+///
+/// # Errors
+/// Returns an error if the specified number of words is smaller than 1 or greater than 8.
+pub fn mem_stream(
+    block_builder: &mut BasicBlockBuilder,
+    proc_ctx: &ProcedureContext,
+    n: u8,
+    span: SourceSpan,
+) -> Result<(), Report> {
+    let min = 1;
+    let max = MEM_STREAM_WORD_LIMIT;
+
+    if n < min || n > max {
+        return Err(RelatedLabel::error("invalid argument")
+            .with_labeled_span(span, "this instruction argument is out of range")
+            .with_help(format!("value must be in the range {min}..={max}"))
+            .with_source_file(proc_ctx.source_manager().get(span.source_id()).ok())
+            .into());
+    }
+
+    for _ in 0..n / 2 {
+        // MSTREAM expects the address at stack position 12, and overwrites the top 8 elements
+        // with the two words read from memory: [a, ...] -> [W_1, W_0, 0, 0, 0, 0, a + 8, ...]
+        block_builder.push_op_many(Pad, 12);
+        block_builder.push_op(MStream);
+
+        // discard the padding and move the address back to the top of the stack
+        block_builder.push_op(SwapW2);
+        block_builder.push_op_many(Drop, 4);
+        block_builder.push_op(SwapW);
+        block_builder.push_op(MovUp8);
+    }
+
+    if n % 2 == 1 {
+        // [a, ...] -> [a, W, ...]
+        block_builder.push_ops([Pad, Pad, Pad, Pad, Dup4, MLoadW, MovUp4]);
+
+        // [a, W, ...] -> [a + 4, W, ...]
+        block_builder.push_ops([Push(Felt::from(4_u32)), Add]);
+    }
+
+    Ok(())
+}
+
+/// Appends operations to the span needed to execute a `mem_copy` instruction, and returns the
+/// loop node which performs the copy. This copies `n` words from the memory region starting at
+/// address `src` into the memory region starting at address `dst`:
+///
+/// [src, dst, n, ...] -> [...]
+///
+/// When `n` is provided as an immediate value, it is not expected on the stack. Likewise, when
+/// `src` and `dst` are provided as immediate values (which requires `n` to be provided as an
+/// immediate value as well), none of the operands are expected on the stack.
+///
+/// The regions may overlap: if `dst` is greater than `src` the words are copied starting from the
+/// end of the region, so that every word is read before it is overwritten.
+///
+/// VM cycles per operation:
+/// - mem_copy: 38 cycles + 25 cycles per word copied
+/// - mem_copy.n: 40 cycles + 25 cycles per word copied
+/// - mem_copy.src.dst.n: 41 cycles + 25 cycles per word copied
+///
+/// # Errors
+/// Returns an error if the immediate number of words is smaller than 1, or greater than the number
+/// of words which fit into the memory address space.
+pub fn mem_copy(
+    block_builder: &mut BasicBlockBuilder,
+    proc_ctx: &ProcedureContext,
+    ptrs: Option<(u32, u32)>,
+    n: Option<(u32, SourceSpan)>,
+) -> Result<Option<MastNodeId>, Report> {
+    if let Some((n, span)) = n {
+        let min = 1;
+        let max = u32::MAX / WORD_SIZE as u32;
+
+        if n < min || n > max {
+            return Err(RelatedLabel::error("invalid argument")
+                .with_labeled_span(span, "this instruction argument is out of range")
+                .with_help(format!("value must be in the range {min}..={max}"))
+                .with_source_file(proc_ctx.source_manager().get(span.source_id()).ok())
+                .into());
+        }
+
+        match ptrs {
+            // [...] -> [src, dst, n, ...]
+            Some((src, dst)) => {
+                push_u32_value(block_builder, n);
+                push_u32_value(block_builder, dst);
+                push_u32_value(block_builder, src);
+            },
+            // [src, dst, ...] -> [src, dst, n, ...]
+            None => {
+                push_u32_value(block_builder, n);
+                block_builder.push_op(MovDn2);
+            },
+        }
+    }
+
+    // make sure the addresses and the number of words are valid u32 values
+    block_builder.push_ops([U32assert2(ZERO), Dup2, Dup0, U32assert2(ZERO), Drop, Drop]);
+
+    // determine the direction of the copy: b = 1 if src < dst, and 0 otherwise
+    // [src, dst, n, ...] -> [b, src, dst, n, ...]
+    block_builder.push_ops([Dup0, Dup2]);
+    u32_ops::u32lt(block_builder);
+
+    // when copying backwards, start from the last word of each region: off = b * 4 * (n - 1)
+    // [b, src, dst, n, ...] -> [off, b, src, dst, n, ...]
+    block_builder.push_ops([Dup0, Dup4, Push(Felt::from(WORD_SIZE as u32)), Mul]);
+    block_builder.push_ops([Push(-Felt::from(WORD_SIZE as u32)), Add, Mul]);
+
+    // [off, b, src, dst, n, ...] -> [dst + off, src + off, b, n, ...]
+    block_builder.push_ops([Dup0, MovUp3, Add, Swap, MovUp3, Add]);
+
+    // compute the address step as 4 - 8 * b, i.e. 4 when copying forwards, and -4 otherwise
+    // [dst', src', b, n, ...] -> [src', dst', n, step, ...]
+    block_builder.push_ops([MovUp2, Push(-Felt::from(2 * WORD_SIZE as u32)), Mul]);
+    block_builder.push_ops([Push(Felt::from(WORD_SIZE as u32)), Add, MovDn3, Swap]);
+
+    // enter the loop only if there is something to copy
+    block_builder.push_ops([Dup2, Eqz, Not]);
+
+    #[rustfmt::skip]
+    let body = vec![
+        // read the word at src: [src, dst, k, step, ...] -> [W, src, dst, k, step, ...]
+        Pad, Pad, Pad, Pad, Dup4, MLoadW,
+        // write the word to dst: [W, src, dst, k, step, ...] -> [src, dst, k, step, ...]
+        Dup5, MStoreW, Drop, Drop, Drop, Drop,
+        // advance the addresses: [src, dst, k, step, ...] -> [src', dst', k, step, ...]
+        Dup3, Add, Swap, Dup3, Add, Swap,
+        // decrement the counter: [src', dst', k, step, ...] -> [src', dst', k - 1, step, ...]
+        MovUp2, Push(-ONE), Add, MovDn2,
+        // continue while there are words left: [src', dst', k - 1, step, ...]
+        Dup2, Eqz, Not,
+    ];
+
+    let mast_forest_builder = block_builder.mast_forest_builder_mut();
+    let body_node_id = mast_forest_builder.ensure_block(body, None)?;
+    let loop_node_id = mast_forest_builder.ensure_loop(body_node_id)?;
+
+    // drop the addresses, the counter and the step once the loop exits
+    let cleanup_node_id = mast_forest_builder.ensure_block(vec![Drop, Drop, Drop, Drop], None)?;
+
+    Ok(Some(mast_forest_builder.ensure_join(loop_node_id, cleanup_node_id)?))
+}
+
 // HELPER FUNCTIONS
 // ================================================================================================
 
@@ -97,6 +97,13 @@ impl Assembler {
                 let error_code = block_builder.register_error(err_msg.expect_string());
                 field_ops::assertw(block_builder, error_code)
             },
+            Instruction::AssertLt(imm) => {
+                field_ops::assert_lt_imm(block_builder, imm.expect_value(), ZERO)
+            },
+            Instruction::AssertLtWithError(imm, err_msg) => {
+                let error_code = block_builder.register_error(err_msg.expect_string());
+                field_ops::assert_lt_imm(block_builder, imm.expect_value(), error_code)
+            },
             Instruction::Assertz => block_builder.push_ops([Eqz, Assert(ZERO)]),
             Instruction::AssertzWithError(err_msg) => {
                 let error_code = block_builder.register_error(err_msg.expect_string());
@@ -143,6 +150,7 @@ impl Assembler {
             Instruction::Gt => field_ops::gt(block_builder),
             Instruction::Gte => field_ops::gte(block_builder),
             Instruction::IsOdd => field_ops::is_odd(block_builder),
+            Instruction::IsEven => field_ops::is_even(block_builder),
 
             // ----- ext2 instructions ------------------------------------------------------------
             Instruction::Ext2Add => ext2_ops::ext2_add(block_builder),
@@ -204,6 +212,9 @@ impl Assembler {
             },
             Instruction::U32OverflowingMadd => block_builder.push_op(U32madd),
             Instruction::U32WrappingMadd => block_builder.push_ops([U32madd, Drop]),
+            Instruction::U32CheckedMaddImm(a, b) => {
+                u32_ops::u32checked_madd(block_builder, a.expect_value(), b.expect_value())
+            },
 
             Instruction::U32Div => u32_ops::u32div(block_builder, proc_ctx, None)?,
             Instruction::U32DivImm(v) => {
@@ -248,6 +259,10 @@ impl Assembler {
             Instruction::U32Gte => u32_ops::u32gte(block_builder),
             Instruction::U32Min => u32_ops::u32min(block_builder),
             Instruction::U32Max => u32_ops::u32max(block_builder),
+            Instruction::I32Lt => u32_ops::i32lt(block_builder),
+            Instruction::I32Lte => u32_ops::i32lte(block_builder),
+            Instruction::I32Gt => u32_ops::i32gt(block_builder),
+            Instruction::I32Gte => u32_ops::i32gte(block_builder),
 
             // ----- stack manipulation -----------------------------------------------------------
             Instruction::Drop => block_builder.push_op(Drop),
@@ -341,6 +356,7 @@ impl Assembler {
             Instruction::CSwapW => block_builder.push_op(CSwapW),
             Instruction::CDrop => block_builder.push_ops([CSwap, Drop]),
             Instruction::CDropW => block_builder.push_ops([CSwapW, Drop, Drop, Drop, Drop]),
+            Instruction::Select => field_ops::select(block_builder),
 
             // ----- input / output instructions --------------------------------------------------
             Instruction::Push(imm) => match (*imm).expect_value() {
@@ -369,6 +385,26 @@ impl Assembler {
             Instruction::AdvLoadW => block_builder.push_op(AdvPopW),
 
             Instruction::MemStream => block_builder.push_op(MStream),
+            Instruction::MemStreamImm(n) => {
+                mem_ops::mem_stream(block_builder, proc_ctx, n.expect_value(), n.span())?
+            },
+            Instruction::MemCopy => return mem_ops::mem_copy(block_builder, proc_ctx, None, None),
+            Instruction::MemCopyImm(n) => {
+                return mem_ops::mem_copy(
+                    block_builder,
+                    proc_ctx,
+                    None,
+                    Some((n.expect_value(), n.span())),
+                );
+            },
+            Instruction::MemCopyPtrImm(src, dst, n) => {
+                return mem_ops::mem_copy(
+                    block_builder,
+                    proc_ctx,
+                    Some((src.expect_value(), dst.expect_value())),
+                    Some((n.expect_value(), n.span())),
+                );
+            },
             Instruction::Locaddr(v) => {
                 env_ops::locaddr(block_builder, v.expect_value(), proc_ctx, span)?
             },
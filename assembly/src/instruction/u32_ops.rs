@@ -115,6 +115,23 @@ pub fn u32mul(span_builder: &mut BasicBlockBuilder, op_mode: U32OpMode, imm: Opt
     handle_arithmetic_operation(span_builder, U32mul, op_mode, imm);
 }
 
+/// Translates u32checked_madd.a.b assembly instruction to VM operations.
+///
+/// Computes `x * a + b` for the value `x` on top of the stack by pushing the immediates around
+/// it and executing `U32MADD`. The operation fails if `x` is not a u32 value, or if the result
+/// does not fit into 32 bits (i.e., the high 32 bits of the result are not zero).
+///
+/// VM cycles: 6 - 8 cycles depending on the values of the immediates.
+pub fn u32checked_madd(block_builder: &mut BasicBlockBuilder, a: u32, b: u32) {
+    // [x, ...] -> [a, x, b, ...]
+    push_u32_value(block_builder, b);
+    block_builder.push_op(Swap);
+    push_u32_value(block_builder, a);
+
+    // [hi, lo, ...] -> [lo, ...], failing if hi is not zero
+    block_builder.push_ops([U32madd, Eqz, Assert(ZERO)]);
+}
+
 /// Translates u32div assembly instructions to VM operations.
 ///
 /// VM cycles per mode:
@@ -808,6 +825,58 @@ pub fn u32gte(block_builder: &mut BasicBlockBuilder) {
     block_builder.push_op(Not);
 }
 
+/// Translates i32lt assembly instruction to VM operations.
+///
+/// The top two stack elements are interpreted as 32-bit integers in two's complement form.
+///
+/// This operation takes 11 VM cycles.
+pub fn i32lt(block_builder: &mut BasicBlockBuilder) {
+    // the operands end up in reverse order, so swap them back to get an lt check
+    flip_sign_bits(block_builder);
+    block_builder.push_op(Swap);
+
+    compute_lt(block_builder);
+}
+
+/// Translates i32lte assembly instruction to VM operations.
+///
+/// The top two stack elements are interpreted as 32-bit integers in two's complement form.
+///
+/// This operation takes 11 VM cycles.
+pub fn i32lte(block_builder: &mut BasicBlockBuilder) {
+    // the operands end up in reverse order, which gives us a gt check
+    flip_sign_bits(block_builder);
+    compute_lt(block_builder);
+
+    // Flip the final results to get the lte results.
+    block_builder.push_op(Not);
+}
+
+/// Translates i32gt assembly instruction to VM operations.
+///
+/// The top two stack elements are interpreted as 32-bit integers in two's complement form.
+///
+/// This operation takes 10 VM cycles.
+pub fn i32gt(block_builder: &mut BasicBlockBuilder) {
+    // the operands end up in reverse order, which gives us a gt check
+    flip_sign_bits(block_builder);
+    compute_lt(block_builder);
+}
+
+/// Translates i32gte assembly instruction to VM operations.
+///
+/// The top two stack elements are interpreted as 32-bit integers in two's complement form.
+///
+/// This operation takes 12 VM cycles.
+pub fn i32gte(block_builder: &mut BasicBlockBuilder) {
+    flip_sign_bits(block_builder);
+    block_builder.push_op(Swap);
+    compute_lt(block_builder);
+
+    // Flip the final results to get the gte results.
+    block_builder.push_op(Not);
+}
+
 /// Translates u32min assembly instruction to VM operations.
 ///
 /// Specifically, we subtract the top value from the second to the top value (U32SUB), check the
@@ -851,6 +920,20 @@ fn compute_lt(block_builder: &mut BasicBlockBuilder) {
     ])
 }
 
+/// Flips the sign bit of the top two stack elements, and leaves them in reverse order.
+///
+/// Flipping the sign bit maps 32-bit integers in two's complement form onto u32 values in a way
+/// which preserves ordering, and so the results can be compared using unsigned comparisons.
+fn flip_sign_bits(block_builder: &mut BasicBlockBuilder) {
+    #[rustfmt::skip]
+    block_builder.push_ops([
+        // [b, a, ...] -> [b', a, ...]
+        Push(Felt::new(1 << 31)), U32add, Drop,
+        // [b', a, ...] -> [a', b', ...]
+        Swap, Push(Felt::new(1 << 31)), U32add, Drop,
+    ]);
+}
+
 /// Duplicate the top two elements in the stack and determine the min and max between them.
 ///
 /// The maximum number will be at the top of the stack and minimum will be at the 2nd index.
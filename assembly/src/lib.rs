@@ -13,6 +13,7 @@ mod basic_block_builder;
 mod id;
 mod instruction;
 pub mod linker;
+mod locals;
 mod mast_forest_builder;
 mod procedure;
 
@@ -25,7 +26,8 @@ mod tests;
 
 // Re-exported for downstream crates
 pub use miden_assembly_syntax::{
-    KernelLibrary, Library, LibraryNamespace, LibraryPath, ModuleParser, Parse, ParseOptions, ast,
+    KernelLibrary, Library, LibraryNamespace, LibraryPath, MemSourceResolver, ModuleParser, Parse,
+    ParseOptions, SourceResolver, ast,
     debuginfo::{
         self, DefaultSourceManager, SourceFile, SourceId, SourceManager, SourceSpan, Span, Spanned,
     },
@@ -40,7 +42,7 @@ pub use miden_core::{mast, utils};
 #[doc(hidden)]
 pub use self::linker::{LinkLibraryKind, LinkerError};
 pub use self::{
-    assembler::Assembler,
+    assembler::{Assembler, ProgramBuilder},
     id::{GlobalProcedureIndex, ModuleIndex},
     procedure::{Procedure, ProcedureContext},
 };
@@ -52,6 +54,14 @@ pub use self::{
 /// instruction.
 const ADVICE_READ_LIMIT: u8 = 16;
 
+/// The maximum number of words that can be read from memory in a single `mem_stream.n`
+/// instruction.
+const MEM_STREAM_WORD_LIMIT: u8 = 8;
+
+/// The default maximum number of aliases which can be followed when resolving a procedure name to
+/// its definition.
+const DEFAULT_MAX_RESOLUTION_DEPTH: usize = 256;
+
 /// The maximum number of bits by which a u32 value can be shifted in a bitwise operation.
 const MAX_U32_SHIFT_VALUE: u8 = 31;
 
@@ -1,10 +1,10 @@
 use alloc::{boxed::Box, string::String, sync::Arc, vec::Vec};
 
 use miden_assembly_syntax::{
-    Felt, LibraryPath,
-    ast::QualifiedProcedureName,
+    Felt, LibraryPath, Word,
+    ast::{Ident, InvokeKind, QualifiedProcedureName},
     debuginfo::{SourceFile, SourceSpan},
-    diagnostics::{Diagnostic, RelatedLabel, miette},
+    diagnostics::{Diagnostic, RelatedError, RelatedLabel, miette},
 };
 use miden_core::{FieldElement, utils::to_hex};
 
@@ -39,6 +39,40 @@ pub enum LinkerError {
         source_file: Option<Arc<SourceFile>>,
         path: LibraryPath,
     },
+    #[error("failed to load module '{path}'")]
+    #[diagnostic(help("see diagnostics for details"))]
+    ModuleLoadFailed {
+        #[label("imported here")]
+        span: SourceSpan,
+        #[source_code]
+        source_file: Option<Arc<SourceFile>>,
+        path: LibraryPath,
+        #[related]
+        errors: Box<[RelatedError]>,
+    },
+    #[error("undefined constant '{name}' in module '{path}'")]
+    #[diagnostic(help(
+        "constants can only be referenced from modules which define them and are assembled from source"
+    ))]
+    UndefinedConstant {
+        #[label]
+        span: SourceSpan,
+        #[source_code]
+        source_file: Option<Arc<SourceFile>>,
+        path: LibraryPath,
+        name: Ident,
+    },
+    #[error("invalid constant '{name}'")]
+    #[diagnostic(help(
+        "this constant does not resolve to a value of the right type, or the value is out of range"
+    ))]
+    InvalidConstant {
+        #[label]
+        span: SourceSpan,
+        #[source_code]
+        source_file: Option<Arc<SourceFile>>,
+        name: Ident,
+    },
     #[error("invalid syscall: '{callee}' is not an exported kernel procedure")]
     #[diagnostic()]
     InvalidSysCallTarget {
@@ -48,6 +82,46 @@ pub enum LinkerError {
         source_file: Option<Arc<SourceFile>>,
         callee: Box<QualifiedProcedureName>,
     },
+    #[error("invalid {kind}: procedure with MAST root {mast_root} is not in the call allowlist")]
+    #[diagnostic(help(
+        "this assembler only permits invoking procedures whose MAST root is in its call allowlist"
+    ))]
+    CallTargetNotAllowed {
+        #[label("invoked here")]
+        span: SourceSpan,
+        #[source_code]
+        source_file: Option<Arc<SourceFile>>,
+        kind: InvokeKind,
+        mast_root: Word,
+    },
+    #[error(
+        "invalid {kind}: the definition of the procedure with MAST root {mast_root} is unknown"
+    )]
+    #[diagnostic(help(
+        "this assembler has a call allowlist, so every invoked procedure must be defined in a known module or library"
+    ))]
+    PhantomCallTargetNotAllowed {
+        #[label("invoked here")]
+        span: SourceSpan,
+        #[source_code]
+        source_file: Option<Arc<SourceFile>>,
+        kind: InvokeKind,
+        mast_root: Word,
+    },
+    #[error(
+        "failed to resolve '{callee}': the chain of aliases exceeds the maximum resolution depth of {max_depth}"
+    )]
+    #[diagnostic(help(
+        "re-export the procedure closer to its definition, or increase the maximum resolution depth of the assembler"
+    ))]
+    ResolutionDepthExceeded {
+        #[label("while resolving this procedure reference")]
+        span: SourceSpan,
+        #[source_code]
+        source_file: Option<Arc<SourceFile>>,
+        callee: Box<QualifiedProcedureName>,
+        max_depth: usize,
+    },
     #[error("value for key {} already present in the advice map", to_hex(Felt::elements_as_bytes(.key)))]
     #[diagnostic(help(
         "previous values at key were '{prev_values:?}'. Operation would have replaced them with '{new_values:?}'",
@@ -15,15 +15,17 @@ use self::{analysis::MaybeRewriteCheck, rewrites::ModuleRewriter};
 pub use self::{
     callgraph::{CallGraph, CycleError},
     errors::LinkerError,
-    name_resolver::{CallerInfo, NameResolver, ResolvedTarget},
+    name_resolver::{CallerInfo, NameResolver, ResolutionStep, ResolvedTarget},
 };
 use super::{GlobalProcedureIndex, ModuleIndex};
 use crate::{
-    Library, LibraryNamespace, LibraryPath, SourceManager, Spanned,
+    DEFAULT_MAX_RESOLUTION_DEPTH, Library, LibraryNamespace, LibraryPath, Parse, ParseOptions,
+    SourceManager, SourceResolver, Spanned,
     ast::{
         Export, InvocationTarget, InvokeKind, Module, ProcedureIndex, ProcedureName,
         ResolvedProcedure,
     },
+    diagnostics::RelatedError,
     library::{ModuleInfo, ProcedureInfo},
 };
 
@@ -227,6 +229,13 @@ pub struct Linker {
     kernel: Kernel,
     /// The source manager to use when emitting diagnostics.
     source_manager: Arc<dyn SourceManager>,
+    /// The maximum number of aliases which can be followed when resolving a procedure name.
+    max_resolution_depth: usize,
+    /// The resolver used to load the sources of imported modules which are unknown to the linker.
+    source_resolver: Option<Arc<dyn SourceResolver>>,
+    /// Whether to treat warning diagnostics as errors when parsing modules loaded via
+    /// `source_resolver`.
+    warnings_as_errors: bool,
 }
 
 // ------------------------------------------------------------------------------------------------
@@ -243,9 +252,37 @@ impl Linker {
             kernel_index: None,
             kernel: Default::default(),
             source_manager,
+            max_resolution_depth: DEFAULT_MAX_RESOLUTION_DEPTH,
+            source_resolver: None,
+            warnings_as_errors: false,
         }
     }
 
+    /// Sets the maximum number of aliases which can be followed when resolving a procedure name to
+    /// its definition.
+    ///
+    /// Resolving a name which requires following a longer chain of aliases fails with
+    /// [LinkerError::ResolutionDepthExceeded].
+    pub fn set_max_resolution_depth(&mut self, depth: usize) {
+        self.max_resolution_depth = depth;
+    }
+
+    /// Sets the resolver used to load imported modules which are not otherwise known to the
+    /// linker.
+    ///
+    /// When linking, every module imported by a pending module, which is neither pending nor part
+    /// of the module graph, is looked up via [SourceResolver::resolve_module], parsed as a library
+    /// module, and linked along with the pending modules.
+    pub fn set_source_resolver(&mut self, resolver: Arc<dyn SourceResolver>) {
+        self.source_resolver = Some(resolver);
+    }
+
+    /// Sets whether warning diagnostics are promoted to errors when parsing modules loaded via
+    /// the source resolver.
+    pub fn set_warnings_as_errors(&mut self, yes: bool) {
+        self.warnings_as_errors = yes;
+    }
+
     /// Registers `library` and all of its modules with the linker, according to its kind
     pub fn link_library(&mut self, library: LinkLibrary) -> Result<(), LinkerError> {
         use alloc::collections::btree_map::Entry;
@@ -355,6 +392,56 @@ impl Linker {
         Ok(module_index)
     }
 
+    /// Loads the modules imported by the pending modules, which are not otherwise known to the
+    /// linker, using the source resolver, if one was provided.
+    ///
+    /// Modules loaded this way are themselves added to the set of pending modules, so that the
+    /// modules they import are loaded as well.
+    fn load_imported_modules(&mut self) -> Result<(), LinkerError> {
+        let Some(resolver) = self.source_resolver.clone() else {
+            return Ok(());
+        };
+
+        let mut next = 0;
+        while next < self.pending.len() {
+            let imports = self.pending[next]
+                .module
+                .imports()
+                .map(|import| (import.span, import.path.clone()))
+                .collect::<Vec<_>>();
+            next += 1;
+
+            for (span, path) in imports {
+                if self.is_pending(&path) || self.find_module_index(&path).is_some() {
+                    continue;
+                }
+                let Some(source) = resolver.resolve_module(&path) else {
+                    continue;
+                };
+
+                log::debug!(target: "linker", "loading imported module {path} from source");
+                let options = ParseOptions {
+                    warnings_as_errors: self.warnings_as_errors,
+                    path: Some(path.clone()),
+                    source_resolver: Some(resolver.clone()),
+                    ..ParseOptions::for_library()
+                };
+                let module =
+                    source.parse_with_options(&self.source_manager, options).map_err(|error| {
+                        LinkerError::ModuleLoadFailed {
+                            span,
+                            source_file: self.source_manager.get(span.source_id()).ok(),
+                            path: path.clone(),
+                            errors: Box::new([RelatedError::new(error)]),
+                        }
+                    })?;
+                self.link_module(module)?;
+            }
+        }
+
+        Ok(())
+    }
+
     fn is_pending(&self, path: &LibraryPath) -> bool {
         self.pending.iter().any(|m| m.module.path() == path)
     }
@@ -413,6 +500,7 @@ impl Linker {
         modules: impl IntoIterator<Item = Box<Module>>,
     ) -> Result<Vec<ModuleIndex>, LinkerError> {
         let module_indices = self.link_modules(modules)?;
+        self.load_imported_modules()?;
 
         self.link_and_rewrite()?;
 
@@ -428,6 +516,7 @@ impl Linker {
     /// kernel features, e.g. `caller`, as if they are defined by the kernel module itself.
     pub fn link_kernel(&mut self, kernel: Box<Module>) -> Result<Vec<ModuleIndex>, LinkerError> {
         let module_index = self.link_module(kernel)?;
+        self.load_imported_modules()?;
 
         // Set the module kind of all pending AST modules to Kernel, as we are linking a kernel
         for module in self.pending.iter_mut() {
@@ -1,12 +1,18 @@
-use alloc::{borrow::Cow, collections::BTreeSet, vec::Vec};
+use alloc::{
+    borrow::Cow,
+    boxed::Box,
+    collections::{BTreeMap, BTreeSet},
+    sync::Arc,
+    vec::Vec,
+};
 
 use miden_assembly_syntax::{
     Word,
     ast::{
-        Ident, InvocationTarget, InvokeKind, ProcedureName, QualifiedProcedureName,
+        ConstantExpr, Ident, InvocationTarget, InvokeKind, ProcedureName, QualifiedProcedureName,
         ResolvedProcedure,
     },
-    debuginfo::{SourceSpan, Span, Spanned},
+    debuginfo::{SourceFile, SourceSpan, Span, Spanned},
     diagnostics::RelatedLabel,
     library::{LibraryNamespace, LibraryPath},
 };
@@ -26,6 +32,7 @@ struct ThinModule {
     index: ModuleIndex,
     path: LibraryPath,
     resolver: crate::ast::LocalNameResolver,
+    constants: BTreeMap<Ident, ConstantExpr>,
 }
 
 /// Represents the context in which names should be resolved.
@@ -72,6 +79,19 @@ impl ResolvedTarget {
     }
 }
 
+/// Represents a single step taken by the [NameResolver] when resolving a procedure name, see
+/// [NameResolver::resolution_trace].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ResolutionStep {
+    /// The name resolved to an alias, and resolution continued with the aliased procedure, whose
+    /// module path is given in its fully-resolved form.
+    Alias(QualifiedProcedureName),
+    /// The name resolved to a concrete procedure definition in the module graph.
+    Procedure(GlobalProcedureIndex),
+    /// The name resolved to a MAST root.
+    MastRoot(Word),
+}
+
 // NAME RESOLVER
 // ================================================================================================
 
@@ -114,6 +134,11 @@ impl<'a> NameResolver<'a> {
             index: module.module_index,
             path: module.module.path().clone(),
             resolver: module.module.resolver(),
+            constants: module
+                .module
+                .constants()
+                .map(|(name, value)| (name.clone(), value.clone()))
+                .collect(),
         });
     }
 
@@ -180,6 +205,130 @@ impl<'a> NameResolver<'a> {
         }
     }
 
+    /// Resolve `name`, a constant defined in the module imported as `module`, to its value, using
+    /// `caller` as the context.
+    ///
+    /// Constants are only known for modules we have the AST for, so this returns `Err` if the
+    /// imported module was provided as part of an already-assembled library.
+    pub fn resolve_constant(
+        &self,
+        caller: &CallerInfo,
+        module: &Ident,
+        name: &Ident,
+    ) -> Result<ConstantExpr, LinkerError> {
+        log::debug!(target: "name-resolver", "resolving constant {module}::{name}");
+        let path =
+            self.resolve_import(caller, module)
+                .ok_or_else(|| LinkerError::UndefinedModule {
+                    span: caller.span,
+                    source_file: self.source_file(caller.span),
+                    path: LibraryPath::new_from_components(
+                        LibraryNamespace::User(module.clone().into_inner()),
+                        [],
+                    ),
+                })?;
+        self.resolve_constant_path(caller, path.into_inner(), name)
+    }
+
+    /// Resolve `name`, a constant defined in the module with the fully-qualified path `path`, to
+    /// its value, using `caller` as the context.
+    ///
+    /// Constants are only known for modules we have the AST for, so this returns `Err` if the
+    /// module was provided as part of an already-assembled library.
+    pub fn resolve_constant_path(
+        &self,
+        caller: &CallerInfo,
+        path: &LibraryPath,
+        name: &Ident,
+    ) -> Result<ConstantExpr, LinkerError> {
+        log::debug!(target: "name-resolver", "resolving constant {path}::{name}");
+        let module_index = self.find_module_index(caller.module, path).ok_or_else(|| {
+            LinkerError::UndefinedModule {
+                span: caller.span,
+                source_file: self.source_file(caller.span),
+                path: path.clone(),
+            }
+        })?;
+        let value = match self.graph.modules[module_index.as_usize()].as_ref() {
+            Some(ModuleLink::Ast(module)) => module.get_constant(name).cloned(),
+            Some(ModuleLink::Info(_)) => None,
+            None => self.pending[self.pending_index(module_index)].constants.get(name).cloned(),
+        };
+        value.ok_or_else(|| LinkerError::UndefinedConstant {
+            span: caller.span,
+            source_file: self.source_file(caller.span),
+            path: path.clone(),
+            name: name.clone(),
+        })
+    }
+
+    /// Returns the source file containing `span`, if it is known to the source manager.
+    pub fn source_file(&self, span: SourceSpan) -> Option<Arc<SourceFile>> {
+        self.graph.source_manager.get(span.source_id()).ok()
+    }
+
+    /// Returns the steps taken to resolve `callee`, using `caller` as the context in which `callee`
+    /// should be resolved.
+    ///
+    /// This follows the same path as name resolution does, recording every alias visited along the
+    /// way, in order, followed by the definition the name ultimately resolved to. If resolution
+    /// fails, the trace ends with the last alias that could be resolved.
+    ///
+    /// NOTE: Once a module has been linked, its aliases are rewritten to refer directly to the
+    /// definition they resolve to, so the full chain of aliases is only observable while the
+    /// module is pending.
+    pub fn resolution_trace(
+        &self,
+        caller: &CallerInfo,
+        callee: &ProcedureName,
+    ) -> Vec<ResolutionStep> {
+        let mut trace = Vec::new();
+        let mut module = if matches!(caller.kind, InvokeKind::SysCall) {
+            // Resolve local names relative to the kernel
+            match self.graph.kernel_index {
+                Some(kernel_index) => kernel_index,
+                None => return trace,
+            }
+        } else {
+            caller.module
+        };
+        let mut name = callee.clone();
+        let mut visited = BTreeSet::default();
+        loop {
+            match self.resolve_local_with_index(module, &name) {
+                Some(ResolvedProcedure::Local(index)) => {
+                    let gid = GlobalProcedureIndex { module, index: index.into_inner() };
+                    trace.push(ResolutionStep::Procedure(gid));
+                    break;
+                },
+                Some(ResolvedProcedure::External(fqn)) => {
+                    let Some(next_module) = self.find_module_index(module, &fqn.module) else {
+                        break;
+                    };
+                    let fqn = QualifiedProcedureName {
+                        span: fqn.span(),
+                        module: self.module_path(next_module),
+                        name: fqn.name,
+                    };
+                    // Stop if the alias loops back on itself
+                    if !visited.insert(fqn.clone()) {
+                        break;
+                    }
+                    trace.push(ResolutionStep::Alias(fqn.clone()));
+                    module = next_module;
+                    name = fqn.name;
+                },
+                Some(ResolvedProcedure::MastRoot(digest)) => {
+                    trace.push(ResolutionStep::MastRoot(digest));
+                    break;
+                },
+                None => break,
+            }
+        }
+
+        trace
+    }
+
     /// Resolver `callee` to a [ResolvedTarget], using `caller` as the context in which `callee`
     /// should be resolved.
     fn resolve(
@@ -361,6 +510,19 @@ impl<'a> NameResolver<'a> {
                             ].into(),
                         });
                     }
+                    // Guard against pathologically long (but acyclic) alias chains
+                    if visited.len() > self.graph.max_resolution_depth {
+                        break Err(LinkerError::ResolutionDepthExceeded {
+                            span: caller.span,
+                            source_file: self
+                                .graph
+                                .source_manager
+                                .get(caller.span.source_id())
+                                .ok(),
+                            callee: Box::new(callee.clone()),
+                            max_depth: self.graph.max_resolution_depth,
+                        });
+                    }
                     current_caller = Cow::Owned(CallerInfo {
                         span: fqn.span(),
                         module: module_index,
@@ -466,6 +628,53 @@ impl<'a> NameResolver<'a> {
         }
     }
 
+    /// Returns every procedure definition named `callee` in the module graph, including pending
+    /// modules, using `caller` as the context in which `callee` should be found.
+    ///
+    /// Unlike `find`, this does not resolve `callee` relative to a specific module, and does
+    /// not fail if `callee` is defined in multiple modules, or in none. Aliases are not included,
+    /// only the definitions themselves. This is intended for tooling, e.g. to suggest candidates
+    /// for a name which could not be resolved.
+    ///
+    /// If the caller is a syscall, only the kernel module is searched.
+    pub fn find_all(
+        &self,
+        caller: &CallerInfo,
+        callee: &ProcedureName,
+    ) -> Vec<GlobalProcedureIndex> {
+        let is_syscall = matches!(caller.kind, InvokeKind::SysCall);
+        let modules = self
+            .graph
+            .modules
+            .iter()
+            .enumerate()
+            .filter_map(|(idx, m)| m.as_ref().map(|_| ModuleIndex::new(idx)))
+            .chain(self.pending.iter().map(|m| m.index));
+
+        modules
+            .filter(|module| !is_syscall || self.graph.kernel_index == Some(*module))
+            .filter_map(|module| {
+                let index = match self.graph.modules[module.as_usize()].as_ref() {
+                    Some(ModuleLink::Ast(ast)) => match ast.resolve(callee)? {
+                        ResolvedProcedure::Local(index) => index.into_inner(),
+                        _ => return None,
+                    },
+                    Some(ModuleLink::Info(info)) => {
+                        info.procedures().find(|(_, p)| &p.name == callee)?.0
+                    },
+                    None => {
+                        let pending_index = self.pending_index(module);
+                        match self.pending[pending_index].resolver.resolve(callee)? {
+                            ResolvedProcedure::Local(index) => index.into_inner(),
+                            _ => return None,
+                        }
+                    },
+                };
+                Some(GlobalProcedureIndex { module, index })
+            })
+            .collect()
+    }
+
     /// Resolve a [LibraryPath] from `src` to a [ModuleIndex] in this graph
     fn find_module_index(&self, src: ModuleIndex, name: &LibraryPath) -> Option<ModuleIndex> {
         log::debug!(target: "name-resolver", "finding module index for {name:?} from {src}");
@@ -523,3 +732,118 @@ impl<'a> NameResolver<'a> {
             .expect("invalid pending module index")
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ast::ProcedureIndex, diagnostics::Report, testing::TestContext};
+
+    #[test]
+    fn resolution_trace_follows_alias_chain() -> Result<(), Report> {
+        const FOO: &str = r#"
+            use.lib::bar
+
+            export.bar::bar"#;
+        const BAR: &str = r#"
+            use.lib::baz
+
+            export.baz::baz->bar"#;
+        const BAZ: &str = r#"
+            export.baz
+                add
+            end"#;
+
+        let context = TestContext::new();
+        let foo = context.parse_module_with_path("lib::foo".parse().unwrap(), FOO)?;
+        let bar = context.parse_module_with_path("lib::bar".parse().unwrap(), BAR)?;
+        let baz = context.parse_module_with_path("lib::baz".parse().unwrap(), BAZ)?;
+
+        let mut linker = Linker::new(context.source_manager());
+        let indices = linker.link_modules([foo, bar, baz])?;
+        let mut resolver = NameResolver::new(&linker);
+        for module in linker.pending.iter() {
+            resolver.push_pending(module);
+        }
+
+        let caller = CallerInfo {
+            span: SourceSpan::default(),
+            module: indices[0],
+            kind: InvokeKind::Exec,
+        };
+        let trace = resolver.resolution_trace(&caller, &ProcedureName::new("bar").unwrap());
+
+        let expected = vec![
+            ResolutionStep::Alias(QualifiedProcedureName::new(
+                "lib::bar".parse().unwrap(),
+                ProcedureName::new("bar").unwrap(),
+            )),
+            ResolutionStep::Alias(QualifiedProcedureName::new(
+                "lib::baz".parse().unwrap(),
+                ProcedureName::new("baz").unwrap(),
+            )),
+            ResolutionStep::Procedure(GlobalProcedureIndex {
+                module: indices[2],
+                index: ProcedureIndex::new(0),
+            }),
+        ];
+        assert_eq!(trace, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn find_all_returns_every_definition() -> Result<(), Report> {
+        const A: &str = r#"
+            export.foo
+                add
+            end"#;
+        const B: &str = r#"
+            export.foo
+                mul
+            end
+
+            export.bar
+                sub
+            end"#;
+        const C: &str = r#"
+            use.dummy::a
+
+            export.a::foo"#;
+
+        let context = TestContext::new();
+        let a = context.parse_module_with_path("dummy::a".parse().unwrap(), A)?;
+        let b = context.parse_module_with_path("dummy::b".parse().unwrap(), B)?;
+        let c = context.parse_module_with_path("dummy::c".parse().unwrap(), C)?;
+
+        let mut linker = Linker::new(context.source_manager());
+        let indices = linker.link([a, b])?;
+        linker.link_module(c)?;
+        let mut resolver = NameResolver::new(&linker);
+        for module in linker.pending.iter() {
+            resolver.push_pending(module);
+        }
+
+        let caller = CallerInfo {
+            span: SourceSpan::default(),
+            module: indices[0],
+            kind: InvokeKind::Exec,
+        };
+        let found = resolver.find_all(&caller, &ProcedureName::new("foo").unwrap());
+
+        // The alias in `dummy::c` is not a definition, and so is not returned
+        let expected = vec![
+            GlobalProcedureIndex {
+                module: indices[0],
+                index: ProcedureIndex::new(0),
+            },
+            GlobalProcedureIndex {
+                module: indices[1],
+                index: ProcedureIndex::new(0),
+            },
+        ];
+        assert_eq!(found, expected);
+        assert!(resolver.find_all(&caller, &ProcedureName::new("baz").unwrap()).is_empty());
+
+        Ok(())
+    }
+}
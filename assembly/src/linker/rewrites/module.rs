@@ -1,10 +1,13 @@
 use alloc::collections::BTreeSet;
 use core::ops::ControlFlow;
 
+use miden_assembly_syntax::{Felt, parser::IntValue};
+
 use crate::{
-    ModuleIndex, SourceSpan, Spanned,
+    ModuleIndex, SourceSpan, Span, Spanned,
     ast::{
-        AliasTarget, InvocationTarget, Invoke, InvokeKind, Module, Procedure,
+        AliasTarget, ConstantExpr, ErrorMsg, Immediate, InvocationTarget, Invoke, InvokeKind,
+        Module, Procedure,
         visit::{self, VisitMut},
     },
     linker::{CallerInfo, LinkerError, NameResolver, ResolvedTarget},
@@ -19,6 +22,8 @@ use crate::{
 /// * Resolving, at least partially, all of the invocation targets in procedures of the module, and
 ///   rewriting those targets as concretely as possible OR as phantom calls representing procedures
 ///   referenced by MAST root for which we have no definition.
+/// * Folding immediates which reference constants defined in imported modules to the value of
+///   those constants.
 pub struct ModuleRewriter<'a, 'b: 'a> {
     resolver: &'a NameResolver<'b>,
     module_id: ModuleIndex,
@@ -85,6 +90,58 @@ impl<'a, 'b: 'a> ModuleRewriter<'a, 'b> {
 
         ControlFlow::Continue(())
     }
+
+    /// Fold `imm` to a literal if it references a constant defined in another module, using
+    /// `convert` to obtain a value of the expected type from the value of the constant.
+    fn fold_imported_constant<T, F>(
+        &mut self,
+        imm: &mut Immediate<T>,
+        convert: F,
+    ) -> ControlFlow<LinkerError>
+    where
+        F: FnOnce(ConstantExpr) -> Option<T>,
+    {
+        let caller = CallerInfo {
+            span: imm.span(),
+            module: self.module_id,
+            kind: InvokeKind::Exec,
+        };
+        let (name, value) = match imm {
+            Immediate::ImportedConstant { module, name } => {
+                log::debug!(target: "linker", "    * folding constant {module}::{name}");
+                (name.clone(), self.resolver.resolve_constant(&caller, module, name))
+            },
+            Immediate::AbsoluteConstantPath { path, name } => {
+                log::debug!(target: "linker", "    * folding constant {path}::{name}");
+                (name.clone(), self.resolver.resolve_constant_path(&caller, path, name))
+            },
+            Immediate::Value(_) | Immediate::Constant(_) => return ControlFlow::Continue(()),
+        };
+        let span = caller.span;
+        let value = match value {
+            Ok(value) => value,
+            Err(err) => return ControlFlow::Break(err),
+        };
+        match convert(value) {
+            Some(value) => {
+                *imm = Immediate::Value(Span::new(span, value));
+                ControlFlow::Continue(())
+            },
+            None => ControlFlow::Break(LinkerError::InvalidConstant {
+                span,
+                source_file: self.resolver.source_file(span),
+                name,
+            }),
+        }
+    }
+}
+
+/// Converts a literal constant value to an integer immediate of type `T`, if it is in range.
+fn literal_as<T: TryFrom<u64>>(value: ConstantExpr) -> Option<T> {
+    match value {
+        ConstantExpr::Literal(value) => T::try_from(value.into_inner().as_int()).ok(),
+        _ => None,
+    }
 }
 
 impl<'a, 'b: 'a> VisitMut<LinkerError> for ModuleRewriter<'a, 'b> {
@@ -96,6 +153,40 @@ impl<'a, 'b: 'a> VisitMut<LinkerError> for ModuleRewriter<'a, 'b> {
         procedure.extend_invoked(core::mem::take(&mut self.invoked));
         ControlFlow::Continue(())
     }
+    fn visit_mut_immediate_u8(&mut self, imm: &mut Immediate<u8>) -> ControlFlow<LinkerError> {
+        self.fold_imported_constant(imm, literal_as)
+    }
+    fn visit_mut_immediate_u16(&mut self, imm: &mut Immediate<u16>) -> ControlFlow<LinkerError> {
+        self.fold_imported_constant(imm, literal_as)
+    }
+    fn visit_mut_immediate_u32(&mut self, imm: &mut Immediate<u32>) -> ControlFlow<LinkerError> {
+        self.fold_imported_constant(imm, literal_as)
+    }
+    fn visit_mut_immediate_felt(&mut self, imm: &mut Immediate<Felt>) -> ControlFlow<LinkerError> {
+        self.fold_imported_constant(imm, |value| match value {
+            ConstantExpr::Literal(value) => Some(value.into_inner()),
+            _ => None,
+        })
+    }
+    fn visit_mut_immediate_hex(
+        &mut self,
+        imm: &mut Immediate<IntValue>,
+    ) -> ControlFlow<LinkerError> {
+        self.fold_imported_constant(imm, |value| match value {
+            ConstantExpr::Literal(value) => Some(IntValue::Felt(value.into_inner())),
+            ConstantExpr::Word(value) => Some(IntValue::Word(value.into_inner())),
+            _ => None,
+        })
+    }
+    fn visit_mut_immediate_error_message(
+        &mut self,
+        imm: &mut ErrorMsg,
+    ) -> ControlFlow<LinkerError> {
+        self.fold_imported_constant(imm, |value| match value {
+            ConstantExpr::String(value) => Some(value.into_inner()),
+            _ => None,
+        })
+    }
     fn visit_mut_syscall(&mut self, target: &mut InvocationTarget) -> ControlFlow<LinkerError> {
         self.rewrite_target(InvokeKind::SysCall, target)
     }
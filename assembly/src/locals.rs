@@ -0,0 +1,162 @@
+use alloc::{vec, vec::Vec};
+use core::ops::ControlFlow;
+
+use miden_assembly_syntax::{
+    ast::{
+        Block, DebugOptions, Immediate, Instruction,
+        visit::{self, Visit, VisitMut},
+    },
+    debuginfo::Span,
+};
+use miden_core::WORD_SIZE;
+
+// DEAD LOCAL ELIMINATION
+// ================================================================================================
+
+/// Removes the procedure locals which are declared, but never referenced, by the procedure body
+/// `body` declaring `num_locals` locals.
+///
+/// Returns the rewritten body together with the reduced number of locals, or `None` if no local
+/// can be removed. The remaining locals keep their relative order, and references to them via
+/// `loc_load`, `loc_loadw`, `loc_store` and `loc_storew` are renumbered accordingly.
+///
+/// If the body accesses locals by word, every local keeps its offset within a word, and the number
+/// of locals keeps its remainder modulo the word size, so that word-aligned addresses remain
+/// word-aligned.
+///
+/// No locals are removed if the body takes the address of a local via `locaddr`, since the address
+/// may be used to access any part of the procedure frame, e.g. by passing it as a pointer to a
+/// callee. Likewise, no locals are removed if the body contains `debug.local` instructions, or
+/// references a local out of bounds; in the latter case, the error is reported when the procedure
+/// is compiled.
+pub(crate) fn eliminate_dead_locals(body: &Block, num_locals: u16) -> Option<(Block, u16)> {
+    let mut usage = LocalUsage {
+        num_locals,
+        used: vec![false; num_locals as usize],
+        is_word_addressed: false,
+    };
+    if usage.visit_block(body).is_break() {
+        return None;
+    }
+
+    let remapping = usage.into_remapping()?;
+    let mut body = body.clone();
+    let _ = RemapLocals { remapping: &remapping.indices }.visit_mut_block(&mut body);
+
+    Some((body, remapping.num_locals))
+}
+
+// LOCAL USAGE
+// ================================================================================================
+
+/// The new indices of the locals which are kept, along with the new number of locals.
+struct Remapping {
+    indices: Vec<Option<u16>>,
+    num_locals: u16,
+}
+
+/// Collects the locals referenced by a procedure body.
+///
+/// The traversal is stopped early if the body contains a reference which cannot be safely
+/// renumbered.
+struct LocalUsage {
+    num_locals: u16,
+    used: Vec<bool>,
+    is_word_addressed: bool,
+}
+
+impl LocalUsage {
+    fn into_remapping(self) -> Option<Remapping> {
+        if self.used.iter().all(|used| *used) {
+            return None;
+        }
+
+        let word_size = WORD_SIZE as u16;
+        let align = |index: u16, like: u16| {
+            if self.is_word_addressed {
+                index + (like % word_size + word_size - index % word_size) % word_size
+            } else {
+                index
+            }
+        };
+
+        let mut next_index = 0;
+        let mut indices = vec![None; self.used.len()];
+        for (old_index, _) in self.used.iter().enumerate().filter(|(_, used)| **used) {
+            let new_index = align(next_index, old_index as u16);
+            indices[old_index] = Some(new_index);
+            next_index = new_index + 1;
+        }
+        let new_num_locals = align(next_index, self.num_locals);
+
+        (new_num_locals < self.num_locals)
+            .then_some(Remapping { indices, num_locals: new_num_locals })
+    }
+}
+
+impl Visit for LocalUsage {
+    fn visit_inst(&mut self, inst: &Span<Instruction>) -> ControlFlow<()> {
+        let word_size = WORD_SIZE as u16;
+        let num_locals = self.num_locals as u32;
+        let (start, len) = match &**inst {
+            Instruction::LocLoad(Immediate::Value(index))
+            | Instruction::LocStore(Immediate::Value(index)) => {
+                let index = index.into_inner();
+                if index as u32 >= num_locals {
+                    return ControlFlow::Break(());
+                }
+                (index, 1)
+            },
+            Instruction::LocLoadW(Immediate::Value(index))
+            | Instruction::LocStoreW(Immediate::Value(index)) => {
+                let index = index.into_inner();
+                if index % word_size != 0 || index as u32 + WORD_SIZE as u32 > num_locals {
+                    return ControlFlow::Break(());
+                }
+                self.is_word_addressed = true;
+                (index, word_size)
+            },
+            Instruction::LocLoad(_)
+            | Instruction::LocStore(_)
+            | Instruction::LocLoadW(_)
+            | Instruction::LocStoreW(_)
+            | Instruction::Locaddr(_)
+            | Instruction::Debug(
+                DebugOptions::LocalAll
+                | DebugOptions::LocalRangeFrom(_)
+                | DebugOptions::LocalInterval(..),
+            ) => return ControlFlow::Break(()),
+            _ => return visit::visit_inst(self, inst),
+        };
+
+        let end = start.saturating_add(len).min(self.num_locals);
+        self.used[start as usize..end as usize].iter_mut().for_each(|used| *used = true);
+        ControlFlow::Continue(())
+    }
+}
+
+// LOCAL RENUMBERING
+// ================================================================================================
+
+/// Rewrites the local indices referenced by a procedure body according to a remapping computed
+/// by [`LocalUsage`].
+struct RemapLocals<'a> {
+    remapping: &'a [Option<u16>],
+}
+
+impl VisitMut for RemapLocals<'_> {
+    fn visit_mut_inst(&mut self, inst: &mut Span<Instruction>) -> ControlFlow<()> {
+        match &mut **inst {
+            Instruction::LocLoad(Immediate::Value(index))
+            | Instruction::LocStore(Immediate::Value(index))
+            | Instruction::LocLoadW(Immediate::Value(index))
+            | Instruction::LocStoreW(Immediate::Value(index)) => {
+                let new_index =
+                    self.remapping[index.into_inner() as usize].expect("local must be in use");
+                *index = Span::new(index.span(), new_index);
+                ControlFlow::Continue(())
+            },
+            _ => visit::visit_mut_inst(self, inst),
+        }
+    }
+}
@@ -12,6 +12,7 @@ use miden_core::{
         Remapping, SubtreeIterator,
     },
 };
+use smallvec::SmallVec;
 
 use super::{GlobalProcedureIndex, LinkerError, Procedure};
 use crate::{
@@ -49,10 +50,9 @@ pub struct MastForestBuilder {
     /// with the same digest are added to the MAST forest builder, only the first procedure is
     /// added to the map, and all subsequent insertions are ignored.
     procedures: BTreeMap<GlobalProcedureIndex, Procedure>,
-    /// A map from procedure MAST root to its global procedure index. Similar to the `procedures`
-    /// map, this map contains only the first inserted procedure for procedures with the same MAST
-    /// root.
-    proc_gid_by_mast_root: BTreeMap<Word, GlobalProcedureIndex>,
+    /// A map from procedure MAST root to the global procedure indices of all procedures with that
+    /// MAST root, in the order in which the procedures were inserted.
+    proc_gids_by_mast_root: BTreeMap<Word, SmallVec<[GlobalProcedureIndex; 1]>>,
     /// A map of MAST node fingerprints to their corresponding positions in the MAST forest.
     node_id_by_fingerprint: BTreeMap<MastNodeFingerprint, MastNodeId>,
     /// The reverse mapping of `node_id_by_fingerprint`. This map caches the fingerprints of all
@@ -60,16 +60,22 @@ pub struct MastForestBuilder {
     hash_by_node_id: BTreeMap<MastNodeId, MastNodeFingerprint>,
     /// A map of decorator fingerprints to their corresponding positions in the MAST forest.
     decorator_id_by_fingerprint: BTreeMap<DecoratorFingerprint, DecoratorId>,
-    /// A set of IDs for basic blocks which have been merged into a bigger basic blocks. This is
-    /// used as a candidate set of nodes that may be eliminated if the are not referenced by any
-    /// other node in the forest and are not a root of any procedure.
-    merged_basic_block_ids: BTreeSet<MastNodeId>,
+    /// A set of IDs for nodes which have been superseded by other nodes (e.g., basic blocks which
+    /// have been merged into bigger basic blocks). This is used as a candidate set of nodes that
+    /// may be eliminated if they are not referenced by any other node in the forest and are not a
+    /// root of any procedure.
+    superseded_node_ids: BTreeSet<MastNodeId>,
     /// A MastForest that contains the MAST of all statically-linked libraries, it's used to find
     /// precompiled procedures and copy their subtrees instead of inserting external nodes.
     statically_linked_mast: Arc<MastForest>,
     /// Keeps track of the new ids assigned to nodes that are copied from the MAST of
     /// statically-linked libraries.
     statically_linked_mast_remapping: Remapping,
+    /// The maximum number of operations placed into a single basic block when joining nodes, if
+    /// limited.
+    max_ops_per_basic_block: Option<usize>,
+    /// Whether peephole optimizations are applied to the operations of new basic blocks.
+    optimize: bool,
 }
 
 impl MastForestBuilder {
@@ -99,13 +105,33 @@ impl MastForestBuilder {
         })
     }
 
+    /// Limits the number of operations placed into a single basic block by [`Self::join_nodes`].
+    ///
+    /// Basic blocks with more operations are split into several contiguous basic blocks, so that
+    /// straight-line code is executed as a sequence of SPAN blocks rather than a single SPAN block
+    /// whose operation batches are separated by RESPAN operations.
+    pub fn set_max_ops_per_basic_block(&mut self, max_ops: Option<usize>) {
+        self.max_ops_per_basic_block = max_ops;
+    }
+
+    /// Sets whether peephole optimizations are applied to basic blocks built via
+    /// [`crate::basic_block_builder::BasicBlockBuilder`].
+    pub fn set_optimizations(&mut self, yes: bool) {
+        self.optimize = yes;
+    }
+
+    /// Returns true if peephole optimizations are enabled.
+    pub fn optimizations_enabled(&self) -> bool {
+        self.optimize
+    }
+
     /// Removes the unused nodes that were created as part of the assembly process, and returns the
     /// resulting MAST forest.
     ///
     /// It also returns the map from old node IDs to new node IDs. Any [`MastNodeId`] used in
     /// reference to the old [`MastForest`] should be remapped using this map.
     pub fn build(mut self) -> (MastForest, BTreeMap<MastNodeId, MastNodeId>) {
-        let nodes_to_remove = get_nodes_to_remove(self.merged_basic_block_ids, &self.mast_forest);
+        let nodes_to_remove = get_nodes_to_remove(self.superseded_node_ids, &self.mast_forest);
         let id_remappings = self.mast_forest.remove_nodes(&nodes_to_remove);
 
         (self.mast_forest, id_remappings)
@@ -178,11 +204,27 @@ impl MastForestBuilder {
 
     /// Returns a reference to the procedure with the specified MAST root, or None
     /// if such a procedure is not present in this MAST forest builder.
+    ///
+    /// If multiple procedures share the MAST root, the one inserted first is returned.
     #[inline(always)]
     pub fn find_procedure_by_mast_root(&self, mast_root: &Word) -> Option<&Procedure> {
-        self.proc_gid_by_mast_root
+        self.find_procedures_by_mast_root(mast_root).next()
+    }
+
+    /// Returns an iterator over all procedures with the specified MAST root, in the order in which
+    /// they were inserted into this MAST forest builder.
+    ///
+    /// Procedures with different names compile to the same MAST root when their bodies are
+    /// identical.
+    pub fn find_procedures_by_mast_root(
+        &self,
+        mast_root: &Word,
+    ) -> impl Iterator<Item = &Procedure> + '_ {
+        self.proc_gids_by_mast_root
             .get(mast_root)
-            .and_then(|gid| self.get_procedure(*gid))
+            .into_iter()
+            .flatten()
+            .filter_map(|gid| self.get_procedure(*gid))
     }
 
     /// Returns the [`MastNode`] for the provided MAST node ID, or None if a node with this ID is
@@ -221,7 +263,7 @@ impl MastForestBuilder {
 
         // We don't have a cache entry yet, but we do want to make sure we don't have a conflicting
         // cache entry with the same MAST root:
-        if let Some(cached) = self.find_procedure_by_mast_root(&procedure.mast_root()) {
+        for cached in self.find_procedures_by_mast_root(&procedure.mast_root()) {
             // Handle the case where a procedure with no locals is lowered to a MastForest
             // consisting only of an `External` node to another procedure which has one or more
             // locals. This will result in the calling procedure having the same digest as the
@@ -246,7 +288,7 @@ impl MastForestBuilder {
         }
 
         self.mast_forest.make_root(procedure.body_node_id());
-        self.proc_gid_by_mast_root.insert(procedure.mast_root(), gid);
+        self.proc_gids_by_mast_root.entry(procedure.mast_root()).or_default().push(gid);
         self.procedures.insert(gid, procedure);
 
         Ok(())
@@ -261,6 +303,9 @@ impl MastForestBuilder {
         debug_assert!(!node_ids.is_empty(), "cannot combine empty MAST node id list");
 
         let mut node_ids = self.merge_contiguous_basic_blocks(node_ids)?;
+        if let Some(max_ops) = self.max_ops_per_basic_block {
+            node_ids = self.split_large_basic_blocks(node_ids, max_ops)?;
+        }
 
         // build a binary tree of blocks joining them using JOIN blocks
         while node_ids.len() > 1 {
@@ -310,6 +355,62 @@ impl MastForestBuilder {
         Ok(merged_node_ids)
     }
 
+    /// Returns a list of [`MastNodeId`]s in which every basic block found in the provided list of
+    /// [`MastNodeId`]s which has more than `max_ops` operations is replaced by a sequence of basic
+    /// blocks with at most `max_ops` operations each.
+    fn split_large_basic_blocks(
+        &mut self,
+        node_ids: Vec<MastNodeId>,
+        max_ops: usize,
+    ) -> Result<Vec<MastNodeId>, Report> {
+        let mut split_node_ids = Vec::with_capacity(node_ids.len());
+
+        for mast_node_id in node_ids {
+            let basic_block_node = match self.mast_forest[mast_node_id].get_basic_block() {
+                Some(block) if block.num_operations() as usize > max_ops => block.clone(),
+                _ => {
+                    split_node_ids.push(mast_node_id);
+                    continue;
+                },
+            };
+
+            let operations: Vec<Operation> = basic_block_node.operations().copied().collect();
+            let num_chunks = operations.len().div_ceil(max_ops);
+            let first_chunk_idx = split_node_ids.len();
+
+            for (chunk_idx, chunk) in operations.chunks(max_ops).enumerate() {
+                let start = chunk_idx * max_ops;
+                let is_last_chunk = chunk_idx == num_chunks - 1;
+
+                // decorators placed after the last operation remain in the last chunk
+                let decorators = basic_block_node
+                    .decorators()
+                    .iter()
+                    .filter(|&&(op_idx, _)| {
+                        op_idx >= start && (op_idx < start + chunk.len() || is_last_chunk)
+                    })
+                    .map(|&(op_idx, decorator_id)| (op_idx - start, decorator_id))
+                    .collect();
+
+                split_node_ids.push(self.ensure_block(chunk.to_vec(), Some(decorators))?);
+            }
+
+            let before_enter = self.mast_forest[mast_node_id].before_enter().to_vec();
+            let after_exit = self.mast_forest[mast_node_id].after_exit().to_vec();
+            if !before_enter.is_empty() {
+                self.append_before_enter(split_node_ids[first_chunk_idx], &before_enter);
+            }
+            if !after_exit.is_empty() {
+                self.append_after_exit(*split_node_ids.last().unwrap(), &after_exit);
+            }
+
+            // the original block may now be orphaned, in which case it is removed later
+            self.superseded_node_ids.insert(mast_node_id);
+        }
+
+        Ok(split_node_ids)
+    }
+
     /// Creates a new basic block by appending all operations and decorators in the provided list of
     /// basic blocks (which are assumed to be contiguous).
     ///
@@ -364,7 +465,7 @@ impl MastForestBuilder {
         }
 
         // Mark the removed basic blocks as merged
-        self.merged_basic_block_ids.extend(contiguous_basic_block_ids.iter());
+        self.superseded_node_ids.extend(contiguous_basic_block_ids.iter());
 
         if !operations.is_empty() || !decorators.is_empty() {
             let merged_basic_block = self.ensure_block(operations, Some(decorators))?;
@@ -527,6 +628,34 @@ impl MastForestBuilder {
         let new_node_fingerprint = self.fingerprint_for_node(&self[node_id]);
         self.hash_by_node_id.insert(node_id, new_node_fingerprint);
     }
+
+    /// Adds a copy of the specified node with the provided decorators attached to it, and returns
+    /// the [`MastNodeId`] associated with the copy.
+    ///
+    /// The `before_enter` decorators are executed ahead of the decorators already attached to the
+    /// node, and the `after_exit` decorators after them, so that the new decorators bracket the
+    /// existing ones.
+    ///
+    /// Unlike [`Self::append_before_enter`] and [`Self::append_after_exit`], this leaves the
+    /// original node untouched, since it may be referenced elsewhere; if it isn't, it is removed
+    /// when the forest is built.
+    pub fn ensure_decorated_node(
+        &mut self,
+        node_id: MastNodeId,
+        before_enter: &[DecoratorId],
+        after_exit: &[DecoratorId],
+    ) -> Result<MastNodeId, Report> {
+        let mut node = self[node_id].clone();
+        node.prepend_before_enter(before_enter);
+        node.append_after_exit(after_exit);
+
+        let decorated_node_id = self.ensure_node(node)?;
+        if decorated_node_id != node_id {
+            self.superseded_node_ids.insert(node_id);
+        }
+
+        Ok(decorated_node_id)
+    }
 }
 
 impl MastForestBuilder {
@@ -611,3 +740,64 @@ fn should_merge(is_procedure: bool, num_op_batches: usize) -> bool {
         true
     }
 }
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::ToString;
+
+    use miden_assembly_syntax::ast::{ProcedureIndex, QualifiedProcedureName, Visibility};
+
+    use super::*;
+    use crate::{DefaultSourceManager, ModuleIndex, ProcedureContext};
+
+    fn procedure(
+        index: u16,
+        name: &str,
+        mast_root: Word,
+        body_node_id: MastNodeId,
+    ) -> (GlobalProcedureIndex, Procedure) {
+        let gid = GlobalProcedureIndex {
+            module: ModuleIndex::const_new(0),
+            index: ProcedureIndex::const_new(index),
+        };
+        let name = QualifiedProcedureName::try_from(name).unwrap();
+        let procedure = ProcedureContext::new(
+            gid,
+            name,
+            Visibility::Public,
+            false,
+            Arc::new(DefaultSourceManager::default()),
+        )
+        .into_procedure(mast_root, body_node_id);
+        (gid, procedure)
+    }
+
+    #[test]
+    fn procedures_with_identical_mast_roots() {
+        let mut builder = MastForestBuilder::default();
+        let body_node_id = builder.ensure_block(vec![Operation::Add], None).unwrap();
+        let mast_root = builder[body_node_id].digest();
+
+        let (foo_gid, foo) = procedure(0, "test::foo", mast_root, body_node_id);
+        let (bar_gid, bar) = procedure(1, "test::bar", mast_root, body_node_id);
+        builder.insert_procedure(foo_gid, foo).unwrap();
+        builder.insert_procedure(bar_gid, bar).unwrap();
+
+        // both names resolve to the shared root
+        assert_eq!(builder.get_procedure(foo_gid).unwrap().mast_root(), mast_root);
+        assert_eq!(builder.get_procedure(bar_gid).unwrap().mast_root(), mast_root);
+
+        // the procedure inserted first is not clobbered by the second one
+        let first = builder.find_procedure_by_mast_root(&mast_root).unwrap();
+        assert_eq!(first.fully_qualified_name().to_string(), "test::foo");
+
+        let names: Vec<_> = builder
+            .find_procedures_by_mast_root(&mast_root)
+            .map(|procedure| procedure.fully_qualified_name().to_string())
+            .collect();
+        assert_eq!(names, ["test::foo", "test::bar"]);
+    }
+}
@@ -1,4 +1,8 @@
-use alloc::{collections::BTreeSet, string::ToString, vec::Vec};
+use alloc::{
+    collections::{BTreeMap, BTreeSet},
+    string::ToString,
+    vec::Vec,
+};
 use core::str::FromStr;
 use std::sync::{Arc, LazyLock};
 
@@ -17,12 +21,15 @@ use proptest::{
 };
 
 use crate::{
-    Assembler, Library, LibraryNamespace, LibraryPath, ModuleParser,
-    ast::{Ident, Module, ModuleKind, ProcedureName, QualifiedProcedureName},
+    Assembler, Library, LibraryNamespace, LibraryPath, MemSourceResolver, ModuleParser,
+    ast::{Export, Ident, Module, ModuleKind, Op, ProcedureName, QualifiedProcedureName},
     diagnostics::Report,
+    library::LibraryError,
     mast_forest_builder::MastForestBuilder,
     report,
-    testing::{TestContext, assert_diagnostic_lines, parse_module, regex, source_file},
+    testing::{
+        TestContext, assert_diagnostic, assert_diagnostic_lines, parse_module, regex, source_file,
+    },
 };
 
 type TestResult = Result<(), Report>;
@@ -75,15 +82,16 @@ end";
     Ok(())
 }
 
-/// TODO(pauls): Do we want to allow this in Miden Assembly?
 #[test]
-#[ignore]
 fn empty_program() -> TestResult {
     let context = TestContext::default();
     let source = source_file!(&context, "begin end");
     let program = context.assemble(source)?;
-    let expected = "begin basic_block noop end end";
-    assert_eq!(expected, format!("{}", program));
+    let expected = "\
+begin
+    basic_block noop end
+end";
+    assert_str_eq!(format!("{program}"), expected);
     Ok(())
 }
 
@@ -185,6 +193,117 @@ end";
     Ok(())
 }
 
+#[test]
+fn optimizations_remove_redundant_ops() -> TestResult {
+    let context = TestContext::default();
+    let source = source_file!(&context, "begin push.1 push.2 push.0 drop drop swap swap add end");
+
+    let program = context.assemble(source.clone())?;
+    let expected = "\
+begin
+    basic_block pad incr push(2) pad drop drop swap swap add end
+end";
+    assert_str_eq!(format!("{program}"), expected);
+
+    let program = Assembler::new(context.source_manager())
+        .with_optimizations(true)
+        .assemble_program(source)?;
+    let expected = "\
+begin
+    basic_block pad incr add end
+end";
+    assert_str_eq!(format!("{program}"), expected);
+    Ok(())
+}
+
+#[test]
+fn optimizations_preserve_used_and_decorated_ops() -> TestResult {
+    let context = TestContext::default();
+
+    // the pushed values are consumed by `add`, and the decorated `drop` must be kept; the `swap`
+    // pair in the `if` body is optimized, while the one spanning the block boundary is not
+    let source = source_file!(
+        &context,
+        "begin push.3 push.4 add push.5 trace.1 drop swap if.true swap swap end swap end"
+    );
+    let program = Assembler::new(context.source_manager())
+        .with_optimizations(true)
+        .assemble_program(source)?;
+    let expected = "\
+begin
+    join
+        join
+            basic_block push(3) push(4) add push(5) trace(1) drop swap end
+            if.true
+                basic_block noop end
+            else
+                basic_block noop end
+            end
+        end
+        basic_block swap end
+    end
+end";
+    assert_str_eq!(format!("{program}"), expected);
+    Ok(())
+}
+
+#[test]
+fn optimizations_eliminate_dead_locals() -> TestResult {
+    let context = TestContext::default();
+    let module = context.parse_module(source_file!(
+        &context,
+        "\
+export.foo.4
+    loc_store.1
+    loc_store.3
+    loc_load.3
+    loc_load.1
+    add
+end
+
+export.bar.12
+    loc_storew.8
+    loc_loadw.0
+end
+
+export.baz.8
+    locaddr.0
+    loc_store.1
+end"
+    ))?;
+    let optimize = |name: &str| {
+        module
+            .procedures()
+            .find_map(|export| match export {
+                Export::Procedure(proc) if proc.name().as_str() == name => {
+                    crate::locals::eliminate_dead_locals(proc.body(), proc.num_locals())
+                },
+                _ => None,
+            })
+            .map(|(body, num_locals)| {
+                let body = body.iter().map(|op| match op {
+                    Op::Inst(inst) => inst.to_string(),
+                    _ => unreachable!("unexpected control flow operation"),
+                });
+                (body.collect::<Vec<_>>(), num_locals)
+            })
+    };
+
+    // unused locals are removed, and the remaining ones are renumbered in order
+    let (body, num_locals) = optimize("foo").expect("locals were not removed");
+    assert_eq!(num_locals, 2);
+    assert_eq!(body, ["loc_store.0", "loc_store.1", "loc_load.1", "loc_load.0", "add"]);
+
+    // the unused word between two referenced words is removed
+    let (body, num_locals) = optimize("bar").expect("locals were not removed");
+    assert_eq!(num_locals, 8);
+    assert_eq!(body, ["loc_storew.4", "loc_loadw.0"]);
+
+    // the address of a local may be used to access any part of the frame, so all locals are kept
+    assert!(optimize("baz").is_none());
+    Ok(())
+}
+
 #[test]
 fn basic_block_and_simple_if_true() -> TestResult {
     let context = TestContext::default();
@@ -414,6 +533,100 @@ fn library_procedure_collision() -> Result<(), Report> {
     Ok(())
 }
 
+#[test]
+fn library_namespace_renaming() -> Result<(), Report> {
+    let context = TestContext::new();
+    let foo = r#"
+        export.foo
+            push.1 add
+        end
+    "#;
+    let foo = parse_module!(&context, "vendored::math::foo", foo);
+    let bar = r#"
+        use.vendored::math::foo
+
+        export.foo::foo->bar1
+
+        export.bar2
+            exec.foo::foo
+            call.foo::foo
+        end
+    "#;
+    let bar = parse_module!(&context, "vendored::bar", bar);
+    let library = Assembler::new(context.source_manager()).assemble_library([foo, bar])?;
+    let renamed = library.clone().with_namespace(LibraryNamespace::new("thirdparty").unwrap())?;
+
+    let expected_exports: BTreeSet<_> =
+        ["thirdparty::math::foo::foo", "thirdparty::bar::bar1", "thirdparty::bar::bar2"]
+            .into_iter()
+            .map(|name| QualifiedProcedureName::from_str(name).unwrap())
+            .collect();
+    assert_eq!(renamed.exports().cloned().collect::<BTreeSet<_>>(), expected_exports);
+
+    // procedures are referenced by MAST root, so neither the roots nor the forest change
+    for (name, renamed_name) in library.exports().zip(renamed.exports()) {
+        assert_eq!(name.name, renamed_name.name);
+        assert_eq!(
+            library.get_procedure_root_by_name(name.clone()),
+            renamed.get_procedure_root_by_name(renamed_name.clone())
+        );
+    }
+    assert_eq!(library.digest(), renamed.digest());
+    assert_eq!(library.mast_forest(), renamed.mast_forest());
+
+    // the source spans follow the renamed exports
+    let bar2 = QualifiedProcedureName::from_str("vendored::bar::bar2").unwrap();
+    let renamed_bar2 = QualifiedProcedureName::from_str("thirdparty::bar::bar2").unwrap();
+    assert!(renamed.source_span(&renamed_bar2).is_some());
+    assert_eq!(renamed.source_span(&renamed_bar2), library.source_span(&bar2));
+
+    // reserved namespaces are rejected
+    assert_matches!(
+        library.with_namespace(LibraryNamespace::Kernel),
+        Err(LibraryError::ReservedNamespace { .. })
+    );
+
+    Ok(())
+}
+
+#[test]
+fn library_export_prefix() -> Result<(), Report> {
+    let context = TestContext::new();
+    let foo = r#"
+        export.foo
+            push.1 add
+        end
+
+        export.bar
+            exec.foo
+            push.2 mul
+        end
+    "#;
+    let foo = parse_module!(&context, "lib::foo", foo);
+    let library = Assembler::new(context.source_manager())
+        .with_export_prefix("vendor_")?
+        .assemble_library([foo.clone()])?;
+
+    let expected_exports: BTreeSet<_> = ["lib::foo::vendor_foo", "lib::foo::vendor_bar"]
+        .into_iter()
+        .map(|name| QualifiedProcedureName::from_str(name).unwrap())
+        .collect();
+    assert_eq!(library.exports().cloned().collect::<BTreeSet<_>>(), expected_exports);
+
+    // the `exec` of `foo` by `bar` still resolves, and the procedures are unchanged
+    let unprefixed = Assembler::new(context.source_manager()).assemble_library([foo])?;
+    assert_eq!(
+        library.get_procedure_root_by_name("lib::foo::vendor_bar"),
+        unprefixed.get_procedure_root_by_name("lib::foo::bar")
+    );
+    assert_eq!(library.mast_forest(), unprefixed.mast_forest());
+
+    // prefixes which are not valid identifiers are rejected
+    assert!(Assembler::new(context.source_manager()).with_export_prefix("vendor ").is_err());
+
+    Ok(())
+}
+
 #[test]
 fn library_serialization() -> Result<(), Report> {
     let context = TestContext::new();
@@ -451,6 +664,87 @@ fn library_serialization() -> Result<(), Report> {
     Ok(())
 }
 
+#[test]
+fn library_source_spans() -> Result<(), Report> {
+    let context = TestContext::new();
+    let foo = r#"
+        export.foo
+            add
+        end
+    "#;
+    let foo = parse_module!(&context, "test::foo", foo);
+    let library = Assembler::new(context.source_manager()).assemble_library([foo])?;
+
+    // a library assembled from source retains the spans of its exports
+    let foo = QualifiedProcedureName::from_str("test::foo::foo").unwrap();
+    let span = library.source_span(&foo).expect("missing source span");
+    let source_manager = context.source_manager();
+    assert!(source_manager.source_slice(span).unwrap().starts_with("export.foo"));
+
+    let missing = QualifiedProcedureName::from_str("test::foo::bar").unwrap();
+    assert_eq!(library.source_span(&missing), None);
+
+    // a compiled library does not
+    let compiled = Library::read_from_bytes(&library.to_bytes()).unwrap();
+    assert_eq!(compiled.source_span(&foo), None);
+
+    Ok(())
+}
+
+#[test]
+fn library_output_arities() -> Result<(), Report> {
+    let context = TestContext::new();
+    let foo = r#"
+        #! Module with documented stack signatures
+
+        #! stack: [a, b] -> [c]
+        export.add
+            add
+        end
+
+        #! stack: [a, ...] -> [a, a, ...]
+        export.dup
+            dup
+        end
+
+        #! Duplicates the top element of the stack
+        export.undeclared
+            dup
+        end
+    "#;
+    let foo = parse_module!(&context, "test::foo", foo);
+    let library = Assembler::new(context.source_manager()).assemble_library([foo])?;
+
+    let add = QualifiedProcedureName::from_str("test::foo::add").unwrap();
+    let dup = QualifiedProcedureName::from_str("test::foo::dup").unwrap();
+    let undeclared = QualifiedProcedureName::from_str("test::foo::undeclared").unwrap();
+    assert_eq!(library.output_arity(&add), Some(1));
+    assert_eq!(library.output_arity(&dup), Some(2));
+    assert_eq!(library.output_arity(&undeclared), None);
+
+    // the declared arities are preserved when the library is serialized
+    let compiled = Library::read_from_bytes(&library.to_bytes()).unwrap();
+    assert_eq!(compiled.output_arity(&add), Some(1));
+    assert_eq!(compiled.output_arity(&dup), Some(2));
+    assert_eq!(compiled.output_arity(&undeclared), None);
+
+    Ok(())
+}
+
+#[test]
+fn library_deserialization_rejects_missing_header() -> Result<(), Report> {
+    let context = TestContext::new();
+    let foo = parse_module!(&context, "test::foo", "export.foo add end");
+    let library = Assembler::new(context.source_manager()).assemble_library([foo])?;
+
+    // libraries serialized before the header was introduced start directly with the MAST forest
+    let bytes = library.to_bytes();
+    let err = Library::read_from_bytes(&bytes[8..]).unwrap_err();
+    assert!(err.to_string().contains("library magic"), "unexpected error: {err}");
+
+    Ok(())
+}
+
 #[test]
 fn get_module_by_path() -> Result<(), Report> {
     let context = TestContext::new();
@@ -926,6 +1220,51 @@ fn constant_err_div_by_zero() -> TestResult {
     Ok(())
 }
 
+#[test]
+fn constant_err_div_by_zero_constant() -> TestResult {
+    let context = TestContext::default();
+    let source = source_file!(
+        &context,
+        "const.ZERO=0 \
+    const.C=5/ZERO \
+    begin \
+    push.C \
+    end"
+    );
+    assert_assembler_diagnostic!(
+        context,
+        source,
+        "syntax error",
+        "help: see emitted diagnostics for details",
+        "invalid constant expression: division by zero",
+        regex!(r#",-\[test[\d]+:1:22\]"#),
+        "1 | const.ZERO=0 const.C=5/ZERO begin push.C end",
+        "  :                      ^^^^^^",
+        "  `----"
+    );
+
+    let source = source_file!(
+        &context,
+        "const.ZERO=0 \
+    const.C=5//ZERO \
+    begin \
+    push.C \
+    end"
+    );
+    assert_assembler_diagnostic!(
+        context,
+        source,
+        "syntax error",
+        "help: see emitted diagnostics for details",
+        "invalid constant expression: division by zero",
+        regex!(r#",-\[test[\d]+:1:22\]"#),
+        "1 | const.ZERO=0 const.C=5//ZERO begin push.C end",
+        "  :                      ^^^^^^^",
+        "  `----"
+    );
+    Ok(())
+}
+
 #[test]
 fn constants_must_be_uppercase() -> TestResult {
     let context = TestContext::default();
@@ -1001,7 +1340,7 @@ fn constant_must_be_valid_felt() -> TestResult {
         "  :                       `-- found a constant identifier here",
         "  `----",
         " help: expected \"*\", or \"+\", or \"-\", or \"/\", or \"//\", or \"@\", or \"adv_map\", or \"begin\", or \"const\", \
-or \"export\", or \"proc\", or \"use\", or end of file, or doc comment"
+or \"export\", or \"include\", or \"proc\", or \"use\", or end of file, or doc comment"
     );
     Ok(())
 }
@@ -1093,7 +1432,7 @@ fn constants_defined_in_global_scope() -> TestResult {
         "  :             `-- found a const here",
         "3 |     push.CONSTANT end",
         "  `----",
-        r#" help: expected primitive opcode (e.g. "add"), or control flow opcode (e.g. "if.true")"#
+        r#" help: expected primitive opcode (e.g. "add"), or "end", or control flow opcode (e.g. "if.true")"#
     );
     Ok(())
 }
@@ -1125,6 +1464,152 @@ fn constant_not_found() -> TestResult {
     Ok(())
 }
 
+#[test]
+fn constants_imported_from_another_module() -> TestResult {
+    let mut context = TestContext::default();
+    context.add_module_from_source(
+        "lib::consts".parse().unwrap(),
+        source_file!(
+            &context,
+            "
+        const.MAX=1000
+        const.WORD=0x0200000000000000030000000000000004000000000000000500000000000000
+
+        export.foo
+            push.MAX
+        end"
+        ),
+    )?;
+
+    let source = source_file!(
+        &context,
+        "
+        use.lib::consts
+
+        begin
+            push.consts::MAX
+            add.consts::MAX
+            push.consts::WORD
+            u32lt.consts::MAX
+        end"
+    );
+    let program = context.assemble(source)?;
+    let expected = "\
+begin
+    basic_block
+        push(1000)
+        push(1000)
+        add
+        push(2)
+        push(3)
+        push(4)
+        push(5)
+        push(1000)
+        u32sub
+        swap
+        drop
+    end
+end";
+    assert_str_eq!(format!("{program}"), expected);
+    Ok(())
+}
+
+#[test]
+fn constants_referenced_by_module_path() -> TestResult {
+    let mut context = TestContext::default();
+    context.add_module_from_source(
+        "lib::consts".parse().unwrap(),
+        source_file!(
+            &context,
+            "
+        const.MAX=1000
+
+        export.foo
+            push.MAX
+        end"
+        ),
+    )?;
+
+    // the module does not need to be imported when it is referenced by its full path
+    let source = source_file!(
+        &context,
+        "
+        begin
+            push.lib::consts::MAX
+            add.::lib::consts::MAX
+        end"
+    );
+    let program = context.assemble(source)?;
+    let expected = "\
+begin
+    basic_block push(1000) push(1000) add end
+end";
+    assert_str_eq!(format!("{program}"), expected);
+    Ok(())
+}
+
+#[test]
+fn constant_in_undefined_module() -> TestResult {
+    let context = TestContext::default();
+    let source = source_file!(
+        &context,
+        "
+        begin
+            push.lib::consts::MAX
+        end"
+    );
+    assert_assembler_diagnostic!(
+        context,
+        source,
+        "undefined module 'lib::consts'",
+        regex!(r#",-\[test[\d]+:3:31\]"#),
+        "2 |         begin",
+        "3 |             push.lib::consts::MAX",
+        "  :                               ^^^",
+        "4 |         end",
+        "  `----"
+    );
+    Ok(())
+}
+
+#[test]
+fn imported_constant_not_found() -> TestResult {
+    let mut context = TestContext::default();
+    context.add_module_from_source(
+        "lib::consts".parse().unwrap(),
+        source_file!(
+            &context,
+            "
+        export.foo
+            push.1
+        end"
+        ),
+    )?;
+
+    let source = source_file!(
+        &context,
+        "
+        use.lib::consts
+
+        begin
+            push.consts::MAX
+        end"
+    );
+    assert_assembler_diagnostic!(
+        context,
+        source,
+        "undefined constant 'MAX' in module 'lib::consts'",
+        regex!(r#",-\[test[\d]+:5:26\]"#),
+        "4 |         begin",
+        "5 |             push.consts::MAX",
+        "  :                          ^^^",
+        "6 |         end",
+        "  `----",
+        " help: constants can only be referenced from modules which define them and are assembled from source"
+    );
+    Ok(())
+}
+
 #[test]
 fn mem_operations_with_constants() -> TestResult {
     let context = TestContext::default();
@@ -1528,24 +2013,100 @@ fn decorators_join_and_split() -> TestResult {
     );
     let expected = "\
 begin
-    join
-        trace(0) trace(1)
-        if.true
-            basic_block trace(2) add trace(3) end
-        else
-            basic_block trace(4) mul trace(5) end
-        end
-        trace(6)
-        if.true
-            basic_block trace(7) push(42) trace(8) end
-        else
-            basic_block trace(9) push(22) trace(10) end
-        end
+    join
+        trace(0) trace(1)
+        if.true
+            basic_block trace(2) add trace(3) end
+        else
+            basic_block trace(4) mul trace(5) end
+        end
+        trace(6)
+        if.true
+            basic_block trace(7) push(42) trace(8) end
+        else
+            basic_block trace(9) push(22) trace(10) end
+        end
+    end
+    trace(11)
+end";
+    let program = context.assemble(source)?;
+    assert_str_eq!(expected, format!("{program}"));
+    Ok(())
+}
+
+#[test]
+fn decorators_procedure_scope() -> TestResult {
+    let context = TestContext::default();
+    let source = source_file!(
+        &context,
+        "\
+    proc.foo
+        push.1 add
+    end
+
+    begin
+        push.2 exec.foo
+    end"
+    );
+    let expected = "\
+begin
+    basic_block
+        procEnter($exec::$main)
+        asmOp(push.2, 1)
+        push(2)
+        procEnter($exec::foo)
+        asmOp(push.1, 2)
+        pad
+        incr
+        asmOp(add, 1)
+        add
+        procExit($exec::foo)
+        procExit($exec::$main)
+    end
+end";
+    let program = Assembler::new(context.source_manager())
+        .with_debug_mode(true)
+        .assemble_program(source)?;
+    assert_str_eq!(expected, format!("{program}"));
+    Ok(())
+}
+
+#[test]
+fn loop_iteration_hint_in_debug_mode() -> TestResult {
+    let context = TestContext::default();
+    let source = source_file!(&context, "begin while.true(max=8) push.0 end end");
+    let program = Assembler::new(context.source_manager())
+        .with_debug_mode(true)
+        .assemble_program(source)?;
+    let expected = "\
+begin
+    procEnter($exec::$main) asmOp(while.true(max=8), 0) loopHint(8)
+    while.true
+        basic_block asmOp(push.0, 1) pad end
+    end
+    procExit($exec::$main)
+end";
+    assert_str_eq!(expected, format!("{program}"));
+    Ok(())
+}
+
+#[test]
+fn loop_iteration_hint_does_not_change_mast_root() -> TestResult {
+    let context = TestContext::default();
+    let hinted =
+        context.assemble(source_file!(&context, "begin while.true(max=8) push.0 end end"))?;
+    let plain = context.assemble(source_file!(&context, "begin while.true push.0 end end"))?;
+    assert_eq!(hinted.hash(), plain.hash());
+
+    // the hint is retained outside of debug mode as well
+    let expected = "\
+begin
+    loopHint(8)
+    while.true
+        basic_block pad end
     end
-    trace(11)
 end";
-    let program = context.assemble(source)?;
-    assert_str_eq!(expected, format!("{program}"));
+    assert_str_eq!(expected, format!("{hinted}"));
     Ok(())
 }
 
@@ -1854,6 +2415,37 @@ end"
     Ok(())
 }
 
+#[test]
+fn program_error_codes() -> TestResult {
+    let context = TestContext::default();
+    let source = source_file!(
+        &context,
+        "\
+    const.ERR1=\"first error\"
+
+    proc.foo
+        u32assert.err=\"second error\"
+        assert.err=ERR1
+    end
+
+    begin
+        assert
+        assert.err=ERR1
+        assert_eq.err=\"third error\"
+        exec.foo
+    end
+    "
+    );
+    let program = context.assemble(source)?;
+
+    let expected = ["first error", "second error", "third error"]
+        .into_iter()
+        .map(|msg| error_code_from_msg(msg).as_int())
+        .collect::<BTreeSet<_>>();
+    assert_eq!(program.error_codes(), expected);
+    Ok(())
+}
+
 /// Ensure that there is no collision between `Assert`, `U32assert2`, and `MpVerify`
 /// instructions with different inner values (which all don't contribute to the MAST root).
 #[test]
@@ -2812,7 +3404,7 @@ end";
         "  :                                      `-- found a -> here",
         "3 |",
         "  `----",
-        r#" help: expected "@", or "adv_map", or "begin", or "const", or "export", or "proc", or "use", or end of file, or doc comment"#
+        r#" help: expected "@", or "adv_map", or "begin", or "const", or "export", or "include", or "proc", or "use", or end of file, or doc comment"#
     );
 
     // --- duplicate module import --------------------------------------------
@@ -3109,7 +3701,7 @@ fn invalid_empty_program() {
         "unexpected end of file",
         regex!(r#",-\[test[\d]+:1:1\]"#),
         "`----",
-        r#" help: expected "@", or "adv_map", or "begin", or "const", or "export", or "proc", or "use", or doc comment"#
+        r#" help: expected "@", or "adv_map", or "begin", or "const", or "export", or "include", or "proc", or "use", or doc comment"#
     );
 
     assert_assembler_diagnostic!(
@@ -3118,7 +3710,7 @@ fn invalid_empty_program() {
         "unexpected end of file",
         regex!(r#",-\[test[\d]+:1:1\]"#),
         "  `----",
-        r#" help: expected "@", or "adv_map", or "begin", or "const", or "export", or "proc", or "use", or doc comment"#
+        r#" help: expected "@", or "adv_map", or "begin", or "const", or "export", or "include", or "proc", or "use", or doc comment"#
     );
 }
 
@@ -3134,7 +3726,7 @@ fn invalid_program_unrecognized_token() {
         "  : ^^|^",
         "  :   `-- found a identifier here",
         "  `----",
-        r#" help: expected "@", or "adv_map", or "begin", or "const", or "export", or "proc", or "use", or doc comment"#
+        r#" help: expected "@", or "adv_map", or "begin", or "const", or "export", or "include", or "proc", or "use", or doc comment"#
     );
 }
 
@@ -3164,7 +3756,7 @@ fn invalid_program_invalid_top_level_token() {
         "  :               ^|^",
         "  :                `-- found a mul here",
         "  `----",
-        r#" help: expected "@", or "adv_map", or "begin", or "const", or "export", or "proc", or "use", or end of file, or doc comment"#
+        r#" help: expected "@", or "adv_map", or "begin", or "const", or "export", or "include", or "proc", or "use", or end of file, or doc comment"#
     );
 }
 
@@ -3904,6 +4496,142 @@ fn emit_instruction_digest() {
     assert_ne!(procedure_digests[1], procedure_digests[2]);
 }
 
+#[test]
+fn program_procedure_digests() -> TestResult {
+    let context = TestContext::new();
+
+    let program_source = r#"
+        proc.foo
+            add
+            mul
+        end
+
+        proc.bar
+            mul
+            add
+        end
+
+        begin
+            call.foo
+            call.bar
+        end
+    "#;
+
+    let program = context.assemble(program_source)?;
+    assert_eq!(program.entrypoint_hash(), program.hash());
+
+    let mut mast_forest = MastForest::new();
+    let foo = mast_forest.add_block(vec![Operation::Add, Operation::Mul], None).unwrap();
+    let bar = mast_forest.add_block(vec![Operation::Mul, Operation::Add], None).unwrap();
+
+    let expected: BTreeSet<Word> =
+        [mast_forest[foo].digest(), mast_forest[bar].digest(), program.entrypoint_hash()].into();
+    let procedure_digests: BTreeSet<Word> = program.procedure_digests().collect();
+    assert_eq!(procedure_digests, expected);
+
+    Ok(())
+}
+
+#[test]
+fn procedure_source_instruction_counts() -> TestResult {
+    const MODULE: &str = "dummy::math";
+    const MODULE_BODY: &str = r#"
+        export.foo
+            push.1 add
+            mul
+        end
+
+        export.bar
+            dup
+            if.true
+                repeat.4
+                    push.2 mul
+                end
+            else
+                while.true
+                    sub.1 dup neq.0
+                end
+            end
+            drop
+        end
+    "#;
+
+    let context = TestContext::default();
+    let source_manager = context.source_manager();
+    let ast = Module::parser(ModuleKind::Library).parse_str(
+        MODULE.parse().unwrap(),
+        MODULE_BODY,
+        &source_manager,
+    )?;
+
+    let counts = ast.procedure_source_instruction_counts();
+    let expected = BTreeMap::from_iter([
+        (ProcedureName::new("foo").unwrap(), 3),
+        (ProcedureName::new("bar").unwrap(), 7),
+    ]);
+    assert_eq!(counts, expected);
+
+    // Sanity check that the module is a valid library
+    Assembler::new(source_manager).assemble_library([ast])?;
+
+    Ok(())
+}
+
+#[test]
+fn call_allowlist() -> TestResult {
+    let context = TestContext::new();
+    let program_source = "
+        proc.foo
+            add
+        end
+
+        proc.bar
+            mul
+        end
+
+        begin
+            call.foo
+            exec.bar
+        end";
+
+    let mut mast_forest = MastForest::new();
+    let foo = mast_forest.add_block(vec![Operation::Add], None).unwrap();
+    let bar = mast_forest.add_block(vec![Operation::Mul], None).unwrap();
+    let foo_root = mast_forest[foo].digest();
+    let bar_root = mast_forest[bar].digest();
+
+    // all invoked procedures are in the allowlist
+    let program = Assembler::new(context.source_manager())
+        .with_call_allowlist([foo_root, bar_root].into())
+        .assemble_program(program_source)?;
+    assert_eq!(program.procedure_digests().count(), 3);
+
+    // `exec.bar` is not in the allowlist
+    let err = Assembler::new(context.source_manager())
+        .with_call_allowlist([foo_root].into())
+        .assemble_program(program_source)
+        .expect_err("expected assembly to fail, but it succeeded");
+    assert_diagnostic!(
+        err,
+        format!("invalid exec: procedure with MAST root {bar_root} is not in the call allowlist")
+    );
+
+    // the definition of the callee is unknown, so it cannot be checked against the allowlist
+    let source = source_file!(&context, format!("begin call.{foo_root} end"));
+    let err = Assembler::new(context.source_manager())
+        .with_call_allowlist([foo_root].into())
+        .assemble_program(source)
+        .expect_err("expected assembly to fail, but it succeeded");
+    assert_diagnostic!(
+        err,
+        format!(
+            "invalid call: the definition of the procedure with MAST root {foo_root} is unknown"
+        )
+    );
+
+    Ok(())
+}
+
 /// Since `foo` and `bar` have the same body, we only expect them to be added once to the program.
 #[test]
 fn duplicate_procedure() {
@@ -4070,6 +4798,273 @@ fn re_exports() -> Result<(), Report> {
     Ok(())
 }
 
+#[test]
+fn reexports_of_same_name_from_different_modules_conflict() {
+    const C: &str = r#"
+        use.lib::a
+        use.lib::b
+
+        export.a::foo
+        export.b::foo"#;
+
+    let context = TestContext::new();
+
+    // A bare name can only refer to a single local definition or alias, so re-exporting the same
+    // name from two modules is rejected up front, rather than being resolved ambiguously
+    let err = context
+        .parse_module_with_path("lib::c".parse().unwrap(), C)
+        .expect_err("expected diagnostic to be raised, but parsing succeeded");
+    assert_diagnostic_lines!(
+        err,
+        "syntax error",
+        "help: see emitted diagnostics for details",
+        "symbol conflict: found duplicate definitions of the same name",
+        regex!(r#",-\[lib::c:5:9\]"#),
+        "4 |",
+        "5 |         export.a::foo",
+        "  :         ^^^^^^|^^^^^^",
+        "  :               `-- previously defined here",
+        "6 |         export.b::foo",
+        "  :         ^^^^^^|^^^^^^",
+        "  :               `-- conflict occurs here",
+        "  `----"
+    );
+}
+
+#[test]
+fn qualified_calls_to_same_name_in_different_modules() -> Result<(), Report> {
+    const A: &str = r#"
+        export.foo
+            add
+        end"#;
+    const B: &str = r#"
+        export.foo
+            mul
+        end"#;
+
+    let context = TestContext::new();
+    let a = context.parse_module_with_path("dummy::a".parse().unwrap(), A)?;
+    let b = context.parse_module_with_path("dummy::b".parse().unwrap(), B)?;
+
+    let source = source_file!(
+        &context,
+        r#"
+        use.dummy::a
+        use.dummy::b
+
+        begin
+            exec.a::foo
+            exec.b::foo
+        end"#
+    );
+    let mut assembler = Assembler::new(context.source_manager());
+    assembler.compile_and_statically_link_all([a, b])?;
+    let program = assembler.assemble_program(source)?;
+
+    let expected = "\
+begin
+    basic_block add mul end
+end";
+    assert_str_eq!(format!("{program}"), expected);
+
+    Ok(())
+}
+
+#[test]
+fn max_resolution_depth() -> Result<(), Report> {
+    const PROGRAM: &str = r#"
+        use.dummy::m7
+
+        begin
+            exec.m7::foo
+        end"#;
+
+    let context = TestContext::new();
+    // each module re-exports `foo` from the previous one, with `m0` containing its definition
+    let modules = || {
+        (0..8)
+            .map(|i| {
+                let source = if i == 0 {
+                    "export.foo push.1 add end".to_string()
+                } else {
+                    format!("use.dummy::m{}\nexport.m{}::foo", i - 1, i - 1)
+                };
+                context.parse_module_with_path(format!("dummy::m{i}").parse().unwrap(), source)
+            })
+            .collect::<Result<Vec<_>, _>>()
+    };
+
+    // the default limit does not affect long alias chains
+    let mut assembler = Assembler::new(context.source_manager());
+    assembler.compile_and_statically_link_all(modules()?)?;
+    let program = assembler.assemble_program(source_file!(&context, PROGRAM))?;
+    let expected = "\
+begin
+    basic_block pad incr add end
+end";
+    assert_str_eq!(format!("{program}"), expected);
+
+    // resolving `foo` in the last module requires following more aliases than permitted
+    let mut assembler = Assembler::new(context.source_manager()).with_max_resolution_depth(2);
+    assembler.compile_and_statically_link_all(modules()?)?;
+    let err = assembler
+        .assemble_program(source_file!(&context, PROGRAM))
+        .expect_err("expected assembly to fail, but it succeeded");
+    assert_diagnostic!(err, "the chain of aliases exceeds the maximum resolution depth of 2");
+
+    Ok(())
+}
+
+#[test]
+fn program_from_mem_sources() -> TestResult {
+    let context = TestContext::new();
+    let sources = MemSourceResolver::new([
+        (
+            "dummy::math".parse().unwrap(),
+            source_file!(&context, "export.double dup add end"),
+        ),
+        (
+            "dummy::util".parse().unwrap(),
+            source_file!(
+                &context,
+                "use.dummy::math\nexport.quadruple exec.math::double exec.math::double end"
+            ),
+        ),
+    ]);
+
+    let mut assembler = Assembler::new(context.source_manager());
+    assembler.compile_and_statically_link_from_sources(&sources)?;
+    let program = assembler.assemble_program(source_file!(
+        &context,
+        "use.dummy::util begin push.3 exec.util::quadruple end"
+    ))?;
+
+    let expected = "\
+begin
+    basic_block push(3) dup0 add dup0 add end
+end";
+    assert_str_eq!(format!("{program}"), expected);
+
+    Ok(())
+}
+
+#[test]
+fn program_with_modules_from_source_resolver() -> TestResult {
+    let context = TestContext::new();
+    let sources = MemSourceResolver::new([
+        (
+            "dummy::math".parse().unwrap(),
+            source_file!(&context, "export.double dup add end"),
+        ),
+        (
+            "dummy::util".parse().unwrap(),
+            source_file!(
+                &context,
+                "use.dummy::math\nexport.quadruple exec.math::double exec.math::double end"
+            ),
+        ),
+    ]);
+
+    // the modules are never linked explicitly, the linker loads them when they are imported
+    let assembler =
+        Assembler::new(context.source_manager()).with_source_resolver(Arc::new(sources));
+    let program = assembler.assemble_program(source_file!(
+        &context,
+        "use.dummy::util begin push.3 exec.util::quadruple end"
+    ))?;
+
+    let expected = "\
+begin
+    basic_block push(3) dup0 add dup0 add end
+end";
+    assert_str_eq!(format!("{program}"), expected);
+
+    Ok(())
+}
+
+#[test]
+fn program_with_invalid_module_from_source_resolver() {
+    let context = TestContext::new();
+    let sources = MemSourceResolver::new([(
+        "dummy::math".parse().unwrap(),
+        source_file!(&context, "export.double dup add"),
+    )]);
+
+    let assembler =
+        Assembler::new(context.source_manager()).with_source_resolver(Arc::new(sources));
+    let err = assembler
+        .assemble_program(source_file!(&context, "use.dummy::math begin exec.math::double end"))
+        .expect_err("expected assembly to fail, but it succeeded");
+    assert_diagnostic!(err, "failed to load module 'dummy::math'");
+}
+
+#[test]
+fn program_with_include() -> TestResult {
+    let context = TestContext::new();
+    let included = source_file!(&context, "proc.double dup add end");
+    let assembler = Assembler::new(context.source_manager()).with_source_resolver(Arc::new(
+        move |path: &str| (path == "double.masm").then(|| included.clone()),
+    ));
+    let program = assembler.assemble_program(source_file!(
+        &context,
+        "include.\"double.masm\" begin push.3 exec.double end"
+    ))?;
+
+    let expected = "\
+begin
+    basic_block push(3) dup0 add end
+end";
+    assert_str_eq!(format!("{program}"), expected);
+
+    Ok(())
+}
+
+#[test]
+fn program_builder_matches_assemble_program() -> Result<(), Report> {
+    const PROGRAM: &str = r#"
+        use.dummy::m0
+        use.dummy::m2
+
+        begin
+            exec.m0::foo
+            exec.m2::foo
+        end"#;
+
+    let context = TestContext::new();
+    let module_source = |i: usize| {
+        if i == 0 {
+            "export.foo push.1 add end".to_string()
+        } else {
+            format!("use.dummy::m{}\nexport.foo exec.m{}::foo push.{i} mul end", i - 1, i - 1)
+        }
+    };
+
+    // assemble the program with all modules provided at once
+    let modules = (0..3)
+        .map(|i| {
+            context
+                .parse_module_with_path(format!("dummy::m{i}").parse().unwrap(), module_source(i))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    let mut assembler = Assembler::new(context.source_manager());
+    assembler.compile_and_statically_link_all(modules)?;
+    let expected = assembler.assemble_program(source_file!(&context, PROGRAM))?;
+
+    // assemble the same program adding one module at a time, dropping each source once added
+    let mut builder = Assembler::new(context.source_manager()).begin();
+    for i in 0..3 {
+        let source = module_source(i);
+        builder.add_module(
+            context.parse_module_with_path(format!("dummy::m{i}").parse().unwrap(), source)?,
+        )?;
+    }
+    let program = builder.finish(source_file!(&context, PROGRAM))?;
+
+    assert_eq!(program.hash(), expected.hash());
+
+    Ok(())
+}
+
 #[test]
 fn module_ordering_can_be_arbitrary() -> Result<(), Report> {
     const A_NAME: &str = "a";
@@ -20,6 +20,8 @@ pub enum InputError {
 
 #[derive(Clone, Debug, thiserror::Error)]
 pub enum OutputError {
+    #[error("stack does not contain valid encoded bytes: {0}")]
+    InvalidByteEncoding(String),
     #[error("overflow addresses contains invalid field element: {0}")]
     InvalidOverflowAddress(String),
     #[error("overflow addresses length is {0}, but expected {1}")]
@@ -40,3 +42,14 @@ pub enum KernelError {
     #[error("kernel can have at most {0} procedures, received {1}")]
     TooManyProcedures(usize, usize),
 }
+
+// SIGNATURE ERROR
+// ================================================================================================
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, thiserror::Error)]
+pub enum SignatureError {
+    #[error("secret key is malformed")]
+    MalformedSecretKey,
+    #[error("generating signatures requires the `std` feature to be enabled")]
+    SigningUnsupported,
+}
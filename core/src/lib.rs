@@ -53,7 +53,7 @@ pub mod chiplets;
 pub mod errors;
 
 mod program;
-pub use program::{Program, ProgramInfo};
+pub use program::{Program, ProgramInfo, ProgramInfoDiff};
 
 mod kernel;
 pub use kernel::Kernel;
@@ -78,7 +78,7 @@ pub mod crypto {
 
     pub mod random {
         pub use miden_crypto::rand::{
-            RandomCoin, RandomCoinError, RpoRandomCoin, RpxRandomCoin, WinterRandomCoin,
+            FeltRng, RandomCoin, RandomCoinError, RpoRandomCoin, RpxRandomCoin, WinterRandomCoin,
         };
     }
 
@@ -118,7 +118,7 @@ pub mod prettier {
 mod operations;
 pub use operations::{
     AssemblyOp, DebugOptions, Decorator, DecoratorIterator, DecoratorList, Operation,
-    opcode_constants::*,
+    SignatureKind, WatchedFieldOp, opcode_constants::*,
 };
 
 pub mod stack;
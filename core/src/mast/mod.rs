@@ -299,53 +299,9 @@ impl MastForest {
         assert!(self.nodes.is_empty());
 
         // Add each node to the new MAST forest, making sure to rewrite any outdated internal
-        // `MastNodeId`s
+        // `MastNodeId`s; note that this preserves the decorators attached to the nodes
         for live_node in nodes_to_add {
-            match &live_node {
-                MastNode::Join(join_node) => {
-                    let first_child =
-                        id_remappings.get(&join_node.first()).copied().unwrap_or(join_node.first());
-                    let second_child = id_remappings
-                        .get(&join_node.second())
-                        .copied()
-                        .unwrap_or(join_node.second());
-
-                    self.add_join(first_child, second_child).unwrap();
-                },
-                MastNode::Split(split_node) => {
-                    let on_true_child = id_remappings
-                        .get(&split_node.on_true())
-                        .copied()
-                        .unwrap_or(split_node.on_true());
-                    let on_false_child = id_remappings
-                        .get(&split_node.on_false())
-                        .copied()
-                        .unwrap_or(split_node.on_false());
-
-                    self.add_split(on_true_child, on_false_child).unwrap();
-                },
-                MastNode::Loop(loop_node) => {
-                    let body_id =
-                        id_remappings.get(&loop_node.body()).copied().unwrap_or(loop_node.body());
-
-                    self.add_loop(body_id).unwrap();
-                },
-                MastNode::Call(call_node) => {
-                    let callee_id = id_remappings
-                        .get(&call_node.callee())
-                        .copied()
-                        .unwrap_or(call_node.callee());
-
-                    if call_node.is_syscall() {
-                        self.add_syscall(callee_id).unwrap();
-                    } else {
-                        self.add_call(callee_id).unwrap();
-                    }
-                },
-                MastNode::Block(_) | MastNode::Dyn(_) | MastNode::External(_) => {
-                    self.add_node(live_node).unwrap();
-                },
-            }
+            self.add_node(live_node.remap_children(id_remappings)).unwrap();
         }
     }
 
@@ -489,6 +445,11 @@ impl MastForest {
         code
     }
 
+    /// Returns the error messages registered in this MAST forest, keyed by their error codes.
+    pub fn error_codes(&self) -> &BTreeMap<u64, Arc<str>> {
+        &self.error_codes
+    }
+
     /// Given an error code as a Felt, resolves it to its corresponding error message.
     pub fn resolve_error_message(&self, code: Felt) -> Option<Arc<str>> {
         let key = u64::from(code);
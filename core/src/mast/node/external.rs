@@ -63,6 +63,12 @@ impl ExternalNode {
         self.before_enter.extend_from_slice(decorator_ids);
     }
 
+    /// Sets the list of decorators to be executed before this node, ahead of any decorators
+    /// which are already present.
+    pub fn prepend_before_enter(&mut self, decorator_ids: &[DecoratorId]) {
+        self.before_enter.splice(0..0, decorator_ids.iter().copied());
+    }
+
     /// Sets the list of decorators to be executed after this node.
     pub fn append_after_exit(&mut self, decorator_ids: &[DecoratorId]) {
         self.after_exit.extend_from_slice(decorator_ids);
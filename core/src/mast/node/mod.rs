@@ -286,6 +286,20 @@ impl MastNode {
         }
     }
 
+    /// Sets the list of decorators to be executed before this node, ahead of any decorators
+    /// which are already present.
+    pub fn prepend_before_enter(&mut self, decorator_ids: &[DecoratorId]) {
+        match self {
+            MastNode::Block(node) => node.prepend_decorators(decorator_ids),
+            MastNode::Join(node) => node.prepend_before_enter(decorator_ids),
+            MastNode::Split(node) => node.prepend_before_enter(decorator_ids),
+            MastNode::Loop(node) => node.prepend_before_enter(decorator_ids),
+            MastNode::Call(node) => node.prepend_before_enter(decorator_ids),
+            MastNode::Dyn(node) => node.prepend_before_enter(decorator_ids),
+            MastNode::External(node) => node.prepend_before_enter(decorator_ids),
+        }
+    }
+
     /// Sets the list of decorators to be executed after this node.
     pub fn append_after_exit(&mut self, decorator_ids: &[DecoratorId]) {
         match self {
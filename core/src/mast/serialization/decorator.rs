@@ -9,7 +9,7 @@ use super::{
     string_table::{StringTable, StringTableBuilder},
 };
 use crate::{
-    AssemblyOp, DebugOptions, Decorator,
+    AssemblyOp, DebugOptions, Decorator, WatchedFieldOp,
     utils::{
         ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable, SliceReader,
     },
@@ -91,6 +91,11 @@ impl DecoratorInfo {
 
                 Ok(Decorator::Debug(DebugOptions::StackTop(value)))
             },
+            EncodedDecoratorVariant::DebugOptionsStackHex => {
+                let value = data_reader.read_u8()?;
+
+                Ok(Decorator::Debug(DebugOptions::StackHex(value)))
+            },
             EncodedDecoratorVariant::DebugOptionsMemAll => {
                 Ok(Decorator::Debug(DebugOptions::MemAll))
             },
@@ -112,10 +117,38 @@ impl DecoratorInfo {
 
                 Ok(Decorator::Trace(value))
             },
+            EncodedDecoratorVariant::ProcScope => {
+                let enter = data_reader.read_bool()?;
+                let name = {
+                    let str_index_in_table = data_reader.read_usize()?;
+                    string_table.read_arc_str(str_index_in_table)?
+                };
+
+                Ok(Decorator::ProcScope { name, enter })
+            },
+            EncodedDecoratorVariant::LoopHint => {
+                let value = data_reader.read_u32()?;
+
+                Ok(Decorator::LoopHint(value))
+            },
             EncodedDecoratorVariant::DebugOptionsAdvStackTop => {
                 let value = data_reader.read_u16()?;
                 Ok(Decorator::Debug(DebugOptions::AdvStackTop(value)))
             },
+            EncodedDecoratorVariant::DebugOptionsWatchField => {
+                let op = match data_reader.read_u8()? {
+                    0 => WatchedFieldOp::Add,
+                    1 => WatchedFieldOp::Mul,
+                    op => {
+                        return Err(DeserializationError::InvalidValue(format!(
+                            "invalid watched field operation: {op}"
+                        )));
+                    },
+                };
+                let value = data_reader.read_u8()?;
+
+                Ok(Decorator::Debug(DebugOptions::WatchField(op, value)))
+            },
         }
     }
 }
@@ -145,6 +178,9 @@ impl Deserializable for DecoratorInfo {
 ///
 /// This is effectively equivalent to a set of constants, and designed to convert between variant
 /// discriminant and enum variant conveniently.
+///
+/// The discriminant of a variant is determined by its position, and is part of the serialized
+/// format. New variants must therefore be appended after the last existing one.
 #[derive(Debug, FromPrimitive, ToPrimitive)]
 #[repr(u8)]
 pub enum EncodedDecoratorVariant {
@@ -156,6 +192,10 @@ pub enum EncodedDecoratorVariant {
     DebugOptionsLocalInterval,
     DebugOptionsAdvStackTop,
     Trace,
+    DebugOptionsWatchField,
+    DebugOptionsStackHex,
+    ProcScope,
+    LoopHint,
 }
 
 impl EncodedDecoratorVariant {
@@ -184,8 +224,12 @@ impl From<&Decorator> for EncodedDecoratorVariant {
                 DebugOptions::MemInterval(..) => Self::DebugOptionsMemInterval,
                 DebugOptions::LocalInterval(..) => Self::DebugOptionsLocalInterval,
                 DebugOptions::AdvStackTop(_) => Self::DebugOptionsAdvStackTop,
+                DebugOptions::WatchField(..) => Self::DebugOptionsWatchField,
+                DebugOptions::StackHex(_) => Self::DebugOptionsStackHex,
             },
             Decorator::Trace(_) => Self::Trace,
+            Decorator::ProcScope { .. } => Self::ProcScope,
+            Decorator::LoopHint(_) => Self::LoopHint,
         }
     }
 }
@@ -270,7 +314,7 @@ impl DecoratorDataBuilder {
                 Some(data_offset)
             },
             Decorator::Debug(debug_options) => match debug_options {
-                DebugOptions::StackTop(value) => {
+                DebugOptions::StackTop(value) | DebugOptions::StackHex(value) => {
                     self.decorator_data.push(*value);
                     Some(data_offset)
                 },
@@ -278,6 +322,15 @@ impl DecoratorDataBuilder {
                     self.decorator_data.extend(value.to_le_bytes());
                     Some(data_offset)
                 },
+                DebugOptions::WatchField(op, value) => {
+                    let op = match op {
+                        WatchedFieldOp::Add => 0,
+                        WatchedFieldOp::Mul => 1,
+                    };
+                    self.decorator_data.push(op);
+                    self.decorator_data.push(*value);
+                    Some(data_offset)
+                },
                 DebugOptions::MemInterval(start, end) => {
                     self.decorator_data.extend(start.to_le_bytes());
                     self.decorator_data.extend(end.to_le_bytes());
@@ -296,6 +349,18 @@ impl DecoratorDataBuilder {
             Decorator::Trace(value) => {
                 self.decorator_data.extend(value.to_le_bytes());
 
+                Some(data_offset)
+            },
+            Decorator::ProcScope { name, enter } => {
+                self.decorator_data.write_bool(*enter);
+                let str_offset = self.string_table_builder.add_string(name);
+                self.decorator_data.write_usize(str_offset);
+
+                Some(data_offset)
+            },
+            Decorator::LoopHint(value) => {
+                self.decorator_data.extend(value.to_le_bytes());
+
                 Some(data_offset)
             },
         }
@@ -3,7 +3,10 @@ use alloc::string::ToString;
 use miden_crypto::{Felt, ONE, Word};
 
 use super::*;
-use crate::{AssemblyOp, DebugOptions, Decorator, mast::MastForestError, operations::Operation};
+use crate::{
+    AssemblyOp, DebugOptions, Decorator, WatchedFieldOp, mast::MastForestError,
+    operations::Operation,
+};
 
 /// If this test fails to compile, it means that `Operation` or `Decorator` was changed. Make sure
 /// that all tests in this file are updated accordingly. For example, if a new `Operation` variant
@@ -112,15 +115,33 @@ fn confirm_operation_and_decorator_structure() {
         Decorator::Debug(debug_options) => match debug_options {
             DebugOptions::StackAll => (),
             DebugOptions::StackTop(_) => (),
+            DebugOptions::StackHex(_) => (),
             DebugOptions::MemAll => (),
             DebugOptions::MemInterval(..) => (),
             DebugOptions::LocalInterval(..) => (),
             DebugOptions::AdvStackTop(_) => (),
+            DebugOptions::WatchField(..) => (),
         },
         Decorator::Trace(_) => (),
+        Decorator::ProcScope { .. } => (),
+        Decorator::LoopHint(_) => (),
     };
 }
 
+/// Decorator discriminants are part of the serialized format, and must not change when new
+/// decorators are added.
+#[test]
+fn decorator_discriminants_are_stable() {
+    use decorator::EncodedDecoratorVariant::*;
+
+    assert_eq!(AssemblyOp.discriminant(), 0);
+    assert_eq!(DebugOptionsAdvStackTop.discriminant(), 6);
+    assert_eq!(Trace.discriminant(), 7);
+    assert_eq!(DebugOptionsWatchField.discriminant(), 8);
+    assert_eq!(DebugOptionsStackHex.discriminant(), 9);
+    assert_eq!(ProcScope.discriminant(), 10);
+}
+
 #[test]
 fn serialize_deserialize_all_nodes() {
     let mut mast_forest = MastForest::new();
@@ -223,6 +244,7 @@ fn serialize_deserialize_all_nodes() {
         let num_operations = operations.len();
 
         let decorators = vec![
+            (0, Decorator::ProcScope { name: "test::foo".into(), enter: true }),
             (
                 0,
                 Decorator::AsmOp(AssemblyOp::new(
@@ -239,11 +261,16 @@ fn serialize_deserialize_all_nodes() {
             ),
             (0, Decorator::Debug(DebugOptions::StackAll)),
             (15, Decorator::Debug(DebugOptions::StackTop(255))),
+            (15, Decorator::Debug(DebugOptions::StackHex(2))),
             (15, Decorator::Debug(DebugOptions::MemAll)),
             (15, Decorator::Debug(DebugOptions::MemInterval(0, 16))),
             (17, Decorator::Debug(DebugOptions::LocalInterval(1, 2, 3))),
             (19, Decorator::Debug(DebugOptions::AdvStackTop(255))),
+            (19, Decorator::Debug(DebugOptions::WatchField(WatchedFieldOp::Add, 0))),
+            (19, Decorator::Debug(DebugOptions::WatchField(WatchedFieldOp::Mul, 3))),
             (num_operations, Decorator::Trace(55)),
+            (num_operations, Decorator::LoopHint(1024)),
+            (num_operations, Decorator::ProcScope { name: "test::foo".into(), enter: false }),
         ];
 
         mast_forest.add_block_with_raw_decorators(operations, decorators).unwrap()
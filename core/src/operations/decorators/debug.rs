@@ -13,6 +13,9 @@ pub enum DebugOptions {
     StackAll,
     /// Prints out the top n items of the stack for the current context.
     StackTop(u8),
+    /// Prints out the top n words of the stack for the current context as hex-encoded bytes. If n
+    /// is 0, all words of the stack are printed.
+    StackHex(u8),
     /// Prints out the entire contents of RAM.
     MemAll,
     /// Prints out the contents of memory stored in the provided interval. Interval boundaries are
@@ -28,6 +31,9 @@ pub enum DebugOptions {
     LocalInterval(u16, u16, u16),
     /// Prints out the top n items of the advice stack for the current context.
     AdvStackTop(u16),
+    /// Prints out a warning if applying the specified field operation to the stack items at
+    /// positions n and n + 1 would wrap around the field modulus.
+    WatchField(WatchedFieldOp, u8),
 }
 
 impl crate::prettier::PrettyPrint for DebugOptions {
@@ -41,12 +47,35 @@ impl fmt::Display for DebugOptions {
         match self {
             Self::StackAll => write!(f, "stack"),
             Self::StackTop(n) => write!(f, "stack.{n}"),
+            Self::StackHex(n) => write!(f, "stack.hex.{n}"),
             Self::MemAll => write!(f, "mem"),
             Self::MemInterval(n, m) => write!(f, "mem.{n}.{m}"),
             Self::LocalInterval(start, end, _) => {
                 write!(f, "local.{start}.{end}")
             },
             Self::AdvStackTop(n) => write!(f, "adv_stack.{n}"),
+            Self::WatchField(op, n) => write!(f, "watch_field.{op}.{n}"),
+        }
+    }
+}
+
+// WATCHED FIELD OPERATION
+// ================================================================================================
+
+/// Field operations which can be checked for wraparound by the `WatchField` debug option.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum WatchedFieldOp {
+    /// Field element addition.
+    Add,
+    /// Field element multiplication.
+    Mul,
+}
+
+impl fmt::Display for WatchedFieldOp {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Add => write!(f, "add"),
+            Self::Mul => write!(f, "mul"),
         }
     }
 }
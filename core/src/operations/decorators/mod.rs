@@ -1,4 +1,4 @@
-use alloc::{string::ToString, vec::Vec};
+use alloc::{string::ToString, sync::Arc, vec::Vec};
 use core::fmt;
 
 use miden_crypto::hash::blake::Blake3_256;
@@ -8,7 +8,10 @@ mod assembly_op;
 pub use assembly_op::AssemblyOp;
 
 mod debug;
-pub use debug::DebugOptions;
+pub use debug::{DebugOptions, WatchedFieldOp};
+
+mod signature;
+pub use signature::SignatureKind;
 
 use crate::mast::{DecoratorFingerprint, DecoratorId};
 
@@ -32,6 +35,14 @@ pub enum Decorator {
     Debug(DebugOptions),
     /// Emits a trace to the host.
     Trace(u32),
+    /// Marks the entry into (`enter == true`) or the exit from (`enter == false`) the procedure
+    /// with the specified fully-qualified name. These decorators are inserted by the assembler at
+    /// procedure boundaries in debug mode.
+    ProcScope { name: Arc<str>, enter: bool },
+    /// Indicates that the loop to which this decorator is attached is expected to run for at most
+    /// the specified number of iterations. This is a performance hint which the processor may use
+    /// to pre-allocate the execution trace; it is not enforced.
+    LoopHint(u32),
 }
 
 impl Decorator {
@@ -53,6 +64,20 @@ impl Decorator {
             },
             Self::Debug(debug) => Blake3_256::hash(debug.to_string().as_bytes()),
             Self::Trace(trace) => Blake3_256::hash(&trace.to_le_bytes()),
+            Self::ProcScope { name, enter } => {
+                let mut bytes_to_hash = Vec::from(name.as_bytes());
+                bytes_to_hash.push(*enter as u8);
+
+                Blake3_256::hash(&bytes_to_hash)
+            },
+            Self::LoopHint(max_iterations) => {
+                // prefix the hint so that it does not collide with a trace decorator of the same
+                // value
+                let mut bytes_to_hash = Vec::from(b"loop_hint".as_slice());
+                bytes_to_hash.extend(max_iterations.to_le_bytes());
+
+                Blake3_256::hash(&bytes_to_hash)
+            },
         }
     }
 }
@@ -71,6 +96,9 @@ impl fmt::Display for Decorator {
             },
             Self::Debug(options) => write!(f, "debug({options})"),
             Self::Trace(trace_id) => write!(f, "trace({trace_id})"),
+            Self::ProcScope { name, enter: true } => write!(f, "procEnter({name})"),
+            Self::ProcScope { name, enter: false } => write!(f, "procExit({name})"),
+            Self::LoopHint(max_iterations) => write!(f, "loopHint({max_iterations})"),
         }
     }
 }
@@ -0,0 +1,153 @@
+use alloc::vec::Vec;
+use core::fmt;
+
+use crate::{Felt, Word, errors::SignatureError, sys_events::EVENT_RPO_FALCON512_SIG_TO_STACK};
+
+// SIGNATURE KIND
+// ================================================================================================
+
+/// Digital signature schemes for which the host can provide the advice required to verify a
+/// signature in the VM.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum SignatureKind {
+    /// Falcon512 signatures over the RPO hash function.
+    RpoFalcon512,
+}
+
+impl SignatureKind {
+    /// Returns the ID of the event which requests the signature advice of this kind to be pushed
+    /// onto the advice stack.
+    ///
+    /// This is the ID of the corresponding
+    /// [`SystemEvent::Signature`](crate::sys_events::SystemEvent::Signature) event.
+    pub const fn event_id(&self) -> u32 {
+        match self {
+            Self::RpoFalcon512 => EVENT_RPO_FALCON512_SIG_TO_STACK,
+        }
+    }
+
+    /// Returns the signature kind requested by the event with the specified ID, if any.
+    pub const fn from_event_id(event_id: u32) -> Option<Self> {
+        match event_id {
+            EVENT_RPO_FALCON512_SIG_TO_STACK => Some(Self::RpoFalcon512),
+            _ => None,
+        }
+    }
+
+    /// Returns a human-readable name of this signature scheme.
+    pub const fn name(&self) -> &'static str {
+        match self {
+            Self::RpoFalcon512 => "RPO Falcon512",
+        }
+    }
+
+    /// Signs the provided message with the provided secret key and returns the values which the
+    /// VM requires to verify the signature, in the order they are expected to be pushed onto the
+    /// advice stack.
+    ///
+    /// The secret key is expected to be serialized with each byte encoded as a single field
+    /// element.
+    ///
+    /// # Errors
+    /// Returns an error if the secret key is malformed, or if this crate was built without the
+    /// `std` feature, which is required to generate signatures.
+    pub fn sign(&self, secret_key: &[Felt], message: Word) -> Result<Vec<Felt>, SignatureError> {
+        match self {
+            Self::RpoFalcon512 => falcon_sign(secret_key, message),
+        }
+    }
+}
+
+impl fmt::Display for SignatureKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+// RPO FALCON512
+// ================================================================================================
+
+/// Signs the provided message with the provided secret key and returns the resulting signature
+/// encoded in the format required by the rpo_faclcon512::verify procedure, or an error if the
+/// secret key is malformed due to either incorrect length or failed decoding.
+///
+/// The values are the ones required for a Falcon signature verification inside the VM and they are:
+///
+/// 1. The challenge point, a tuple of elements representing an element in the quadratic extension
+///    field, at which we evaluate the polynomials in the subsequent three points to check the
+///    product relationship.
+/// 2. The expanded public key represented as the coefficients of a polynomial of degree < 512.
+/// 3. The signature represented as the coefficients of a polynomial of degree < 512.
+/// 4. The product of the above two polynomials in the ring of polynomials with coefficients in the
+///    Miden field.
+/// 5. The nonce represented as 8 field elements.
+#[cfg(feature = "std")]
+fn falcon_sign(sk: &[Felt], msg: Word) -> Result<Vec<Felt>, SignatureError> {
+    use alloc::vec;
+
+    use crate::{
+        crypto::{
+            dsa::rpo_falcon512::{Polynomial, SecretKey},
+            hash::Rpo256,
+        },
+        utils::Deserializable,
+    };
+
+    // Create the corresponding secret key
+    let mut sk_bytes = Vec::with_capacity(sk.len());
+    for element in sk {
+        let value = element.as_int();
+        if value > u8::MAX as u64 {
+            return Err(SignatureError::MalformedSecretKey);
+        }
+        sk_bytes.push(value as u8);
+    }
+
+    let sk =
+        SecretKey::read_from_bytes(&sk_bytes).map_err(|_| SignatureError::MalformedSecretKey)?;
+
+    // We can now generate the signature
+    let sig = sk.sign(msg);
+
+    // The signature is composed of a nonce and a polynomial s2
+
+    // The nonce is represented as 8 field elements.
+    let nonce = sig.nonce();
+
+    // We convert the signature to a polynomial
+    let s2 = sig.sig_poly();
+
+    // We also need in the VM the expanded key corresponding to the public key the was provided
+    // via the operand stack
+    let h = sk.compute_pub_key_poly().0;
+
+    // Lastly, for the probabilistic product routine that is part of the verification procedure,
+    // we need to compute the product of the expanded key and the signature polynomial in
+    // the ring of polynomials with coefficients in the Miden field.
+    let pi = Polynomial::mul_modulo_p(&h, s2);
+
+    // We now push the expanded key, the signature polynomial, and the product of the
+    // expanded key and the signature polynomial to the advice stack. We also push
+    // the challenge point at which the previous polynomials will be evaluated.
+    // Finally, we push the nonce needed for the hash-to-point algorithm.
+
+    let mut polynomials: Vec<Felt> =
+        h.coefficients.iter().map(|a| Felt::from(a.value() as u32)).collect();
+    polynomials.extend(s2.coefficients.iter().map(|a| Felt::from(a.value() as u32)));
+    polynomials.extend(pi.iter().map(|a| Felt::new(*a)));
+
+    let digest_polynomials = Rpo256::hash_elements(&polynomials);
+    let challenge = (digest_polynomials[0], digest_polynomials[1]);
+
+    let mut result: Vec<Felt> = vec![challenge.0, challenge.1];
+    result.extend_from_slice(&polynomials);
+    result.extend_from_slice(&nonce.to_elements());
+
+    result.reverse();
+    Ok(result)
+}
+
+#[cfg(not(feature = "std"))]
+fn falcon_sign(_sk: &[Felt], _msg: Word) -> Result<Vec<Felt>, SignatureError> {
+    Err(SignatureError::SigningUnsupported)
+}
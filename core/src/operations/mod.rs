@@ -1,7 +1,10 @@
 use core::fmt;
 
 mod decorators;
-pub use decorators::{AssemblyOp, DebugOptions, Decorator, DecoratorIterator, DecoratorList};
+pub use decorators::{
+    AssemblyOp, DebugOptions, Decorator, DecoratorIterator, DecoratorList, SignatureKind,
+    WatchedFieldOp,
+};
 use opcode_constants::*;
 
 use crate::{
@@ -1,4 +1,4 @@
-use alloc::{sync::Arc, vec::Vec};
+use alloc::{collections::BTreeSet, sync::Arc, vec::Vec};
 use core::fmt;
 
 use miden_crypto::{Felt, WORD_SIZE, Word};
@@ -12,6 +12,19 @@ use crate::{
     utils::ToElements,
 };
 
+// CONSTANTS
+// ===============================================================================================
+
+/// Magic string for detecting that a file is a binary-encoded [`Program`].
+const MAGIC: &[u8; 5] = b"PROG\0";
+
+/// The format version of a binary-encoded [`Program`].
+///
+/// If future modifications are made to this format, the version should be incremented by 1. This
+/// is independent of the format version of the encoded [`MastForest`], which is checked
+/// separately.
+const VERSION: [u8; 3] = [0, 0, 0];
+
 // PROGRAM
 // ===============================================================================================
 
@@ -83,6 +96,13 @@ impl Program {
         self.entrypoint
     }
 
+    /// Returns the MAST root of the program's entrypoint procedure.
+    ///
+    /// This is the same as [`Program::hash`].
+    pub fn entrypoint_hash(&self) -> Word {
+        self.hash()
+    }
+
     /// Returns a reference to the underlying [`MastForest`].
     pub fn mast_forest(&self) -> &Arc<MastForest> {
         &self.mast_forest
@@ -108,10 +128,23 @@ impl Program {
         self.mast_forest.find_procedure_root(digest)
     }
 
+    /// Returns an iterator over the MAST roots of all procedures contained in this program,
+    /// including the entrypoint.
+    pub fn procedure_digests(&self) -> impl Iterator<Item = Word> + '_ {
+        self.mast_forest.procedure_digests()
+    }
+
     /// Returns the number of procedures in this program.
     pub fn num_procedures(&self) -> u32 {
         self.mast_forest.num_procedures()
     }
+
+    /// Returns the set of error codes referenced by the assertions in this program.
+    ///
+    /// The corresponding error messages can be resolved via [`MastForest::error_codes`].
+    pub fn error_codes(&self) -> BTreeSet<u64> {
+        self.mast_forest.error_codes().keys().copied().collect()
+    }
 }
 
 // ------------------------------------------------------------------------------------------------
@@ -148,10 +181,35 @@ impl Program {
             }
         })?
     }
+
+    /// Reads a [Program] from the provided file path.
+    pub fn deserialize_from_file<P>(path: P) -> Result<Self, DeserializationError>
+    where
+        P: AsRef<std::path::Path>,
+    {
+        use crate::utils::ReadAdapter;
+
+        let path = path.as_ref();
+        let mut file = std::fs::File::open(path).map_err(|err| {
+            DeserializationError::InvalidValue(format!(
+                "failed to open file at {}: {err}",
+                path.to_string_lossy()
+            ))
+        })?;
+        let mut adapter = ReadAdapter::new(&mut file);
+
+        Self::read_from(&mut adapter)
+    }
 }
 
+/// The binary encoding of a [`Program`] starts with a magic string and a format version, which are
+/// validated on deserialization, followed by the encoded MAST forest, kernel and entrypoint.
+///
+/// Deserializing a serialized program yields a program equal to the original one.
 impl Serializable for Program {
     fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        target.write_bytes(MAGIC);
+        target.write_bytes(&VERSION);
         self.mast_forest.write_into(target);
         self.kernel.write_into(target);
         target.write_u32(self.entrypoint.as_u32());
@@ -160,6 +218,20 @@ impl Serializable for Program {
 
 impl Deserializable for Program {
     fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let magic: [u8; 5] = source.read_array()?;
+        if magic != *MAGIC {
+            return Err(DeserializationError::InvalidValue(format!(
+                "Invalid program magic bytes. Expected '{:?}', got '{:?}'",
+                *MAGIC, magic
+            )));
+        }
+        let version: [u8; 3] = source.read_array()?;
+        if version != VERSION {
+            return Err(DeserializationError::InvalidValue(format!(
+                "Unsupported program version. Got '{version:?}', but only '{VERSION:?}' is supported",
+            )));
+        }
+
         let mast_forest = Arc::new(source.read()?);
         let kernel = source.read()?;
         let entrypoint = MastNodeId::from_u32_safe(source.read_u32()?, &mast_forest)?;
@@ -230,6 +302,85 @@ impl ProgramInfo {
     pub fn kernel_procedures(&self) -> &[Word] {
         self.kernel.proc_hashes()
     }
+
+    /// Returns the differences between this program info and `other`, e.g. to find out why a
+    /// proof generated for one program does not verify against the other.
+    pub fn diff(&self, other: &ProgramInfo) -> ProgramInfoDiff {
+        let program_hashes = (self.program_hash != other.program_hash)
+            .then_some((self.program_hash, other.program_hash));
+
+        let procedures: BTreeSet<_> = self.kernel_procedures().iter().collect();
+        let other_procedures: BTreeSet<_> = other.kernel_procedures().iter().collect();
+
+        ProgramInfoDiff {
+            program_hashes,
+            kernel_procedures_only_in_self: procedures
+                .difference(&other_procedures)
+                .map(|&&root| root)
+                .collect(),
+            kernel_procedures_only_in_other: other_procedures
+                .difference(&procedures)
+                .map(|&&root| root)
+                .collect(),
+        }
+    }
+}
+
+/// The differences between two [ProgramInfo]s, as returned by [ProgramInfo::diff].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ProgramInfoDiff {
+    program_hashes: Option<(Word, Word)>,
+    kernel_procedures_only_in_self: Vec<Word>,
+    kernel_procedures_only_in_other: Vec<Word>,
+}
+
+impl ProgramInfoDiff {
+    /// Returns true if the compared program infos are equal.
+    pub fn is_empty(&self) -> bool {
+        self.program_hashes.is_none() && !self.kernel_differs()
+    }
+
+    /// Returns the program hashes of the compared program infos, in the order they were compared,
+    /// or `None` if the program hashes are equal.
+    pub fn program_hashes(&self) -> Option<(&Word, &Word)> {
+        self.program_hashes.as_ref().map(|(hash, other_hash)| (hash, other_hash))
+    }
+
+    /// Returns true if the kernels of the compared program infos consist of different procedures.
+    pub fn kernel_differs(&self) -> bool {
+        !self.kernel_procedures_only_in_self.is_empty()
+            || !self.kernel_procedures_only_in_other.is_empty()
+    }
+
+    /// Returns the roots of the kernel procedures which are only in the kernel of the program info
+    /// on which [ProgramInfo::diff] was called.
+    pub fn kernel_procedures_only_in_self(&self) -> &[Word] {
+        &self.kernel_procedures_only_in_self
+    }
+
+    /// Returns the roots of the kernel procedures which are only in the kernel of the program info
+    /// passed to [ProgramInfo::diff].
+    pub fn kernel_procedures_only_in_other(&self) -> &[Word] {
+        &self.kernel_procedures_only_in_other
+    }
+}
+
+impl fmt::Display for ProgramInfoDiff {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_empty() {
+            return writeln!(f, "program infos are equal");
+        }
+        if let Some((hash, other_hash)) = self.program_hashes() {
+            writeln!(f, "program hash differs: {hash} != {other_hash}")?;
+        }
+        for root in self.kernel_procedures_only_in_self() {
+            writeln!(f, "kernel procedure only in self: {root}")?;
+        }
+        for root in self.kernel_procedures_only_in_other() {
+            writeln!(f, "kernel procedure only in other: {root}")?;
+        }
+        Ok(())
+    }
 }
 
 impl From<Program> for ProgramInfo {
@@ -294,3 +445,90 @@ fn pad_next_mul_8(input: &mut Vec<Felt>) {
     let output_len = input.len().next_multiple_of(8);
     input.resize(output_len, Felt::ZERO);
 }
+
+// TESTS
+// ===============================================================================================
+
+#[cfg(test)]
+mod tests {
+    use winter_utils::DeserializationError;
+
+    use super::*;
+    use crate::Operation;
+
+    fn test_program() -> Program {
+        let mut mast_forest = MastForest::new();
+        let entrypoint =
+            mast_forest.add_block(vec![Operation::Pad, Operation::Incr], None).unwrap();
+        mast_forest.make_root(entrypoint);
+        let kernel = Kernel::new(&[Word::default()]).unwrap();
+
+        Program::with_kernel(Arc::new(mast_forest), entrypoint, kernel)
+    }
+
+    #[test]
+    fn program_info_diff_reports_kernel_mismatch() {
+        let program = test_program();
+        let (shared_proc, other_proc) = (Word::default(), Word::from([1_u32, 0, 0, 0]));
+        let other_kernel = Kernel::new(&[shared_proc, other_proc]).unwrap();
+        let other_program =
+            Program::with_kernel(program.mast_forest().clone(), program.entrypoint(), other_kernel);
+
+        let info = ProgramInfo::from(program);
+        let other_info = ProgramInfo::from(other_program);
+        assert!(info.diff(&info).is_empty());
+
+        let diff = info.diff(&other_info);
+        assert!(!diff.is_empty());
+        assert_eq!(diff.program_hashes(), None);
+        assert!(diff.kernel_differs());
+        assert!(diff.kernel_procedures_only_in_self().is_empty());
+        assert_eq!(diff.kernel_procedures_only_in_other(), &[other_proc]);
+
+        let diff = other_info.diff(&info);
+        assert_eq!(diff.kernel_procedures_only_in_self(), &[other_proc]);
+        assert!(diff.kernel_procedures_only_in_other().is_empty());
+    }
+
+    #[test]
+    fn program_serialization_round_trip() {
+        let program = test_program();
+        let bytes = program.to_bytes();
+
+        assert_eq!(&bytes[..5], MAGIC);
+        assert_eq!(&bytes[5..8], &VERSION);
+        assert_eq!(Program::read_from_bytes(&bytes).unwrap(), program);
+    }
+
+    #[test]
+    fn program_deserialization_rejects_truncated_buffer() {
+        let bytes = test_program().to_bytes();
+
+        for len in [0, 4, 8, bytes.len() - 1] {
+            assert_eq!(
+                Program::read_from_bytes(&bytes[..len]),
+                Err(DeserializationError::UnexpectedEOF),
+                "a buffer truncated to {len} bytes was accepted"
+            );
+        }
+    }
+
+    #[test]
+    fn program_deserialization_rejects_invalid_header() {
+        let bytes = test_program().to_bytes();
+
+        let mut wrong_magic = bytes.clone();
+        wrong_magic[0] = b'X';
+        assert!(matches!(
+            Program::read_from_bytes(&wrong_magic),
+            Err(DeserializationError::InvalidValue(msg)) if msg.contains("magic")
+        ));
+
+        let mut wrong_version = bytes;
+        wrong_version[7] = 1;
+        assert!(matches!(
+            Program::read_from_bytes(&wrong_version),
+            Err(DeserializationError::InvalidValue(msg)) if msg.contains("version")
+        ));
+    }
+}
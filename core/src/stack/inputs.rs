@@ -2,7 +2,8 @@ use alloc::vec::Vec;
 use core::{ops::Deref, slice};
 
 use super::{
-    super::ZERO, ByteWriter, Felt, InputError, MIN_STACK_DEPTH, Serializable, get_num_stack_values,
+    super::ZERO, BYTES_PER_ELEMENT, ByteWriter, Felt, InputError, MAX_STACK_BYTES, MIN_STACK_DEPTH,
+    Serializable, get_num_stack_values,
 };
 use crate::utils::{ByteReader, Deserializable, DeserializationError};
 
@@ -53,6 +54,33 @@ impl StackInputs {
 
         Self::new(values)
     }
+
+    /// Creates stack inputs which encode the provided bytes.
+    ///
+    /// The top of the stack holds the number of bytes, and is followed by the bytes packed into
+    /// field elements in little-endian order, 7 bytes per element; the last element holds the
+    /// remaining bytes if their number is not a multiple of 7. Thus, at most 105 bytes can be
+    /// encoded. The bytes can be decoded from the outputs of a program via
+    /// [StackOutputs::decode_bytes](super::StackOutputs::decode_bytes).
+    ///
+    /// # Errors
+    /// Returns an error if the encoded bytes do not fit into the stack.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, InputError> {
+        let num_elements = 1 + bytes.len().div_ceil(BYTES_PER_ELEMENT);
+        if bytes.len() > MAX_STACK_BYTES {
+            return Err(InputError::InputLengthExceeded(MIN_STACK_DEPTH, num_elements));
+        }
+
+        let mut elements = [ZERO; MIN_STACK_DEPTH];
+        elements[0] = Felt::from(bytes.len() as u32);
+        for (element, chunk) in elements[1..].iter_mut().zip(bytes.chunks(BYTES_PER_ELEMENT)) {
+            let mut element_bytes = [0; 8];
+            element_bytes[..chunk.len()].copy_from_slice(chunk);
+            *element = Felt::new(u64::from_le_bytes(element_bytes));
+        }
+
+        Ok(Self { elements })
+    }
 }
 
 impl Deref for StackInputs {
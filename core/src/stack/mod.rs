@@ -25,6 +25,14 @@ mod tests;
 /// - Number of elements below which the depth of the stack never drops.
 pub const MIN_STACK_DEPTH: usize = 16;
 
+/// Number of bytes packed into a single stack element by [StackInputs::from_bytes]; 7 bytes always
+/// fit into a field element without reduction modulo the field prime.
+const BYTES_PER_ELEMENT: usize = 7;
+
+/// Maximum number of bytes which can be packed into the stack by [StackInputs::from_bytes], taking
+/// into account that the top of the stack holds the number of bytes.
+const MAX_STACK_BYTES: usize = (MIN_STACK_DEPTH - 1) * BYTES_PER_ELEMENT;
+
 // HELPER FUNCTIONS
 // ================================================================================================
 
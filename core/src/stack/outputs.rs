@@ -3,7 +3,10 @@ use core::ops::Deref;
 
 use miden_crypto::{WORD_SIZE, Word, ZERO};
 
-use super::{ByteWriter, Felt, MIN_STACK_DEPTH, OutputError, Serializable, get_num_stack_values};
+use super::{
+    BYTES_PER_ELEMENT, ByteWriter, Felt, MAX_STACK_BYTES, MIN_STACK_DEPTH, OutputError,
+    Serializable, get_num_stack_values,
+};
 use crate::utils::{ByteReader, Deserializable, DeserializationError, range};
 
 // STACK OUTPUTS
@@ -86,6 +89,24 @@ impl StackOutputs {
         Some(word_elements.into())
     }
 
+    /// Returns the `u64` value located starting at the specified Felt position on the stack or
+    /// `None` if out of bounds or if the value is not a valid `u64`.
+    ///
+    /// The value is expected to be represented by two 32-bit limbs, with the high limb located at
+    /// position `idx` and the low limb at position `idx + 1`; this is the representation used by
+    /// the `std::math::u64` module.
+    pub fn get_u64(&self, idx: usize) -> Option<u64> {
+        let hi = u32::try_from(self.get_stack_item(idx)?.as_int()).ok()?;
+        let lo = u32::try_from(self.get_stack_item(idx + 1)?.as_int()).ok()?;
+        Some(((hi as u64) << 32) | lo as u64)
+    }
+
+    /// Returns all elements of the stack, with the element at the top of the stack in the first
+    /// position.
+    pub fn as_felts(&self) -> &[Felt] {
+        &self.elements
+    }
+
     /// Returns the number of requested stack outputs or returns the full stack if fewer than the
     /// requested number of stack values exist.
     pub fn stack_truncated(&self, num_outputs: usize) -> &[Felt] {
@@ -93,6 +114,41 @@ impl StackOutputs {
         &self.elements[..len]
     }
 
+    /// Decodes the bytes encoded on the stack in the format produced by
+    /// [StackInputs::from_bytes](super::StackInputs::from_bytes).
+    ///
+    /// # Errors
+    /// Returns an error if the number of bytes at the top of the stack exceeds 105, or if an
+    /// element holding the bytes has a value which does not fit into the number of bytes it is
+    /// expected to hold.
+    pub fn decode_bytes(&self) -> Result<Vec<u8>, OutputError> {
+        let num_bytes = self.elements[0].as_int();
+        if num_bytes > MAX_STACK_BYTES as u64 {
+            return Err(OutputError::InvalidByteEncoding(format!(
+                "number of bytes can not exceed {MAX_STACK_BYTES}, but {num_bytes} was found"
+            )));
+        }
+
+        let mut bytes = Vec::with_capacity(num_bytes as usize);
+        let mut remaining = num_bytes as usize;
+        for (position, element) in self.elements.iter().enumerate().skip(1) {
+            if remaining == 0 {
+                break;
+            }
+            let num_element_bytes = remaining.min(BYTES_PER_ELEMENT);
+            let element_bytes = element.as_int().to_le_bytes();
+            if element_bytes[num_element_bytes..].iter().any(|byte| *byte != 0) {
+                return Err(OutputError::InvalidByteEncoding(format!(
+                    "element {element} at stack position {position} does not fit into {num_element_bytes} bytes"
+                )));
+            }
+            bytes.extend_from_slice(&element_bytes[..num_element_bytes]);
+            remaining -= num_element_bytes;
+        }
+
+        Ok(bytes)
+    }
+
     // PUBLIC MUTATORS
     // --------------------------------------------------------------------------------------------
 
@@ -1,7 +1,8 @@
 use alloc::vec::Vec;
 
 use crate::{
-    StackInputs, StackOutputs,
+    Felt, StackInputs, StackOutputs,
+    errors::{InputError, OutputError},
     utils::{Deserializable, Serializable},
 };
 
@@ -128,3 +129,46 @@ fn test_outputs_empty() {
 
     assert_eq!(*inputs, *result);
 }
+
+// BYTE ENCODING TESTS
+// ================================================================================================
+
+#[test]
+fn test_bytes_round_trip() {
+    for len in [0, 1, 6, 7, 8, 13, 14, 50, 104, 105] {
+        let bytes = (0..len).map(|i| (255 - i) as u8).collect::<Vec<_>>();
+        let inputs = StackInputs::from_bytes(&bytes).unwrap();
+        assert_eq!(inputs[0], Felt::from(len as u32));
+
+        let outputs = StackOutputs::from(*inputs);
+        assert_eq!(outputs.decode_bytes().unwrap(), bytes, "round trip failed for {len} bytes");
+    }
+}
+
+#[test]
+fn test_bytes_packing() {
+    let inputs = StackInputs::from_bytes(&[1, 2, 3, 4, 5, 6, 7, 8, 9]).unwrap();
+    assert_eq!(inputs[0], Felt::new(9));
+    assert_eq!(inputs[1], Felt::new(0x07_06_05_04_03_02_01));
+    assert_eq!(inputs[2], Felt::new(0x09_08));
+    assert!(inputs[3..].iter().all(|element| *element == Felt::new(0)));
+}
+
+#[test]
+fn test_bytes_too_long() {
+    let err = StackInputs::from_bytes(&[0; 106]).unwrap_err();
+    assert!(matches!(err, InputError::InputLengthExceeded(16, 17)));
+
+    let outputs = StackOutputs::try_from_ints([106]).unwrap();
+    assert!(matches!(outputs.decode_bytes(), Err(OutputError::InvalidByteEncoding(_))));
+}
+
+#[test]
+fn test_bytes_invalid_element() {
+    // the second element is expected to hold a single byte
+    let outputs = StackOutputs::try_from_ints([8, 1, 256]).unwrap();
+    assert!(matches!(outputs.decode_bytes(), Err(OutputError::InvalidByteEncoding(_))));
+
+    let outputs = StackOutputs::try_from_ints([7, 1 << 56]).unwrap();
+    assert!(matches!(outputs.decode_bytes(), Err(OutputError::InvalidByteEncoding(_))));
+}
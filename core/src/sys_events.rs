@@ -7,6 +7,8 @@ use core::fmt;
 // between 0 and 2^32.
 pub use constants::*;
 
+use crate::SignatureKind;
+
 #[rustfmt::skip]
 mod constants {
     pub const EVENT_MERKLE_NODE_MERGE: u32            = 276124218;
@@ -16,6 +18,8 @@ mod constants {
     pub const EVENT_HAS_MAP_KEY: u32                  = 652777600;
     pub const EVENT_U64_DIV: u32                      = 678156251;
     pub const EVENT_EXT2_INV: u32                     = 1251967401;
+    pub const EVENT_BATCH_INV: u32                    = 1339512083;
+    pub const EVENT_SHA256_TO_STACK: u32              = 1484120781;
     pub const EVENT_SMT_PEEK: u32                     = 1889584556;
     pub const EVENT_U32_CLZ: u32                      = 1951932030;
     pub const EVENT_U32_CTZ: u32                      = 2008979519;
@@ -23,10 +27,14 @@ mod constants {
     pub const EVENT_U32_CTO: u32                      = 2083700134;
     pub const EVENT_ILOG2: u32                        = 2297972669;
     pub const EVENT_MEM_TO_MAP: u32                   = 2389394361;
+    pub const EVENT_KECCAK256_TO_STACK: u32           = 2390671442;
     pub const EVENT_HDWORD_TO_MAP: u32                = 2391452729;
+    pub const EVENT_HASHED_MEM_TO_MAP: u32            = 2617290415;
     pub const EVENT_HDWORD_TO_MAP_WITH_DOMAIN: u32    = 2822590340;
+    pub const EVENT_DRAW_RANDOM: u32                  = 3038265101;
     pub const EVENT_HPERM_TO_MAP: u32                 = 3297060969;
     pub const EVENT_FALCON_DIV: u32                   = 3419226155;
+    pub const EVENT_RPO_FALCON512_SIG_TO_STACK: u32   = 3556172487;
 }
 
 /// Defines a set of actions which can be initiated from the VM to inject new data into the advice
@@ -232,6 +240,98 @@ pub enum SystemEvent {
     ///   Advice stack: [ilog2(n), ...]
     ILog2,
 
+    /// Reads a byte-encoded message from memory, computes its SHA-256 digest and pushes the
+    /// digest onto the advice stack.
+    ///
+    /// Inputs:
+    ///   Operand stack: [ptr, len, ...]
+    ///   Advice stack: [...]
+    ///
+    /// Outputs:
+    ///   Operand stack: [ptr, len, ...]
+    ///   Advice stack: [d0, d1, d2, d3, d4, d5, d6, d7, ...]
+    ///
+    /// Where `len` is the length of the message in bytes, and the message is read from memory
+    /// starting at address `ptr` with each memory element holding 4 bytes of the message packed
+    /// as a big-endian u32 value (the last element is padded with zero bytes if needed). The
+    /// digest is represented by 8 big-endian u32 values `d0, ..., d7`.
+    ///
+    /// The digest is computed outside of the VM, and so the code consuming it is responsible for
+    /// verifying it.
+    Sha256ToStack,
+
+    /// Reads a byte-encoded message from memory, computes its keccak-256 digest and pushes the
+    /// digest onto the advice stack.
+    ///
+    /// Inputs:
+    ///   Operand stack: [ptr, len, ...]
+    ///   Advice stack: [...]
+    ///
+    /// Outputs:
+    ///   Operand stack: [ptr, len, ...]
+    ///   Advice stack: [d0, d1, d2, d3, d4, d5, d6, d7, ...]
+    ///
+    /// Where `len` is the length of the message in bytes, and the message is read from memory
+    /// starting at address `ptr` with each memory element holding 4 bytes of the message packed
+    /// as a little-endian u32 value (the last element is padded with zero bytes if needed). The
+    /// digest is represented by 8 little-endian u32 values `d0, ..., d7`.
+    ///
+    /// The digest is computed outside of the VM, and so the code consuming it is responsible for
+    /// verifying it.
+    Keccak256ToStack,
+
+    /// Reads `n` field elements from memory, computes their multiplicative inverses and pushes
+    /// the inverses onto the advice stack.
+    ///
+    /// Inputs:
+    ///   Operand stack: [ptr, n, ...]
+    ///   Advice stack: [...]
+    ///
+    /// Outputs:
+    ///   Operand stack: [ptr, n, ...]
+    ///   Advice stack: [b_0, b_1, ..., b_{n-1}, ...]
+    ///
+    /// Where `b_i` is the inverse of the element `a_i` located in memory at address `ptr + i`.
+    /// Zero elements have no inverse, and so `0` is pushed onto the advice stack in their place;
+    /// the code consuming the inverses is responsible for handling this case.
+    BatchInv,
+
+    /// Draws `n` pseudo-random field elements from an RPO-based random coin seeded with the word
+    /// `SEED` and pushes them onto the advice stack.
+    ///
+    /// Inputs:
+    ///   Operand stack: [SEED, n, ...]
+    ///   Advice stack: [...]
+    ///
+    /// Outputs:
+    ///   Operand stack: [SEED, n, ...]
+    ///   Advice stack: [r_0, r_1, ..., r_{n-1}, ...]
+    ///
+    /// Where `r_i` is the i-th element drawn from the random coin. The drawn elements depend only
+    /// on `SEED`, and so the same seed always results in the same sequence of elements.
+    DrawRandom,
+
+    /// Signs the message on the operand stack with the secret key associated with the public key
+    /// on the operand stack, and pushes the values required to verify a signature of the
+    /// specified kind in the VM onto the advice stack.
+    ///
+    /// Inputs:
+    ///   Operand stack: [PK, MSG, ...]
+    ///   Advice stack: [...]
+    ///   Advice map: {PK: SK}
+    ///
+    /// Outputs:
+    ///   Operand stack: [PK, MSG, ...]
+    ///   Advice stack: [SIGNATURE, ...]
+    ///   Advice map: {PK: SK}
+    ///
+    /// Where:
+    /// - PK is the digest of the public key.
+    /// - MSG is the digest of the message being signed.
+    /// - SK is the serialized secret key associated with the public key PK.
+    /// - SIGNATURE is the signature advice produced by [`SignatureKind::sign`].
+    Signature { kind: SignatureKind },
+
     // ADVICE MAP SYSTEM EVENTS
     // --------------------------------------------------------------------------------------------
     /// Reads words from memory at the specified range and inserts them into the advice map under
@@ -248,6 +348,21 @@ pub enum SystemEvent {
     /// Where `values` are the elements located in memory[start_addr..end_addr].
     MemToMap,
 
+    /// Reads `n` words from memory starting at address `ptr` and inserts them into the advice map
+    /// under the key defined by the hash of these words.
+    ///
+    /// Inputs:
+    ///   Operand stack: [ptr, n, ...]
+    ///   Advice map: {...}
+    ///
+    /// Outputs:
+    ///   Operand stack: [ptr, n, ...]
+    ///   Advice map: {KEY: values}
+    ///
+    /// Where `values` are the elements located in memory[ptr..ptr + 4 * n], and KEY is computed as
+    /// hash(values).
+    HashedMemToMap,
+
     /// Reads two word from the operand stack and inserts them into the advice map under the key
     /// defined by the hash of these words.
     ///
@@ -309,7 +424,15 @@ impl SystemEvent {
             SystemEvent::U32Clo => EVENT_U32_CLO,
             SystemEvent::U32Cto => EVENT_U32_CTO,
             SystemEvent::ILog2 => EVENT_ILOG2,
+            SystemEvent::Sha256ToStack => EVENT_SHA256_TO_STACK,
+            SystemEvent::Keccak256ToStack => EVENT_KECCAK256_TO_STACK,
+            SystemEvent::BatchInv => EVENT_BATCH_INV,
+            SystemEvent::DrawRandom => EVENT_DRAW_RANDOM,
+            SystemEvent::Signature { kind } => match kind {
+                SignatureKind::RpoFalcon512 => EVENT_RPO_FALCON512_SIG_TO_STACK,
+            },
             SystemEvent::MemToMap => EVENT_MEM_TO_MAP,
+            SystemEvent::HashedMemToMap => EVENT_HASHED_MEM_TO_MAP,
             SystemEvent::HdwordToMap => EVENT_HDWORD_TO_MAP,
             SystemEvent::HdwordToMapWithDomain => EVENT_HDWORD_TO_MAP_WITH_DOMAIN,
             SystemEvent::HpermToMap => EVENT_HPERM_TO_MAP,
@@ -334,7 +457,15 @@ impl SystemEvent {
             EVENT_U32_CLO => Some(SystemEvent::U32Clo),
             EVENT_U32_CTO => Some(SystemEvent::U32Cto),
             EVENT_ILOG2 => Some(SystemEvent::ILog2),
+            EVENT_SHA256_TO_STACK => Some(SystemEvent::Sha256ToStack),
+            EVENT_KECCAK256_TO_STACK => Some(SystemEvent::Keccak256ToStack),
+            EVENT_BATCH_INV => Some(SystemEvent::BatchInv),
+            EVENT_DRAW_RANDOM => Some(SystemEvent::DrawRandom),
+            EVENT_RPO_FALCON512_SIG_TO_STACK => {
+                Some(SystemEvent::Signature { kind: SignatureKind::RpoFalcon512 })
+            },
             EVENT_MEM_TO_MAP => Some(SystemEvent::MemToMap),
+            EVENT_HASHED_MEM_TO_MAP => Some(SystemEvent::HashedMemToMap),
             EVENT_HDWORD_TO_MAP => Some(SystemEvent::HdwordToMap),
             EVENT_HDWORD_TO_MAP_WITH_DOMAIN => Some(SystemEvent::HdwordToMapWithDomain),
             EVENT_HPERM_TO_MAP => Some(SystemEvent::HpermToMap),
@@ -366,7 +497,15 @@ impl fmt::Display for SystemEvent {
             Self::U32Clo => write!(f, "u32clo"),
             Self::U32Cto => write!(f, "u32cto"),
             Self::ILog2 => write!(f, "ilog2"),
+            Self::Sha256ToStack => write!(f, "sha256_to_stack"),
+            Self::Keccak256ToStack => write!(f, "keccak256_to_stack"),
+            Self::BatchInv => write!(f, "batch_inv"),
+            Self::DrawRandom => write!(f, "draw_random"),
+            Self::Signature { kind } => match kind {
+                SignatureKind::RpoFalcon512 => write!(f, "rpo_falcon512_sig_to_stack"),
+            },
             Self::MemToMap => write!(f, "mem_to_map"),
+            Self::HashedMemToMap => write!(f, "hashed_mem_to_map"),
             Self::HdwordToMap => write!(f, "hdword_to_map"),
             Self::HdwordToMapWithDomain => write!(f, "hdword_to_map_with_domain"),
             Self::HpermToMap => write!(f, "hperm_to_map"),
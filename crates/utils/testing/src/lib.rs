@@ -33,7 +33,7 @@ pub use miden_processor::{
 use miden_processor::{Program, fast::FastProcessor};
 use miden_prover::utils::range;
 pub use miden_prover::{MerkleTreeVC, ProvingOptions, prove};
-pub use miden_verifier::{AcceptableOptions, VerifierError, verify};
+pub use miden_verifier::{AcceptableOptions, VerifierError, verify, verify_with_options};
 pub use pretty_assertions::{assert_eq, assert_ne, assert_str_eq};
 #[cfg(not(target_family = "wasm"))]
 use proptest::prelude::{Arbitrary, Strategy};
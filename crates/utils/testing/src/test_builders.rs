@@ -237,3 +237,52 @@ macro_rules! build_test_by_mode {
         }
     }};
 }
+
+/// Returns a vector of advice map entries, i.e., `Vec<(Word, Vec<Felt>)>`, built from a list of
+/// `key => [values]` pairs.
+///
+/// * `key`: either a list of four `u64` values, e.g. `[1, 2, 3, 4]`, or an expression convertible
+///   into a [Word](crate::Word) via `Word::try_from()`.
+/// * `values`: a list of `u64` values, each of which is converted into a [Felt](crate::Felt).
+///
+/// The result can be passed as the advice map parameter of the `build_test` and `build_debug_test`
+/// macros, e.g.:
+///
+/// ```ignore
+/// let adv_map = advice_map! {
+///     [1, 2, 3, 4] => [8, 7, 6, 5],
+///     key => [9, 10],
+/// };
+/// ```
+#[macro_export]
+macro_rules! advice_map {
+    ($($entries:tt)*) => {{
+        let mut advice_map: ::alloc::vec::Vec<($crate::Word, ::alloc::vec::Vec<$crate::Felt>)> =
+            ::alloc::vec::Vec::new();
+        $crate::advice_map_entries!(advice_map; $($entries)*);
+        advice_map
+    }};
+}
+
+/// Appends the entries of an `advice_map!` invocation to the specified vector.
+///
+/// This macro is an internal helper of `advice_map!`, and is not intended to be called directly.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! advice_map_entries {
+    ($map:ident;) => {};
+    ($map:ident; [$($key:expr),+ $(,)?] => [$($value:expr),* $(,)?] $(, $($rest:tt)*)?) => {
+        $map.push((
+            $crate::Word::new([$($crate::Felt::new($key)),+]),
+            ::alloc::vec![$($crate::Felt::new($value)),*],
+        ));
+        $crate::advice_map_entries!($map; $($($rest)*)?);
+    };
+    ($map:ident; $key:expr => [$($value:expr),* $(,)?] $(, $($rest:tt)*)?) => {
+        $map.push((
+            $crate::Word::try_from($key).unwrap(),
+            ::alloc::vec![$($crate::Felt::new($value)),*],
+        ));
+        $crate::advice_map_entries!($map; $($($rest)*)?);
+    };
+}
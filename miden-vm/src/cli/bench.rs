@@ -0,0 +1,131 @@
+use std::{path::PathBuf, time::Instant};
+
+use clap::Parser;
+use miden_assembly::diagnostics::{IntoDiagnostic, Report, WrapErr};
+use miden_processor::{DefaultHost, ExecutionOptions};
+use miden_stdlib::StdLibrary;
+
+use super::utils::{get_program, get_program_inputs};
+
+#[derive(Debug, Clone, Parser)]
+#[command(about = "Benchmark the execution of a Miden program")]
+pub struct BenchCmd {
+    /// Path to a .masm assembly file or a .masp package file
+    #[arg(value_parser)]
+    program_file: PathBuf,
+
+    /// Number of cycles the program is expected to consume
+    #[arg(short = 'e', long = "exp-cycles", default_value = "64")]
+    expected_cycles: u32,
+
+    /// Path to input file
+    #[arg(short = 'i', long = "input", value_parser)]
+    input_file: Option<PathBuf>,
+
+    /// Paths to .masl library files (only used for assembly files)
+    #[arg(short = 'l', long = "libraries", value_parser)]
+    library_paths: Vec<PathBuf>,
+
+    /// Maximum number of cycles a program is allowed to consume
+    #[arg(short = 'm', long = "max-cycles", default_value = "4294967295")]
+    max_cycles: u32,
+
+    /// Number of times the program is executed
+    #[arg(
+        short = 'n',
+        long = "iterations",
+        default_value = "10",
+        value_parser = clap::value_parser!(u32).range(1..),
+    )]
+    iterations: u32,
+
+    /// Disable debug instructions (release mode)
+    #[arg(short = 'r', long = "release")]
+    release: bool,
+}
+
+impl BenchCmd {
+    pub fn execute(&self) -> Result<(), Report> {
+        println!("===============================================================================");
+        println!("Benchmark program: {}", self.program_file.display());
+        println!("-------------------------------------------------------------------------------");
+
+        let (program, source_manager, libraries) =
+            get_program(&self.program_file, &self.library_paths, !self.release)?;
+
+        // fetch the stack and program inputs from the arguments
+        let (stack_inputs, advice_inputs) =
+            get_program_inputs(&self.input_file, &self.program_file)?;
+
+        let execution_options = ExecutionOptions::new(
+            Some(self.max_cycles),
+            self.expected_cycles,
+            false,
+            !self.release,
+        )
+        .into_diagnostic()?;
+
+        let mut total_cycles = 0;
+        let mut trace_len_summary = None;
+        let now = Instant::now();
+
+        // execute the program the requested number of times, with a fresh host for each run
+        for _ in 0..self.iterations {
+            let mut host = DefaultHost::default();
+            host.load_mast_forest(StdLibrary::default().mast_forest().clone()).unwrap();
+            for lib in &libraries.libraries {
+                host.load_mast_forest(lib.mast_forest().clone()).unwrap();
+            }
+
+            let trace = miden_processor::execute(
+                &program,
+                stack_inputs.clone(),
+                advice_inputs.clone(),
+                &mut host,
+                execution_options,
+                source_manager.clone(),
+            )
+            .wrap_err("Failed to generate execution trace")?;
+
+            // the length of the main trace is the number of executed cycles
+            total_cycles += trace.trace_len_summary().main_trace_len();
+            trace_len_summary = Some(*trace.trace_len_summary());
+        }
+
+        let elapsed = now.elapsed();
+        let summary = trace_len_summary.expect("the program is executed at least once");
+
+        println!(
+            "Executed the program with hash {} {} times in {} ms ({} ms per run)",
+            hex::encode(<[u8; 32]>::from(program.hash())),
+            self.iterations,
+            elapsed.as_millis(),
+            elapsed.as_millis() / self.iterations as u128,
+        );
+
+        // print the average number of cycles and the lengths of the trace segments
+        println!(
+            "Average VM cycles: {}
+Trace length: {} (padded to {})
+├── Stack rows: {}
+├── Range checker rows: {}
+└── Chiplets rows: {}
+    ├── Hash chiplet rows: {}
+    ├── Bitwise chiplet rows: {}
+    ├── Memory chiplet rows: {}
+    └── Kernel ROM rows: {}",
+            total_cycles / self.iterations as usize,
+            summary.trace_len(),
+            summary.padded_trace_len(),
+            summary.main_trace_len(),
+            summary.range_trace_len(),
+            summary.chiplets_trace_len().trace_len(),
+            summary.chiplets_trace_len().hash_chiplet_len(),
+            summary.chiplets_trace_len().bitwise_chiplet_len(),
+            summary.chiplets_trace_len().memory_chiplet_len(),
+            summary.chiplets_trace_len().kernel_rom_len(),
+        );
+
+        Ok(())
+    }
+}
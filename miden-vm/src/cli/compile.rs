@@ -1,7 +1,9 @@
 use std::path::PathBuf;
 
-use clap::Parser;
+use base64::{Engine, engine::general_purpose::STANDARD as BASE64};
+use clap::{Parser, ValueEnum};
 use miden_assembly::diagnostics::{IntoDiagnostic, Report, WrapErr};
+use miden_core::{Program, Word};
 
 use super::data::{Debug, Libraries, ProgramFile};
 
@@ -17,6 +19,9 @@ pub struct CompileCmd {
     /// Path to output file
     #[arg(short = 'o', long = "output", value_parser)]
     output_file: Option<PathBuf>,
+    /// Format in which the program hash is printed
+    #[arg(long = "digest-format", value_enum, default_value_t = DigestFormat::Hex)]
+    digest_format: DigestFormat,
 }
 
 impl CompileCmd {
@@ -35,8 +40,7 @@ impl CompileCmd {
         let compiled_program = program.compile(Debug::Off, &libraries.libraries)?;
 
         // report program hash to user
-        let program_hash: [u8; 32] = compiled_program.hash().into();
-        println!("program hash is {}", hex::encode(program_hash));
+        println!("program hash is {}", self.digest_format.render(compiled_program.hash()));
 
         // write the compiled program into the specified path if one is provided; if the path is
         // not provided, writes the file into the same directory as the source file, but with
@@ -48,8 +52,79 @@ impl CompileCmd {
         });
 
         compiled_program
-            .write_to_file(out_path)
+            .write_to_file(&out_path)
             .into_diagnostic()
-            .wrap_err("Failed to write the compiled file")
+            .wrap_err("Failed to write the compiled file")?;
+
+        // make sure the written program can be loaded back without reassembly
+        let written_program = Program::deserialize_from_file(&out_path)
+            .into_diagnostic()
+            .wrap_err("Failed to read back the compiled file")?;
+        if written_program.hash() != compiled_program.hash() {
+            return Err(Report::msg(format!(
+                "Program read back from {} has a different hash than the compiled program",
+                out_path.display()
+            )));
+        }
+
+        println!("compiled program written to {}", out_path.display());
+
+        Ok(())
+    }
+}
+
+// DIGEST FORMAT
+// ================================================================================================
+
+/// Describes how the program hash is rendered.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum DigestFormat {
+    /// Hex-encoded bytes of the digest.
+    Hex,
+    /// Base64-encoded bytes of the digest.
+    Base64,
+    /// Array of the field elements of the digest.
+    FeltArray,
+}
+
+impl DigestFormat {
+    /// Returns the provided digest rendered in this format.
+    pub fn render(&self, digest: Word) -> String {
+        let bytes: [u8; 32] = digest.into();
+        match self {
+            Self::Hex => hex::encode(bytes),
+            Self::Base64 => BASE64.encode(bytes),
+            Self::FeltArray => {
+                let elements: Vec<String> =
+                    digest.iter().map(|element| element.as_int().to_string()).collect();
+                format!("[{}]", elements.join(", "))
+            },
+        }
+    }
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod test {
+    use miden_core::Felt;
+
+    use super::*;
+
+    #[test]
+    fn test_digest_format_render() {
+        let digest =
+            Word::new([Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(u64::MAX >> 1)]);
+
+        assert_eq!(
+            DigestFormat::Hex.render(digest),
+            "010000000000000002000000000000000300000000000000ffffffffffffff7f"
+        );
+        assert_eq!(
+            DigestFormat::Base64.render(digest),
+            "AQAAAAAAAAACAAAAAAAAAAMAAAAAAAAA/////////38="
+        );
+        assert_eq!(DigestFormat::FeltArray.render(digest), "[1, 2, 3, 9223372036854775807]");
     }
 }
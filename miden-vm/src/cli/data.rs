@@ -97,7 +97,11 @@ impl OutputFile {
 
     /// Converts stack output vector to [StackOutputs].
     pub fn stack_outputs(&self) -> Result<StackOutputs, String> {
-        let stack = self.stack.iter().map(|v| v.parse::<u64>().unwrap()).collect::<Vec<u64>>();
+        let stack = self
+            .stack
+            .iter()
+            .map(|v| v.parse::<u64>().map_err(|err| format!("Invalid stack output `{v}` - {err}")))
+            .collect::<Result<Vec<u64>, String>>()?;
 
         StackOutputs::try_from_ints(stack)
             .map_err(|e| format!("Construct stack outputs failed {e}"))
@@ -193,8 +197,13 @@ impl ProofFile {
             .map_err(|err| format!("Failed to open proof file `{}` - {}", path.display(), err))?;
 
         // deserialize bytes into a stark proof
-        ExecutionProof::from_bytes(&file)
-            .map_err(|err| format!("Failed to decode proof data - {err}"))
+        ExecutionProof::from_bytes(&file).map_err(|err| {
+            format!(
+                "Failed to decode proof data from `{}`, the file may be corrupted or truncated - {}",
+                path.display(),
+                err
+            )
+        })
     }
 
     /// Write stark proof to file
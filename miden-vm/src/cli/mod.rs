@@ -1,3 +1,4 @@
+mod bench;
 mod bundle;
 mod compile;
 pub mod data;
@@ -8,6 +9,7 @@ mod run;
 pub mod utils;
 mod verify;
 
+pub use bench::BenchCmd;
 pub use bundle::BundleCmd;
 pub use compile::CompileCmd;
 pub use debug::DebugCmd;
@@ -57,8 +57,13 @@ pub struct ProveCmd {
     #[arg(long = "rpx", conflicts_with("recursive"))]
     rpx: bool,
 
-    /// Security level for execution proofs generated by the VM
-    #[arg(short = 's', long = "security", default_value = "96bits")]
+    /// Security level for execution proofs generated by the VM, in bits
+    #[arg(
+        short = 's',
+        long = "security",
+        default_value = "96",
+        value_parser = ["96", "128", "96bits", "128bits"],
+    )]
     security: String,
 
     /// Enable tracing to monitor execution of the VM
@@ -79,24 +84,25 @@ impl ProveCmd {
             !self.release,
         )?;
         Ok(match self.security.as_str() {
-            "96bits" => {
+            "96" | "96bits" => {
                 if self.rpx {
                     ProvingOptions::with_96_bit_security_rpx()
                 } else {
                     ProvingOptions::with_96_bit_security(self.recursive)
                 }
             },
-            "128bits" => {
+            "128" | "128bits" => {
                 if self.rpx {
                     ProvingOptions::with_128_bit_security_rpx()
                 } else {
                     ProvingOptions::with_128_bit_security(self.recursive)
                 }
             },
-            other => panic!("{other} is not a valid security setting"),
+            other => unreachable!("{other} is not a valid security setting"),
         }
         .with_execution_options(exec_options))
     }
+
     pub fn execute(&self) -> Result<(), Report> {
         println!("===============================================================================");
         println!("Prove program: {}", self.program_file.display());
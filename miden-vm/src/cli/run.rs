@@ -1,18 +1,14 @@
-use std::{path::PathBuf, sync::Arc, time::Instant};
+use std::{path::PathBuf, time::Instant};
 
 use clap::Parser;
-use miden_assembly::{
-    DefaultSourceManager,
-    diagnostics::{IntoDiagnostic, Report, WrapErr},
-};
+use miden_assembly::diagnostics::{IntoDiagnostic, Report, WrapErr};
 use miden_processor::{DefaultHost, ExecutionOptions, ExecutionTrace};
 use miden_stdlib::StdLibrary;
-use miden_vm::internal::InputFile;
 use tracing::instrument;
 
 use super::{
-    data::{Libraries, OutputFile},
-    utils::{get_masm_program, get_masp_program},
+    data::OutputFile,
+    utils::{get_program, get_program_inputs},
 };
 
 #[derive(Debug, Clone, Parser)]
@@ -61,22 +57,8 @@ impl RunCmd {
         println!("Run program: {}", self.program_file.display());
         println!("-------------------------------------------------------------------------------");
 
-        // determine file type based on extension
-        let ext = self
-            .program_file
-            .extension()
-            .and_then(|s| s.to_str())
-            .unwrap_or("")
-            .to_lowercase();
-
         let now = Instant::now();
-
-        // use a single match expression based on file extension
-        let (trace, program_hash) = match ext.as_str() {
-            "masp" => run_masp_program(self)?,
-            "masm" => run_masm_program(self)?,
-            _ => return Err(Report::msg("The provided file must have a .masm or .masp extension")),
-        };
+        let (trace, program_hash) = run_program(self)?;
 
         println!(
             "Executed the program with hash {} in {} ms",
@@ -127,60 +109,14 @@ impl RunCmd {
 // ================================================================================================
 
 #[instrument(name = "run_program", skip_all)]
-fn run_masp_program(params: &RunCmd) -> Result<(ExecutionTrace, [u8; 32]), Report> {
-    let program = get_masp_program(&params.program_file)?;
-
-    // use simplified input data reading
-    let input_data = InputFile::read(&params.input_file, &params.program_file)?;
-
-    let stack_inputs = input_data.parse_stack_inputs().map_err(Report::msg)?;
-    let advice_inputs = input_data.parse_advice_inputs().map_err(Report::msg)?;
-    let mut host = DefaultHost::default();
-    host.load_mast_forest(StdLibrary::default().mast_forest().clone()).unwrap();
-
-    let execution_options = ExecutionOptions::new(
-        Some(params.max_cycles),
-        params.expected_cycles,
-        params.trace,
-        !params.release,
-    )
-    .into_diagnostic()?;
-
-    let program_hash: [u8; 32] = program.hash().into();
-
-    // Packages don't ship with sources, so we use a default source manager.
-    let source_manager = Arc::new(DefaultSourceManager::default());
-
-    // execute program and generate outputs
-    let trace = miden_processor::execute(
-        &program,
-        stack_inputs,
-        advice_inputs,
-        &mut host,
-        execution_options,
-        source_manager,
-    )
-    .wrap_err("Failed to generate execution trace")?;
-
-    Ok((trace, program_hash))
-}
-
-#[instrument(name = "run_program", skip_all)]
-fn run_masm_program(params: &RunCmd) -> Result<(ExecutionTrace, [u8; 32]), Report> {
-    for lib in &params.library_paths {
-        if !lib.is_file() {
-            let name = lib.display();
-            return Err(Report::msg(format!("{name} must be a file.")));
-        }
-    }
-
-    // load libraries from files
-    let libraries = Libraries::new(&params.library_paths)?;
-
+fn run_program(params: &RunCmd) -> Result<(ExecutionTrace, [u8; 32]), Report> {
     // load program from file and compile
-    let (program, source_manager) =
-        get_masm_program(&params.program_file, &libraries, !params.release)?;
-    let input_data = InputFile::read(&params.input_file, &params.program_file)?;
+    let (program, source_manager, libraries) =
+        get_program(&params.program_file, &params.library_paths, !params.release)?;
+
+    // fetch the stack and program inputs from the arguments
+    let (stack_inputs, advice_inputs) =
+        get_program_inputs(&params.input_file, &params.program_file)?;
 
     let execution_options = ExecutionOptions::new(
         Some(params.max_cycles),
@@ -190,9 +126,6 @@ fn run_masm_program(params: &RunCmd) -> Result<(ExecutionTrace, [u8; 32]), Repor
     )
     .into_diagnostic()?;
 
-    // fetch the stack and program inputs from the arguments
-    let stack_inputs = input_data.parse_stack_inputs().map_err(Report::msg)?;
-    let advice_inputs = input_data.parse_advice_inputs().map_err(Report::msg)?;
     let mut host = DefaultHost::default();
     host.load_mast_forest(StdLibrary::default().mast_forest().clone()).unwrap();
     for lib in libraries.libraries {
@@ -1,11 +1,17 @@
-use std::{fs, path::Path, sync::Arc};
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    sync::Arc,
+};
 
 use miden_assembly::{
-    SourceManager,
+    DefaultSourceManager, SourceManager,
     diagnostics::{IntoDiagnostic, Report, WrapErr},
 };
 use miden_mast_package::{MastArtifact, Package};
+use miden_processor::{AdviceInputs, StackInputs};
 use miden_prover::utils::Deserializable;
+use miden_vm::internal::InputFile;
 
 use crate::cli::data::{Debug, Libraries, ProgramFile};
 
@@ -37,3 +43,52 @@ pub fn get_masm_program(
 
     Ok((program, program_file.source_manager().clone()))
 }
+
+/// Returns a `Program` type from a `.masm` assembly file or a `.masp` package file, depending on
+/// the extension of the file, together with its source manager and the libraries it is linked
+/// against.
+///
+/// Libraries are only used for assembly files, so no libraries are returned for packages.
+pub fn get_program(
+    path: &Path,
+    library_paths: &[PathBuf],
+    debug_on: bool,
+) -> Result<(miden_core::Program, Arc<dyn SourceManager>, Libraries), Report> {
+    let ext = path.extension().and_then(|s| s.to_str()).unwrap_or("").to_lowercase();
+
+    match ext.as_str() {
+        "masp" => {
+            // Packages don't ship with sources, so we use a default source manager.
+            let program = get_masp_program(path)?;
+            let libraries = Libraries { libraries: Vec::new() };
+            Ok((program, Arc::new(DefaultSourceManager::default()), libraries))
+        },
+        "masm" => {
+            for lib in library_paths {
+                if !lib.is_file() {
+                    let name = lib.display();
+                    return Err(Report::msg(format!("{name} must be a file.")));
+                }
+            }
+
+            // load libraries from files
+            let libraries = Libraries::new(library_paths)?;
+            let (program, source_manager) = get_masm_program(path, &libraries, debug_on)?;
+            Ok((program, source_manager, libraries))
+        },
+        _ => Err(Report::msg("The provided file must have a .masm or .masp extension")),
+    }
+}
+
+/// Returns the stack and advice inputs of a program from the provided input file, or from the
+/// `.inputs` file next to the program file if no input file is provided.
+pub fn get_program_inputs(
+    input_file: &Option<PathBuf>,
+    program_file: &Path,
+) -> Result<(StackInputs, AdviceInputs), Report> {
+    let input_data = InputFile::read(input_file, program_file)?;
+    let stack_inputs = input_data.parse_stack_inputs().map_err(Report::msg)?;
+    let advice_inputs = input_data.parse_advice_inputs().map_err(Report::msg)?;
+
+    Ok((stack_inputs, advice_inputs))
+}
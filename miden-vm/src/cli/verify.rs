@@ -28,7 +28,7 @@ pub struct VerifyCmd {
 
 impl VerifyCmd {
     pub fn execute(&self) -> Result<(), Report> {
-        let (input_file, output_file) = self.infer_defaults().unwrap();
+        let output_file = self.infer_defaults()?;
 
         println!("===============================================================================");
         println!("Verifying proof: {}", self.proof_file.display());
@@ -37,8 +37,9 @@ impl VerifyCmd {
         // read program hash from input
         let program_hash = ProgramHash::read(&self.program_hash).map_err(Report::msg)?;
 
-        // load input data from file
-        let input_data = InputFile::read(&Some(input_file), self.proof_file.as_ref())?;
+        // load input data from file; if no input file was specified and there is no `.inputs` file
+        // next to the proof file, the stack inputs are empty
+        let input_data = InputFile::read(&self.input_file, self.proof_file.as_ref())?;
 
         // fetch the stack inputs from the arguments
         let stack_inputs = input_data.parse_stack_inputs().map_err(Report::msg)?;
@@ -68,24 +69,20 @@ impl VerifyCmd {
         Ok(())
     }
 
-    fn infer_defaults(&self) -> Result<(PathBuf, PathBuf), Report> {
-        let proof_file = if Path::new(&self.proof_file.as_os_str()).try_exists().is_err() {
-            return Err(Report::msg("Proof file does not exist"));
-        } else {
-            self.proof_file.clone()
-        };
-
-        let input_file = self.input_file.clone().unwrap_or_else(|| {
-            let mut input_path = proof_file.clone();
-            input_path.set_extension("inputs");
-            input_path
-        });
+    fn infer_defaults(&self) -> Result<PathBuf, Report> {
+        if !Path::new(&self.proof_file.as_os_str()).try_exists().unwrap_or(false) {
+            return Err(Report::msg(format!(
+                "Proof file `{}` does not exist",
+                self.proof_file.display()
+            )));
+        }
+
         let output_file = self.output_file.clone().unwrap_or_else(|| {
-            let mut output_path = proof_file.clone();
+            let mut output_path = self.proof_file.clone();
             output_path.set_extension("outputs");
             output_path
         });
 
-        Ok((input_file.to_path_buf(), output_file.to_path_buf()))
+        Ok(output_file)
     }
 }
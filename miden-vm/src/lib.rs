@@ -12,13 +12,13 @@ pub use miden_assembly::{
 pub use miden_processor::{
     AdviceInputs, AdviceProvider, AsmOpInfo, AsyncHost, BaseHost, DefaultHost, ExecutionError,
     ExecutionTrace, Kernel, Operation, Program, ProgramInfo, StackInputs, SyncHost, VmState,
-    VmStateIterator, ZERO, crypto, execute, execute_iter, utils,
+    VmStateIterator, ZERO, crypto, estimate_trace_len, execute, execute_iter, utils,
 };
 pub use miden_prover::{
     ExecutionProof, FieldExtension, HashFunction, InputError, Proof, ProvingOptions, StackOutputs,
     Word, math, prove,
 };
-pub use miden_verifier::{VerificationError, verify};
+pub use miden_verifier::{VerificationError, verify, verify_with_options};
 
 // (private) exports
 // ================================================================================================
@@ -29,6 +29,7 @@ pub struct Cli {
 #[derive(Debug, Parser)]
 pub enum Actions {
     Analyze(tools::Analyze),
+    Bench(cli::BenchCmd),
     Compile(cli::CompileCmd),
     Bundle(cli::BundleCmd),
     Debug(cli::DebugCmd),
@@ -44,6 +45,7 @@ impl Cli {
     pub fn execute(&self) -> Result<(), Report> {
         match &self.action {
             Actions::Analyze(analyze) => analyze.execute(),
+            Actions::Bench(bench) => bench.execute(),
             Actions::Compile(compile) => compile.execute(),
             Actions::Bundle(compile) => compile.execute(),
             Actions::Debug(debug) => debug.execute(),
@@ -58,6 +58,116 @@ fn cli_run() -> Result<(), Box<dyn std::error::Error>> {
     Ok(())
 }
 
+#[test]
+fn cli_prove() {
+    let program_file = std::env::temp_dir().join("cli_prove.masm");
+    let proof_file = std::env::temp_dir().join("cli_prove.proof");
+    let output_file = std::env::temp_dir().join("cli_prove.outputs");
+    fs::write(&program_file, "begin push.1.2 add swap drop end").unwrap();
+
+    let mut cmd = bin_under_test().command();
+    cmd.arg("prove")
+        .arg(program_file.as_path())
+        .arg("--security")
+        .arg("96")
+        .arg("--proof")
+        .arg(proof_file.as_path())
+        .arg("--output")
+        .arg(output_file.as_path());
+    cmd.assert().success();
+
+    assert!(!fs::read(&proof_file).unwrap().is_empty());
+    assert!(!fs::read(&output_file).unwrap().is_empty());
+
+    fs::remove_file(&program_file).unwrap();
+    fs::remove_file(&proof_file).unwrap();
+    fs::remove_file(&output_file).unwrap();
+}
+
+#[test]
+fn cli_prove_verify() {
+    let program_file = std::env::temp_dir().join("cli_prove_verify.masm");
+    let proof_file = std::env::temp_dir().join("cli_prove_verify.proof");
+    let output_file = std::env::temp_dir().join("cli_prove_verify.outputs");
+    fs::write(&program_file, "begin push.1.2 add swap drop end").unwrap();
+
+    let mut cmd = bin_under_test().command();
+    cmd.arg("prove")
+        .arg(program_file.as_path())
+        .arg("--proof")
+        .arg(proof_file.as_path())
+        .arg("--output")
+        .arg(output_file.as_path());
+    let output = cmd.output().unwrap();
+    assert!(output.status.success());
+
+    // the program hash is only reported on the standard output
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    let program_hash = stdout
+        .lines()
+        .find_map(|line| line.strip_prefix("Proving program with hash "))
+        .and_then(|line| line.strip_suffix("..."))
+        .unwrap()
+        .to_string();
+
+    let verify = |proof_file: &Path| {
+        let mut cmd = bin_under_test().command();
+        cmd.arg("verify")
+            .arg("--proof")
+            .arg(proof_file)
+            .arg("--output")
+            .arg(output_file.as_path())
+            .arg("--program-hash")
+            .arg(&program_hash);
+        cmd.assert()
+    };
+
+    // the generated proof is valid
+    verify(&proof_file)
+        .success()
+        .stdout(predicate::str::contains("Verification complete"));
+
+    // a truncated proof file cannot be decoded
+    let truncated_proof_file = std::env::temp_dir().join("cli_prove_verify_truncated.proof");
+    let proof_bytes = fs::read(&proof_file).unwrap();
+    fs::write(&truncated_proof_file, &proof_bytes[..proof_bytes.len() / 2]).unwrap();
+    verify(&truncated_proof_file)
+        .failure()
+        .stderr(predicate::str::contains("the file may be corrupted or truncated"));
+
+    // tampering with the outputs makes the verification fail
+    let outputs = fs::read_to_string(&output_file).unwrap();
+    assert!(outputs.contains("\"3\""));
+    fs::write(&output_file, outputs.replacen("\"3\"", "\"4\"", 1)).unwrap();
+    verify(&proof_file)
+        .failure()
+        .stderr(predicate::str::contains("Program failed verification"));
+
+    fs::remove_file(&program_file).unwrap();
+    fs::remove_file(&proof_file).unwrap();
+    fs::remove_file(&truncated_proof_file).unwrap();
+    fs::remove_file(&output_file).unwrap();
+}
+
+#[test]
+fn cli_bench() {
+    let mut cmd = bin_under_test().command();
+    cmd.arg("bench")
+        .arg("./masm-examples/fib/fib.masm")
+        .arg("-n")
+        .arg("2")
+        .arg("-m")
+        .arg("8192")
+        .arg("-e")
+        .arg("8192");
+
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("2 times"))
+        .stdout(predicate::str::is_match(r"Average VM cycles: \d+").unwrap())
+        .stdout(predicate::str::is_match(r"Trace length: \d+ \(padded to \d+\)").unwrap());
+}
+
 use miden_assembly::Library;
 use miden_core::Decorator;
 
@@ -80,6 +190,66 @@ fn cli_bundle_debug() {
     fs::remove_file(&output_file).unwrap();
 }
 
+#[test]
+fn cli_compile_digest_format() {
+    use base64::{Engine, engine::general_purpose::STANDARD as BASE64};
+    use miden_assembly::Assembler;
+
+    let source_file = "./tests/integration/cli/data/debug_adv_stack_all.masm";
+    let output_file = std::env::temp_dir().join("cli_compile_digest_format.masb");
+
+    let source = fs::read_to_string(source_file).unwrap();
+    let program_hash = Assembler::default().assemble_program(source).unwrap().hash();
+    let hash_bytes: [u8; 32] = program_hash.into();
+    let hash_elements: Vec<String> = program_hash.iter().map(|e| e.as_int().to_string()).collect();
+
+    let expected = [
+        ("hex", program_hash.to_hex().trim_start_matches("0x").to_string()),
+        ("base64", BASE64.encode(hash_bytes)),
+        ("felt-array", format!("[{}]", hash_elements.join(", "))),
+    ];
+
+    for (format, expected_hash) in expected {
+        let mut cmd = bin_under_test().command();
+        cmd.arg("compile")
+            .arg("--assembly")
+            .arg(source_file)
+            .arg("--output")
+            .arg(output_file.as_path())
+            .arg("--digest-format")
+            .arg(format);
+        cmd.assert()
+            .success()
+            .stdout(predicate::str::contains(format!("program hash is {expected_hash}\n")));
+    }
+    fs::remove_file(&output_file).unwrap();
+}
+
+#[test]
+fn cli_compile_output() {
+    use miden_assembly::Assembler;
+    use miden_core::Program;
+
+    let source_file = "./tests/integration/cli/data/debug_adv_stack_all.masm";
+    let output_file = std::env::temp_dir().join("cli_compile_output.masb");
+
+    let mut cmd = bin_under_test().command();
+    cmd.arg("compile")
+        .arg("--assembly")
+        .arg(source_file)
+        .arg("--output")
+        .arg(output_file.as_path());
+    cmd.assert().success();
+
+    assert!(fs::metadata(&output_file).unwrap().len() > 0);
+
+    let source = fs::read_to_string(source_file).unwrap();
+    let expected_hash = Assembler::default().assemble_program(source).unwrap().hash();
+    let program = Program::deserialize_from_file(&output_file).unwrap();
+    assert_eq!(program.hash(), expected_hash);
+    fs::remove_file(&output_file).unwrap();
+}
+
 #[test]
 fn cli_bundle_no_exports() {
     let mut cmd = bin_under_test().command();
@@ -165,8 +335,8 @@ fn test_debug_adv_stack_all() -> Result<(), Box<dyn std::error::Error>> {
 
     cmd.assert().stdout(predicate::str::contains(
         "Advice Stack state before step 2:
-├──  0: 42
-└──  1: 21
+├──  0: 21
+└──  1: 42
 ",
     ));
 
@@ -184,7 +354,7 @@ fn test_debug_adv_stack_prefix() -> Result<(), Box<dyn std::error::Error>> {
 
     cmd.assert().stdout(predicate::str::contains(
         "Advice Stack state before step 2:
-└──  0: 42
+└──  0: 21
 ",
     ));
 
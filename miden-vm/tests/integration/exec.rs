@@ -1,11 +1,34 @@
 use alloc::sync::Arc;
 
 use miden_assembly::{Assembler, DefaultSourceManager};
-use miden_core::{ONE, Program, assert_matches};
-use miden_processor::{AdviceError, AdviceInputs, ExecutionOptions, MastForest};
-use miden_prover::{StackInputs, Word};
+use miden_core::{
+    ONE, Program, ZERO, assert_matches,
+    mast::{BasicBlockNode, MastNode, MastNodeId},
+};
+use miden_debug_types::{LineNumber, SourceLanguage, SourceManager};
+use miden_processor::{
+    AdviceError, AdviceInputs, Breakpoint, CallFrameProfile, ContextId, ExecutionError,
+    ExecutionOptions, ExecutionTrace, MastForest, MemoryAddress, Process, RowIndex,
+    fast::FastProcessor,
+};
+use miden_prover::{StackInputs, StackOutputs, Word};
 use miden_vm::DefaultHost;
 
+/// Executes `program` with default inputs and options, and returns its stack outputs.
+fn execute(program: &Program) -> StackOutputs {
+    let mut host = DefaultHost::default();
+    let trace = miden_processor::execute(
+        program,
+        StackInputs::default(),
+        AdviceInputs::default(),
+        &mut host,
+        ExecutionOptions::default(),
+        Arc::new(DefaultSourceManager::default()),
+    )
+    .unwrap();
+    trace.stack_outputs().clone()
+}
+
 #[test]
 fn advice_map_loaded_before_execution() {
     let source = "\
@@ -63,3 +86,579 @@ fn advice_map_loaded_before_execution() {
     )
     .unwrap();
 }
+
+#[test]
+fn max_ops_per_basic_block_splits_basic_blocks() {
+    let source = "
+    begin
+        repeat.200
+            add.1
+        end
+    end";
+
+    // sums `f` over all basic blocks executed by the program, which consists of JOIN and SPAN
+    // blocks only
+    fn sum_over_blocks(program: &Program, f: fn(&BasicBlockNode) -> usize) -> usize {
+        fn visit(
+            forest: &MastForest,
+            node_id: MastNodeId,
+            f: fn(&BasicBlockNode) -> usize,
+        ) -> usize {
+            match &forest[node_id] {
+                MastNode::Block(block) => f(block),
+                MastNode::Join(join) => {
+                    visit(forest, join.first(), f) + visit(forest, join.second(), f)
+                },
+                _ => unreachable!("unexpected node in straight-line program"),
+            }
+        }
+        visit(program.mast_forest(), program.entrypoint(), f)
+    }
+
+    // counts the RESPAN operations executed by the program
+    fn num_respans(program: &Program) -> usize {
+        sum_over_blocks(program, |block| block.num_op_batches() - 1)
+    }
+
+    // counts the decorators of all basic blocks, as well as the procedure scope decorators which
+    // are attached to the root of the program body when it is not a single basic block
+    fn num_decorators(program: &Program) -> usize {
+        let root = &program.mast_forest()[program.entrypoint()];
+        root.before_enter().len()
+            + root.after_exit().len()
+            + sum_over_blocks(program, |block| block.decorators().len())
+    }
+
+    // by default, the 200 operations are executed as a single basic block with 3 op batches
+    let program = Assembler::default().assemble_program(source).unwrap();
+    assert_eq!(num_respans(&program), 2);
+
+    // with at most 50 operations per block, every block fits into a single op batch
+    let split_program = Assembler::default()
+        .with_max_ops_per_basic_block(50)
+        .assemble_program(source)
+        .unwrap();
+    assert_eq!(num_respans(&split_program), 0);
+
+    // the split blocks are joined together, so the program has a different MAST root
+    let split_root = &split_program.mast_forest()[split_program.entrypoint()];
+    assert!(matches!(split_root, MastNode::Join(_)));
+    assert_ne!(split_program.hash(), program.hash());
+
+    assert_eq!(execute(&split_program), execute(&program));
+    assert_eq!(execute(&program).get_stack_item(0), Some(200_u32.into()));
+
+    // decorators are carried over into the split blocks
+    let debug_program =
+        Assembler::default().with_debug_mode(true).assemble_program(source).unwrap();
+    let split_debug_program = Assembler::default()
+        .with_debug_mode(true)
+        .with_max_ops_per_basic_block(50)
+        .assemble_program(source)
+        .unwrap();
+    assert_eq!(num_respans(&split_debug_program), 0);
+    assert_eq!(num_decorators(&split_debug_program), num_decorators(&debug_program));
+    assert_eq!(execute(&split_debug_program), execute(&program));
+}
+
+#[test]
+fn optimizations_preserve_program_behavior() {
+    let source = "
+    proc.foo.4
+        loc_store.1
+        loc_store.3
+        loc_load.1
+        loc_load.3
+        push.7 drop
+        swap swap
+        sub
+    end
+
+    proc.bar.12
+        loc_storew.8 dropw
+        locaddr.9 mem_load
+        dup locaddr.0 mem_store
+        loc_load.0 add
+    end
+
+    begin
+        push.5.12 exec.foo
+        push.1.2.3.4 exec.bar
+        add swap drop
+    end";
+
+    let program = Assembler::default().assemble_program(source).unwrap();
+    let optimized_program =
+        Assembler::default().with_optimizations(true).assemble_program(source).unwrap();
+    assert_ne!(optimized_program.hash(), program.hash());
+
+    let outputs = execute(&program);
+    assert_eq!(execute(&optimized_program), outputs);
+    assert_eq!(outputs.get_stack_item(0), Some(11_u32.into()));
+}
+
+#[test]
+fn optimizations_keep_locals_reachable_via_locaddr() {
+    // `foo` only refers to its locals via the address of the first one, which is passed to the
+    // callees as a pointer to a buffer of two words
+    let source = "
+    proc.write_words
+        dup push.1.2.3.4 movup.4 mem_storew dropw
+        add.4 push.5.6.7.8 movup.4 mem_storew dropw
+    end
+
+    proc.read_second_word
+        add.4 padw movup.4 mem_loadw
+    end
+
+    proc.clobber.4
+        padw loc_storew.0 dropw
+    end
+
+    proc.foo.8
+        locaddr.0 exec.write_words
+        exec.clobber
+        locaddr.0 exec.read_second_word
+    end
+
+    begin
+        exec.foo swapw dropw
+    end";
+
+    let program = Assembler::default().assemble_program(source).unwrap();
+    let optimized_program =
+        Assembler::default().with_optimizations(true).assemble_program(source).unwrap();
+
+    // the second word of the buffer must not be overwritten by the frame of `clobber`
+    let outputs = execute(&optimized_program);
+    assert_eq!(outputs, execute(&program));
+    assert_eq!(outputs.get_stack_word(0), Some(Word::from([5_u32, 6, 7, 8])));
+}
+
+#[test]
+fn stack_depth_limit_exceeded() {
+    let source = "
+    begin
+        push.10 push.11 push.12
+        drop drop drop
+    end";
+
+    let program = Assembler::default().assemble_program(source).unwrap();
+    let execute = |max_stack_depth| {
+        let mut host = DefaultHost::default();
+        miden_processor::execute(
+            &program,
+            StackInputs::default(),
+            AdviceInputs::default(),
+            &mut host,
+            ExecutionOptions::default().with_max_stack_depth(max_stack_depth),
+            Arc::new(DefaultSourceManager::default()),
+        )
+    };
+
+    let execute_fast = |max_stack_depth| {
+        let mut host = DefaultHost::default();
+        FastProcessor::new(&[])
+            .with_execution_options(
+                ExecutionOptions::default().with_max_stack_depth(max_stack_depth),
+            )
+            .execute_sync(&program, &mut host)
+    };
+
+    assert!(execute(19).is_ok());
+    assert!(execute_fast(19).is_ok());
+
+    // the span is entered at cycle 0, and `push.12` grows the stack to 19 items at cycle 3
+    assert_matches!(
+        execute(18).unwrap_err(),
+        ExecutionError::StackDepthExceeded { clk, depth: 19 } if clk == RowIndex::from(3)
+    );
+    assert_matches!(
+        execute_fast(18).unwrap_err(),
+        ExecutionError::StackDepthExceeded { clk, depth: 19 } if clk == RowIndex::from(3)
+    );
+}
+
+#[test]
+fn advice_pop_limit_exceeded() {
+    let source = "
+    begin
+        adv_push.2 adv_push.1
+        drop drop drop
+    end";
+
+    let program = Assembler::default().assemble_program(source).unwrap();
+    let advice_inputs = AdviceInputs::default().with_stack_values([1, 2, 3, 4]).unwrap();
+    let execute = |max_advice_pops| {
+        let mut host = DefaultHost::default();
+        miden_processor::execute(
+            &program,
+            StackInputs::default(),
+            advice_inputs.clone(),
+            &mut host,
+            ExecutionOptions::default().with_max_advice_pops(max_advice_pops),
+            Arc::new(DefaultSourceManager::default()),
+        )
+    };
+    let execute_fast = |max_advice_pops| {
+        let mut host = DefaultHost::default();
+        FastProcessor::new_with_advice_inputs(&[], advice_inputs.clone())
+            .with_execution_options(
+                ExecutionOptions::default().with_max_advice_pops(max_advice_pops),
+            )
+            .execute_sync(&program, &mut host)
+    };
+
+    assert!(execute(3).is_ok());
+    assert!(execute_fast(3).is_ok());
+
+    assert_matches!(
+        execute(2).unwrap_err(),
+        ExecutionError::AdviceError {
+            err: AdviceError::StackPopLimitExceeded { limit: 2 },
+            ..
+        }
+    );
+    assert_matches!(
+        execute_fast(2).unwrap_err(),
+        ExecutionError::AdviceError {
+            err: AdviceError::StackPopLimitExceeded { limit: 2 },
+            ..
+        }
+    );
+}
+
+#[test]
+fn seeded_advice_without_inputs() {
+    let source = "
+    begin
+        adv_loadw adv_push.2 add swap drop
+    end";
+
+    let program = Assembler::default().assemble_program(source).unwrap();
+    let execute = |options| {
+        let mut host = DefaultHost::default();
+        miden_processor::execute(
+            &program,
+            StackInputs::default(),
+            AdviceInputs::default(),
+            &mut host,
+            options,
+            Arc::new(DefaultSourceManager::default()),
+        )
+    };
+    let execute_fast = |options| {
+        let mut host = DefaultHost::default();
+        FastProcessor::new(&[])
+            .with_execution_options(options)
+            .execute_sync(&program, &mut host)
+    };
+
+    // without a seed, the program fails as no advice was provided
+    assert!(execute(ExecutionOptions::default()).is_err());
+
+    // with a seed, the advice is drawn deterministically, and both processors agree on it
+    let seed = Word::from([1_u32, 2, 3, 4]);
+    let options = ExecutionOptions::default().with_advice_seed(seed);
+    let outputs = execute(options).unwrap().stack_outputs().clone();
+    assert_eq!(execute(options).unwrap().stack_outputs(), &outputs);
+    assert_eq!(execute_fast(options).unwrap(), outputs);
+
+    // a different seed yields different advice
+    let other_options = ExecutionOptions::default().with_advice_seed(Word::from([5_u32, 6, 7, 8]));
+    assert_ne!(execute(other_options).unwrap().stack_outputs(), &outputs);
+}
+
+#[test]
+fn chiplet_segment_lengths_reported_in_trace() {
+    let source = "
+    begin
+        repeat.8
+            hperm
+        end
+    end";
+
+    let program = Assembler::default().assemble_program(source).unwrap();
+    let mut host = DefaultHost::default();
+    let trace = miden_processor::execute(
+        &program,
+        StackInputs::default(),
+        AdviceInputs::default(),
+        &mut host,
+        ExecutionOptions::default(),
+        Arc::new(DefaultSourceManager::default()),
+    )
+    .unwrap();
+
+    let lengths = trace.chiplet_segment_lengths();
+    let chiplets_len = trace.trace_len_summary().chiplets_trace_len();
+    assert_eq!(lengths.hasher, chiplets_len.hash_chiplet_len());
+    assert_eq!(lengths.bitwise, chiplets_len.bitwise_chiplet_len());
+    assert_eq!(lengths.kernel_rom, chiplets_len.kernel_rom_len());
+    assert!(lengths.hasher > lengths.total() - lengths.hasher);
+}
+
+#[test]
+fn memory_access_histogram() {
+    let source = "
+    begin
+        push.7 mem_store.4
+        mem_load.4 mem_load.4 drop drop
+        padw mem_loadw.8 dropw
+    end";
+
+    let program = Assembler::default().assemble_program(source).unwrap();
+    let mut host = DefaultHost::default();
+    let mut process = Process::new(
+        program.kernel().clone(),
+        StackInputs::default(),
+        AdviceInputs::default(),
+        ExecutionOptions::default(),
+    );
+    process.execute(&program, &mut host).unwrap();
+
+    let histogram = process.memory_access_histogram();
+    assert_eq!(histogram.len(), 2);
+    assert_eq!(histogram[&(ContextId::root(), MemoryAddress::from(4))], 3);
+    assert_eq!(histogram[&(ContextId::root(), MemoryAddress::from(8))], 1);
+}
+
+#[test]
+fn cycle_limit_exceeded() {
+    let source = "
+    begin
+        push.1
+        while.true
+            push.1
+        end
+    end";
+
+    let program = Assembler::default().assemble_program(source).unwrap();
+    let mut host = DefaultHost::default();
+    let err = miden_processor::execute(
+        &program,
+        StackInputs::default(),
+        AdviceInputs::default(),
+        &mut host,
+        ExecutionOptions::new(Some(64), 64, false, false).unwrap(),
+        Arc::new(DefaultSourceManager::default()),
+    )
+    .unwrap_err();
+    assert_matches!(err, ExecutionError::CycleLimitExceeded { limit: 64 });
+}
+
+#[test]
+fn cycle_limit_not_exceeded() {
+    let source = "
+    begin
+        push.3 push.1
+        while.true
+            sub.1 dup neq.0
+        end
+        add.7 swap drop
+    end";
+
+    let program = Assembler::default().assemble_program(source).unwrap();
+    let mut host = DefaultHost::default();
+    let trace = miden_processor::execute(
+        &program,
+        StackInputs::default(),
+        AdviceInputs::default(),
+        &mut host,
+        ExecutionOptions::new(Some(64), 64, false, false).unwrap(),
+        Arc::new(DefaultSourceManager::default()),
+    )
+    .unwrap();
+    assert_eq!(trace.stack_outputs().get_stack_item(0), Some(7_u32.into()));
+}
+
+#[test]
+fn resumed_execution_matches_single_shot() {
+    let source = "
+    proc.double
+        mul.2
+    end
+
+    begin
+        push.0 mem_store.0
+        push.5 push.1
+        while.true
+            dup mem_load.0 add mem_store.0
+            sub.1 dup neq.0
+        end
+        drop mem_load.0 swap drop
+        call.double
+    end";
+
+    let program = Assembler::default().assemble_program(source).unwrap();
+    let new_process = || {
+        Process::new(
+            program.kernel().clone(),
+            StackInputs::default(),
+            AdviceInputs::default(),
+            ExecutionOptions::default(),
+        )
+    };
+
+    let mut host = DefaultHost::default();
+    let mut process = new_process();
+    let outputs = process.execute(&program, &mut host).unwrap();
+    let trace = ExecutionTrace::new(process, outputs);
+    assert_eq!(trace.stack_outputs().get_stack_item(0), Some(30_u32.into()));
+
+    // run the program past the first iteration of the loop, and inspect the state
+    let mut host = DefaultHost::default();
+    let mut process = new_process();
+    assert_eq!(process.run_until(&program, &mut host, RowIndex::from(20)).unwrap(), None);
+    let state = process.state();
+    assert_eq!(state.clk(), RowIndex::from(20));
+    assert_eq!(state.get_mem_value(ContextId::root(), 0), Some(5_u32.into()));
+
+    // step through a few cycles, and then run the program to completion
+    for _ in 0..5 {
+        assert_eq!(process.step(&program, &mut host).unwrap(), None);
+    }
+    assert_eq!(process.state().clk(), RowIndex::from(25));
+    let outputs = process.execute(&program, &mut host).unwrap();
+    let resumed_trace = ExecutionTrace::new(process, outputs);
+
+    assert_eq!(resumed_trace.stack_outputs(), trace.stack_outputs());
+    assert_eq!(resumed_trace.program_hash(), trace.program_hash());
+    assert_eq!(resumed_trace.trace_len_summary(), trace.trace_len_summary());
+}
+
+#[test]
+fn failed_execution_cannot_be_resumed() {
+    let source = "
+    proc.fail
+        push.1 assertz
+    end
+
+    begin
+        push.1 push.2
+        call.fail
+        add
+    end";
+
+    let program = Assembler::default().assemble_program(source).unwrap();
+    let mut host = DefaultHost::default();
+    let mut process = Process::new(
+        program.kernel().clone(),
+        StackInputs::default(),
+        AdviceInputs::default(),
+        ExecutionOptions::default(),
+    );
+
+    assert_matches!(
+        process.execute(&program, &mut host).unwrap_err(),
+        ExecutionError::FailedAssertion { .. }
+    );
+    // the remainder of the program is dropped on failure, rather than executed from the
+    // continuation which failed
+    assert_matches!(
+        process.step(&program, &mut host).unwrap_err(),
+        ExecutionError::ProgramAlreadyExecuted
+    );
+}
+
+#[test]
+fn breakpoints_pause_execution() {
+    let source = "\
+begin
+    push.1 push.2
+    add
+    push.3
+    mul
+    swap drop
+end";
+
+    let source_manager = Arc::new(DefaultSourceManager::default());
+    let source_file =
+        source_manager.load(SourceLanguage::Masm, "test://breakpoints".into(), source.to_string());
+    let program = Assembler::new(source_manager.clone())
+        .with_debug_mode(true)
+        .assemble_program(source_file)
+        .unwrap();
+
+    let mut host = DefaultHost::default();
+    let mut process = Process::new(
+        program.kernel().clone(),
+        StackInputs::default(),
+        AdviceInputs::default(),
+        ExecutionOptions::default(),
+    )
+    .with_source_manager(source_manager);
+    let breakpoints = [
+        Breakpoint::Clock(RowIndex::from(2)),
+        Breakpoint::AsmOp {
+            uri: "test://breakpoints".into(),
+            line: LineNumber::new(5).unwrap(),
+        },
+    ];
+
+    // `push.1` is executed as PAD INCR, starting at cycle 1
+    assert_eq!(process.run_with_breakpoints(&program, &mut host, &breakpoints).unwrap(), None);
+    assert_eq!(process.state().clk(), RowIndex::from(2));
+    assert_eq!(process.state().get_stack_top(2), [ZERO, ZERO]);
+
+    // `mul` on line 5 has not been executed yet
+    assert_eq!(process.run_with_breakpoints(&program, &mut host, &breakpoints).unwrap(), None);
+    assert_eq!(process.state().get_stack_top(2), [3_u32.into(), 3_u32.into()]);
+
+    let outputs = process.run_with_breakpoints(&program, &mut host, &breakpoints).unwrap();
+    assert_eq!(outputs.unwrap().get_stack_item(0), Some(9_u32.into()));
+}
+
+#[test]
+fn profiling_counts_call_frame_cycles() {
+    fn profile(num_iterations: u32) -> (CallFrameProfile, CallFrameProfile) {
+        let source = format!(
+            "
+            proc.foo
+                push.1 push.2 add drop
+            end
+
+            begin
+                push.{num_iterations} push.1
+                while.true
+                    call.foo
+                    sub.1 dup neq.0
+                end
+                drop
+            end"
+        );
+        let program = Assembler::default().assemble_program(source).unwrap();
+        let foo_root = program
+            .mast_forest()
+            .procedure_digests()
+            .find(|digest| *digest != program.hash())
+            .unwrap();
+
+        let mut host = DefaultHost::default();
+        let mut process = Process::new(
+            program.kernel().clone(),
+            StackInputs::default(),
+            AdviceInputs::default(),
+            ExecutionOptions::default().with_profiling(),
+        );
+        process.execute(&program, &mut host).unwrap();
+
+        let report = process.call_profiling_report().unwrap();
+        (
+            report.get(&program.hash()).unwrap().clone(),
+            report.get(&foo_root).unwrap().clone(),
+        )
+    }
+
+    let (main_2, foo_2) = profile(2);
+    let (main_4, foo_4) = profile(4);
+
+    assert_eq!(foo_2.num_calls(), 2);
+    assert_eq!(foo_4.num_calls(), 4);
+    assert_eq!(foo_4.inclusive_cycles(), 2 * foo_2.inclusive_cycles());
+    assert_eq!(foo_4.exclusive_cycles(), foo_4.inclusive_cycles());
+
+    assert_eq!(main_4.num_calls(), 1);
+    assert_eq!(main_4.inclusive_cycles(), main_4.exclusive_cycles() + foo_4.inclusive_cycles());
+    assert!(main_4.exclusive_cycles() > main_2.exclusive_cycles());
+}
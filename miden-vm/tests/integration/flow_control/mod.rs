@@ -11,6 +11,13 @@ use miden_vm::Module;
 // SIMPLE FLOW CONTROL TESTS
 // ================================================================================================
 
+#[test]
+fn empty_program() {
+    let test = build_test!("begin end", &[1, 2, 3]);
+    test.expect_stack(&[3, 2, 1]);
+    test.prove_and_verify(vec![1, 2, 3], false);
+}
+
 #[test]
 fn conditional_execution() {
     // --- if without else ------------------------------------------------------------------------
@@ -55,6 +62,34 @@ fn conditional_loop() {
     test.expect_stack(&[10]);
 }
 
+#[test]
+fn conditional_loop_with_iterations_hint() {
+    // the hint is smaller than the actual number of iterations, which must not affect execution
+    let source = "
+        begin
+            dup push.0 movdn.2 neq.0
+            while.true{}
+                dup movup.2 add swap push.1 sub dup neq.0
+            end
+            drop
+        end";
+    let source_with_hint = source.replace("{}", "(max=4)");
+    let source_without_hint = source.replace("{}", "");
+
+    let test_with_hint = build_test!(&source_with_hint, &[10]);
+    test_with_hint.expect_stack(&[55]);
+    let test_without_hint = build_test!(&source_without_hint, &[10]);
+
+    let (program_with_hint, _) = test_with_hint.compile().unwrap();
+    let (program_without_hint, _) = test_without_hint.compile().unwrap();
+    assert_eq!(program_with_hint.hash(), program_without_hint.hash());
+
+    let trace_with_hint = test_with_hint.execute().unwrap();
+    let trace_without_hint = test_without_hint.execute().unwrap();
+    assert_eq!(trace_with_hint.stack_outputs(), trace_without_hint.stack_outputs());
+    assert_eq!(trace_with_hint.get_trace_len(), trace_without_hint.get_trace_len());
+}
+
 #[test]
 fn faulty_condition_from_loop() {
     let source = "
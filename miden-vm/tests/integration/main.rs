@@ -1,6 +1,13 @@
 extern crate alloc;
 
-use miden_utils_testing::{build_op_test, build_test};
+use alloc::sync::Arc;
+
+use miden_assembly::DefaultSourceManager;
+use miden_utils_testing::{AcceptableOptions, Felt, build_op_test, build_test};
+use miden_vm::{
+    Assembler, DefaultHost, ProgramInfo, ProvingOptions, StackInputs, prove, verify,
+    verify_with_options,
+};
 
 mod air;
 mod cli;
@@ -23,6 +30,70 @@ fn multi_output_program() {
     test.prove_and_verify(vec![1, 2, 3], false);
 }
 
+#[test]
+fn verify_returns_security_level() {
+    let program = Assembler::default().assemble_program("begin push.1 add end").unwrap();
+    let stack_inputs = StackInputs::try_from_ints([1]).unwrap();
+    let (stack_outputs, proof) = prove(
+        &program,
+        stack_inputs.clone(),
+        Default::default(),
+        &mut DefaultHost::default(),
+        ProvingOptions::with_96_bit_security(false),
+        Arc::new(DefaultSourceManager::default()),
+    )
+    .unwrap();
+
+    let security_level =
+        verify(ProgramInfo::from(program), stack_inputs, stack_outputs, proof).unwrap();
+    assert!(security_level >= 96, "proof has security level of {security_level} bits");
+}
+
+#[test]
+fn prove_verify_for_testing() {
+    let program = Assembler::default().assemble_program("begin push.1 add end").unwrap();
+    let stack_inputs = StackInputs::try_from_ints([1]).unwrap();
+    let (stack_outputs, proof) = prove(
+        &program,
+        stack_inputs.clone(),
+        Default::default(),
+        &mut DefaultHost::default(),
+        ProvingOptions::for_testing(),
+        Arc::new(DefaultSourceManager::default()),
+    )
+    .unwrap();
+
+    assert_eq!(stack_outputs.get_stack_item(0), Some(Felt::new(2)));
+
+    // low-security proofs are rejected by the default verifier
+    let program_info = ProgramInfo::from(program);
+    let result =
+        verify(program_info.clone(), stack_inputs.clone(), stack_outputs.clone(), proof.clone());
+    assert!(result.is_err());
+
+    // but are accepted when the testing parameters are explicitly allowed
+    let opts = AcceptableOptions::OptionSet(vec![ProvingOptions::TESTING]);
+    verify_with_options(program_info, stack_inputs, stack_outputs, proof, &opts).unwrap();
+}
+
+#[test]
+fn decode_stack_outputs() {
+    let source = "begin u32overflowing_add end";
+    let outputs = build_test!(source, &[5, 6, 7, 8, 4294967295, 1]).get_last_stack_state();
+
+    // u32overflowing_add leaves the carry at the top of the stack and the sum below it
+    assert_eq!(outputs.get_u64(0), Some(1 << 32));
+    assert_eq!(outputs.get_u64(3), Some((7 << 32) | 6));
+    assert_eq!(outputs.get_stack_word(2), Some([5u64, 6, 7, 8].map(Felt::new).into()));
+    assert_eq!(outputs.as_felts().len(), 16);
+    assert_eq!(outputs.as_felts()[..6], [1u64, 0, 8, 7, 6, 5].map(Felt::new));
+
+    // the value must be in bounds, and both limbs must be valid u32 values
+    assert_eq!(outputs.get_u64(15), None);
+    let outputs = build_test!("begin nop end", &[4294967296, 1]).get_last_stack_state();
+    assert_eq!(outputs.get_u64(0), None);
+}
+
 #[test]
 fn program_with_respan() {
     let source = "
@@ -1,6 +1,11 @@
-use miden_core::Felt;
+use miden_core::{Felt, StarkField, assert_matches};
+use miden_processor::{AdviceError, ExecutionError};
 use miden_prover::Word;
-use miden_utils_testing::{TRUNCATE_STACK_PROC, build_test, crypto::MerkleStore, rand::rand_value};
+use miden_utils_testing::{
+    TRUNCATE_STACK_PROC, advice_map, build_test,
+    crypto::{MerkleStore, Rpo256},
+    rand::rand_value,
+};
 
 // ADVICE INJECTION
 // ================================================================================================
@@ -204,6 +209,57 @@ fn advice_insert_mem() {
     test.expect_stack(&[1, 2, 3, 4, 5, 6, 7, 8]);
 }
 
+#[test]
+fn advice_insert_mem_hashed() {
+    let source = "begin
+    # stack: [KEY, ...]
+
+    # write two words to memory.
+    # mem_storew reverses the order of field elements in the word when it's stored in memory.
+    push.1.2.3.4 mem_storew.8 dropw push.5.6.7.8 mem_storew.12 dropw
+    # State Transition:
+    # mem[8..11]: [1, 2, 3, 4]
+    # mem[12..15]: [5, 6, 7, 8]
+
+    # copy 2 words starting at address 8 from memory to advice map under the hash of these words
+    push.2.8 adv.insert_mem_hashed drop drop
+    # State Transition:
+    # advice_map: k: hash([1, 2, 3, 4, 5, 6, 7, 8]), v: [1, 2, 3, 4, 5, 6, 7, 8]
+
+    # retrieve the words using the hash, which was provided as the stack input, as the key
+    adv.push_mapval dropw
+    # State Transition:
+    # advice_stack: [1, 2, 3, 4, 5, 6, 7, 8]
+
+    # move the values from the advice stack to the operand stack and truncate the stack
+    adv_push.8 swapdw dropw dropw
+    # State Transition:
+    # stack: [8, 7, 6, 5, 4, 3, 2, 1]
+    end";
+
+    let values: Vec<Felt> = (1..=8).map(Felt::new).collect();
+    let key = Rpo256::hash_elements(&values);
+    let stack_inputs: Vec<u64> = key.iter().map(|value| value.as_int()).collect();
+
+    let test = build_test!(source, &stack_inputs);
+    test.expect_stack(&[8, 7, 6, 5, 4, 3, 2, 1]);
+}
+
+#[test]
+fn advice_insert_mem_hashed_too_many_words() {
+    // the number of inserted words is capped at 2^16
+    let source = "begin push.65537.0 adv.insert_mem_hashed drop drop end";
+
+    let test = build_test!(source);
+    assert_matches!(
+        test.execute(),
+        Err(ExecutionError::AdviceError {
+            err: AdviceError::TooManyMemWords { count: 65537, max: 65536 },
+            ..
+        })
+    );
+}
+
 #[test]
 fn advice_push_mapval() {
     // --- test simple adv.mapval ---------------------------------------------
@@ -314,6 +370,26 @@ fn advice_has_mapkey() {
     test.expect_stack(&[0, 4, 3, 2, 1]);
 }
 
+#[test]
+fn advice_map_macro() {
+    let key = Word::new([Felt::new(5), Felt::new(6), Felt::new(7), Felt::new(8)]);
+    let adv_map = advice_map! {
+        [1, 2, 3, 4] => [8, 7, 6],
+        key => [],
+    };
+
+    assert_eq!(
+        adv_map,
+        vec![
+            (
+                Word::new([Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)]),
+                vec![Felt::new(8), Felt::new(7), Felt::new(6)]
+            ),
+            (key, vec![]),
+        ]
+    );
+}
+
 #[test]
 fn advice_insert_hdword() {
     // --- test hashing without domain ----------------------------------------
@@ -367,3 +443,173 @@ fn advice_insert_hdword() {
     let test = build_test!(source, &stack_inputs);
     test.expect_stack(&[1, 2, 3, 4, 5, 6, 7, 8]);
 }
+
+#[test]
+fn advice_push_sha256() {
+    use sha2::{Digest, Sha256};
+
+    // includes an empty message as well as lengths which are not multiples of 4 bytes
+    for len in [0_usize, 1, 3, 4, 5, 55, 64, 100] {
+        let message: Vec<u8> = (0..len).map(|i| (i * 7 + 3) as u8).collect();
+
+        // store the message in memory starting at address 100, packing 4 bytes per element
+        let mut source = String::from("begin\n");
+        for (i, chunk) in message.chunks(4).enumerate() {
+            let mut bytes = [0_u8; 4];
+            bytes[..chunk.len()].copy_from_slice(chunk);
+            let element = u32::from_be_bytes(bytes);
+            source.push_str(&format!("    push.{element} mem_store.{}\n", 100 + i));
+        }
+        source.push_str(&format!(
+            "    push.{len} push.100 adv.push_sha256 drop drop\n    adv_push.8 swapdw dropw dropw\nend"
+        ));
+
+        // adv_push.8 places the first word of the digest deepest on the stack
+        let expected: Vec<u64> = Sha256::digest(&message)
+            .chunks_exact(4)
+            .rev()
+            .map(|chunk| u32::from_be_bytes(chunk.try_into().unwrap()) as u64)
+            .collect();
+
+        let test = build_test!(&source);
+        test.expect_stack(&expected);
+    }
+}
+
+#[test]
+fn advice_push_sha256_message_too_long() {
+    // the length of the hashed message is capped at 2^20 bytes
+    let source = "begin push.1048577 push.100 adv.push_sha256 drop drop end";
+
+    let test = build_test!(source);
+    assert_matches!(
+        test.execute(),
+        Err(ExecutionError::AdviceError {
+            err: AdviceError::MessageTooLong { len: 1048577, max: 1048576 },
+            ..
+        })
+    );
+}
+
+#[test]
+fn advice_push_keccak256() {
+    use sha3::{Digest, Keccak256};
+
+    // includes an empty message as well as lengths which are not multiples of 4 bytes; 136 bytes
+    // is the keccak-256 rate, so it exercises the padding spilling into an extra block
+    for len in [0_usize, 1, 3, 4, 5, 135, 136, 200] {
+        let message: Vec<u8> = (0..len).map(|i| (i * 7 + 3) as u8).collect();
+
+        // store the message in memory starting at address 100, packing 4 bytes per element
+        let mut source = String::from("begin\n");
+        for (i, chunk) in message.chunks(4).enumerate() {
+            let mut bytes = [0_u8; 4];
+            bytes[..chunk.len()].copy_from_slice(chunk);
+            let element = u32::from_le_bytes(bytes);
+            source.push_str(&format!("    push.{element} mem_store.{}\n", 100 + i));
+        }
+        source.push_str(&format!(
+            "    push.{len} push.100 adv.push_keccak256 drop drop\n    adv_push.8 swapdw dropw dropw\nend"
+        ));
+
+        // adv_push.8 places the first word of the digest deepest on the stack
+        let expected: Vec<u64> = Keccak256::digest(&message)
+            .chunks_exact(4)
+            .rev()
+            .map(|chunk| u32::from_le_bytes(chunk.try_into().unwrap()) as u64)
+            .collect();
+
+        let test = build_test!(&source);
+        test.expect_stack(&expected);
+    }
+}
+
+#[test]
+fn advice_push_keccak256_message_too_long() {
+    // the length of the hashed message is capped at 2^20 bytes
+    let source = "begin push.1048577 push.100 adv.push_keccak256 drop drop end";
+
+    let test = build_test!(source);
+    assert_matches!(
+        test.execute(),
+        Err(ExecutionError::AdviceError {
+            err: AdviceError::MessageTooLong { len: 1048577, max: 1048576 },
+            ..
+        })
+    );
+}
+
+#[test]
+fn advice_push_batch_inv() {
+    // includes a zero element, which must be mapped to zero
+    let values = [3_u64, 0, 7, 1 << 32, Felt::MODULUS - 1, 1];
+
+    // store the values in memory starting at address 200
+    let mut source = String::from("begin\n");
+    for (i, value) in values.iter().enumerate() {
+        source.push_str(&format!("    push.{value} mem_store.{}\n", 200 + i));
+    }
+    source.push_str(&format!("    push.{} push.200 adv.push_batch_inv drop drop\n", values.len()));
+
+    // for each element, check that a * a_inv == 1 when a is nonzero, and that a_inv == 0 otherwise
+    for i in 0..values.len() {
+        source.push_str(&format!(
+            "    mem_load.{} adv_push.1 dup.1 eq.0 if.true assertz drop else mul push.1 assert_eq end\n",
+            200 + i
+        ));
+    }
+    source.push_str("end");
+
+    let test = build_test!(&source);
+    test.expect_stack(&[]);
+}
+
+#[test]
+fn advice_push_batch_inv_too_many_elements() {
+    // the number of inverted elements is capped at 2^16
+    let source = "begin push.65537 push.200 adv.push_batch_inv drop drop end";
+
+    let test = build_test!(source);
+    assert_matches!(
+        test.execute(),
+        Err(ExecutionError::AdviceError {
+            err: AdviceError::TooManyBatchInvElements { count: 65537, max: 65536 },
+            ..
+        })
+    );
+}
+
+#[test]
+fn advice_push_random() {
+    // draw 4 elements from a random coin seeded with the word at the top of the stack and move
+    // them onto the operand stack
+    let source = "begin push.4 push.4.3.2.1 adv.push_random dropw drop adv_push.4 swapw dropw end";
+
+    // the drawn elements are fully determined by the seed, and so must never change
+    let drawn = [
+        12079974661027167602,
+        13304014435322299387,
+        4432869455895064705,
+        8770608282763309215,
+    ];
+
+    // the first drawn element is at the top of the advice stack, and so ends up deepest on the
+    // operand stack
+    let test = build_test!(source);
+    test.expect_stack(&[drawn[3], drawn[2], drawn[1], drawn[0]]);
+}
+
+#[test]
+fn advice_push_random_too_many_elements() {
+    // the number of drawn elements is capped at 2^16
+    let source = "begin push.65537 push.4.3.2.1 adv.push_random dropw drop end";
+
+    let test = build_test!(source);
+    assert_matches!(
+        test.execute(),
+        Err(ExecutionError::AdviceError {
+            err: AdviceError::TooManyRandomElements { count: 65537, max: 65536 },
+            ..
+        })
+    );
+}
@@ -0,0 +1,117 @@
+use std::sync::Arc;
+
+use miden_assembly::{Assembler, DefaultSourceManager};
+use miden_processor::{
+    AdviceInputs, ContextId, ExecutionError, ExecutionOptions, Process, Program,
+    fast::FastProcessor,
+};
+use miden_prover::StackInputs;
+
+use super::TestHost;
+
+#[test]
+fn test_call_boundaries() {
+    let source = "\
+    proc.foo
+        push.1 drop
+        call.bar
+    end
+
+    proc.bar
+        push.2 drop
+    end
+
+    begin
+        call.foo
+        call.bar
+    end";
+
+    // a procedure has the same MAST root as a program consisting of the same instructions
+    let bar = Assembler::default().assemble_program("begin push.2 drop end").unwrap().hash();
+    let foo = {
+        let source = "proc.bar push.2 drop end begin push.1 drop call.bar end";
+        Assembler::default().assemble_program(source).unwrap().hash()
+    };
+
+    let program: Program = Assembler::default().assemble_program(source).unwrap();
+    let mut host = TestHost::default();
+    miden_processor::execute(
+        &program,
+        StackInputs::default(),
+        AdviceInputs::default(),
+        &mut host,
+        ExecutionOptions::default(),
+        Arc::new(DefaultSourceManager::default()),
+    )
+    .unwrap();
+
+    assert_eq!(host.call_enter_handler, vec![foo, bar, bar]);
+    assert_eq!(host.call_exit_handler, vec![bar, foo, bar]);
+
+    // the fast processor reports the same call boundaries
+    let mut fast_host = TestHost::default();
+    FastProcessor::new(&[]).execute_sync(&program, &mut fast_host).unwrap();
+
+    assert_eq!(fast_host.call_enter_handler, host.call_enter_handler);
+    assert_eq!(fast_host.call_exit_handler, host.call_exit_handler);
+}
+
+#[test]
+fn test_call_veto() {
+    let source = "\
+    proc.foo
+        push.1 drop
+    end
+
+    proc.bar
+        push.2 drop
+    end
+
+    begin
+        call.foo
+        call.bar
+        call.foo
+    end";
+
+    let foo = Assembler::default().assemble_program("begin push.1 drop end").unwrap().hash();
+    let bar = Assembler::default().assemble_program("begin push.2 drop end").unwrap().hash();
+
+    let program: Program = Assembler::default().assemble_program(source).unwrap();
+    let mut host = TestHost {
+        vetoed_calls: vec![bar],
+        ..Default::default()
+    };
+    let mut process = Process::new(
+        program.kernel().clone(),
+        StackInputs::default(),
+        AdviceInputs::default(),
+        ExecutionOptions::default(),
+    );
+    let result = process.execute(&program, &mut host);
+
+    assert!(matches!(
+        result,
+        Err(ExecutionError::FailedToExecuteProgram("call vetoed by the host"))
+    ));
+    // the call to `foo` preceding the vetoed call was allowed, and execution halted at the veto
+    assert_eq!(host.call_enter_handler, vec![foo]);
+    assert_eq!(host.call_exit_handler, vec![foo]);
+    // the vetoed call left the execution context of the caller in place
+    let state = process.state();
+    assert_eq!(state.ctx(), ContextId::root());
+    assert_eq!(state.overflow_len(), 0);
+
+    // the fast processor halts at the same call
+    let mut fast_host = TestHost {
+        vetoed_calls: vec![bar],
+        ..Default::default()
+    };
+    let result = FastProcessor::new(&[]).execute_sync(&program, &mut fast_host);
+
+    assert!(matches!(
+        result,
+        Err(ExecutionError::FailedToExecuteProgram("call vetoed by the host"))
+    ));
+    assert_eq!(fast_host.call_enter_handler, host.call_enter_handler);
+    assert_eq!(fast_host.call_exit_handler, host.call_exit_handler);
+}
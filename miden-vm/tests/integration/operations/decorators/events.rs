@@ -1,8 +1,12 @@
 use std::sync::Arc;
 
 use miden_assembly::{Assembler, DefaultSourceManager};
-use miden_processor::{AdviceInputs, ExecutionOptions, Program};
-use miden_prover::StackInputs;
+use miden_core::{Felt, ZERO};
+use miden_processor::{
+    AdviceInputs, BaseHost, ErrorContext, ExecutionError, ExecutionOptions, MastForest,
+    ProcessState, Program, SyncHost,
+};
+use miden_prover::{StackInputs, Word};
 
 use super::TestHost;
 
@@ -35,6 +39,100 @@ fn test_event_handling() {
     assert_eq!(host.event_handler, expected);
 }
 
+#[test]
+fn test_event_handler_reads_memory_word() {
+    let source = "\
+    begin
+        push.1.2.3.4
+        mem_storew.8
+        dropw
+        emit.1
+    end";
+
+    // compile and execute program
+    let program: Program = Assembler::default().assemble_program(source).unwrap();
+    let mut host = MemReadHost::default();
+    miden_processor::execute(
+        &program,
+        StackInputs::default(),
+        AdviceInputs::default(),
+        &mut host,
+        ExecutionOptions::default(),
+        Arc::new(DefaultSourceManager::default()),
+    )
+    .unwrap();
+
+    // the host must observe the word stored by the program, and no word at an unused address
+    let expected = Word::from([1_u32, 2, 3, 4]);
+    assert_eq!(host.words, vec![Some(expected), None]);
+}
+
+#[test]
+fn test_event_handler_reads_stack_top() {
+    let source = "\
+    begin
+        push.1
+        push.2
+        push.3
+        emit.1
+        drop drop drop
+    end";
+
+    // compile and execute program
+    let program: Program = Assembler::default().assemble_program(source).unwrap();
+    let mut host = StackReadHost::default();
+    miden_processor::execute(
+        &program,
+        StackInputs::try_from_ints([7]).unwrap(),
+        AdviceInputs::default(),
+        &mut host,
+        ExecutionOptions::default(),
+        Arc::new(DefaultSourceManager::default()),
+    )
+    .unwrap();
+
+    // the values pushed last are at the top of the stack
+    assert_eq!(host.stack_top, [3_u32, 2, 1, 7].map(Felt::from));
+
+    // requesting more items than the stack contains returns the whole stack
+    assert_eq!(host.stack_depth, 19);
+
+    // the 3 pushed values spilled the bottom of the initial stack into the overflow table
+    assert_eq!(host.overflow_len, 3);
+    assert_eq!(host.overflow, [ZERO; 3]);
+}
+
+#[test]
+fn test_event_handler_reads_overflow_table() {
+    let source = "\
+    begin
+        push.1.2.3.4.5.6.7.8.9
+        push.10.11.12.13.14.15.16.17.18
+        emit.1
+        dropw dropw dropw dropw drop drop
+    end";
+
+    // compile and execute program
+    let program: Program = Assembler::default().assemble_program(source).unwrap();
+    let mut host = StackReadHost::default();
+    miden_processor::execute(
+        &program,
+        StackInputs::default(),
+        AdviceInputs::default(),
+        &mut host,
+        ExecutionOptions::default(),
+        Arc::new(DefaultSourceManager::default()),
+    )
+    .unwrap();
+
+    // 18 values pushed onto the initial 16 zeros leave 18 elements in the overflow table: the
+    // first 2 pushed values directly below the top 16 items, followed by the initial zeros
+    assert_eq!(host.overflow_len, 18);
+    let mut expected = vec![Felt::from(2_u32), Felt::from(1_u32)];
+    expected.resize(18, ZERO);
+    assert_eq!(host.overflow, expected);
+}
+
 #[test]
 fn test_trace_handling() {
     let source = "\
@@ -159,3 +257,67 @@ fn test_parsing_debug_advice_stack() {
     )
     .unwrap();
 }
+
+// MEMORY READING HOST
+// ================================================================================================
+
+/// A host which reads the words at addresses 8 and 12 of the current context on every event.
+#[derive(Debug, Default)]
+struct MemReadHost {
+    words: Vec<Option<Word>>,
+}
+
+impl BaseHost for MemReadHost {}
+
+impl SyncHost for MemReadHost {
+    fn get_mast_forest(&self, _node_digest: &Word) -> Option<Arc<MastForest>> {
+        None
+    }
+
+    fn on_event(
+        &mut self,
+        process: &mut ProcessState,
+        _event_id: u32,
+        _err_ctx: &impl ErrorContext,
+    ) -> Result<(), ExecutionError> {
+        let ctx = process.ctx();
+        for addr in [8, 12] {
+            let word = process.get_mem_word(ctx, addr).map_err(ExecutionError::MemoryError)?;
+            self.words.push(word);
+        }
+        Ok(())
+    }
+}
+
+// STACK READING HOST
+// ================================================================================================
+
+/// A host which reads the top of the operand stack and the overflow table on every event.
+#[derive(Debug, Default)]
+struct StackReadHost {
+    stack_top: Vec<Felt>,
+    stack_depth: usize,
+    overflow: Vec<Felt>,
+    overflow_len: usize,
+}
+
+impl BaseHost for StackReadHost {}
+
+impl SyncHost for StackReadHost {
+    fn get_mast_forest(&self, _node_digest: &Word) -> Option<Arc<MastForest>> {
+        None
+    }
+
+    fn on_event(
+        &mut self,
+        process: &mut ProcessState,
+        _event_id: u32,
+        _err_ctx: &impl ErrorContext,
+    ) -> Result<(), ExecutionError> {
+        self.stack_top = process.get_stack_top(4);
+        self.stack_depth = process.get_stack_top(usize::MAX).len();
+        self.overflow = process.overflow_elements();
+        self.overflow_len = process.overflow_len();
+        Ok(())
+    }
+}
@@ -8,6 +8,7 @@ use miden_prover::Word;
 
 mod advice;
 mod asmop;
+mod calls;
 mod events;
 
 // TEST HOST
@@ -17,6 +18,9 @@ pub struct TestHost {
     pub event_handler: Vec<u32>,
     pub trace_handler: Vec<u32>,
     pub debug_handler: Vec<String>,
+    pub call_enter_handler: Vec<Word>,
+    pub call_exit_handler: Vec<Word>,
+    pub vetoed_calls: Vec<Word>,
 }
 
 impl BaseHost for TestHost {
@@ -37,6 +41,22 @@ impl BaseHost for TestHost {
         self.trace_handler.push(trace_id);
         Ok(())
     }
+
+    fn on_call_enter(
+        &mut self,
+        _process: &mut ProcessState,
+        callee: Word,
+    ) -> Result<(), ExecutionError> {
+        if self.vetoed_calls.contains(&callee) {
+            return Err(ExecutionError::FailedToExecuteProgram("call vetoed by the host"));
+        }
+        self.call_enter_handler.push(callee);
+        Ok(())
+    }
+
+    fn on_call_exit(&mut self, _process: &mut ProcessState, callee: Word) {
+        self.call_exit_handler.push(callee);
+    }
 }
 
 impl SyncHost for TestHost {
@@ -642,6 +642,26 @@ fn gte() {
     test_felt_comparison_op("gte", 0, 1, 1);
 }
 
+#[test]
+fn is_odd() {
+    let asm_op = "is_odd";
+
+    for value in [0, 1, 2, 7, 10, u32::MAX as u64, 1 << 32, (1 << 32) + 1, Felt::MODULUS - 1] {
+        let test = build_op_test!(asm_op, &[value]);
+        test.expect_stack(&[value % 2]);
+    }
+}
+
+#[test]
+fn is_even() {
+    let asm_op = "is_even";
+
+    for value in [0, 1, 2, 7, 10, u32::MAX as u64, 1 << 32, (1 << 32) + 1, Felt::MODULUS - 1] {
+        let test = build_op_test!(asm_op, &[value]);
+        test.expect_stack(&[1 - value % 2]);
+    }
+}
+
 // FIELD OPS ARITHMETIC - RANDOMIZED TESTS
 // ================================================================================================
 
@@ -1,5 +1,5 @@
 use miden_core::{Felt, chiplets::hasher::apply_permutation, utils::ToElements};
-use miden_processor::{AdviceError, ExecutionError, RowIndex};
+use miden_processor::{ContextId, ExecutionError, RowIndex};
 use miden_utils_testing::expect_exec_error_matches;
 
 use super::{TRUNCATE_STACK_PROC, build_op_test, build_test};
@@ -34,12 +34,31 @@ fn adv_push_invalid() {
     let test = build_op_test!("adv_push.1");
     expect_exec_error_matches!(
         test,
-        ExecutionError::AdviceError {
-            err: AdviceError::StackReadFailed, clk, ..
-        } if clk == RowIndex::from(2),
+        ExecutionError::AdviceStackReadFailed { clk, ctx, .. }
+            if clk == RowIndex::from(2) && ctx == ContextId::root(),
     )
 }
 
+#[test]
+fn adv_push_invalid_in_call() {
+    // the error must report the clock cycle of the failing pop and the context it was executed in
+    let source = "
+        proc.foo
+            push.1 drop adv_push.1
+        end
+
+        begin
+            call.foo
+        end";
+
+    let test = build_test!(source);
+    expect_exec_error_matches!(
+        test,
+        ExecutionError::AdviceStackReadFailed { clk, ctx, .. }
+            if clk == RowIndex::from(5) && ctx == ContextId::from(1_u32),
+    );
+}
+
 // OVERWRITING VALUES ON THE STACK (LOAD)
 // ================================================================================================
 
@@ -60,9 +79,8 @@ fn adv_loadw_invalid() {
     let test = build_op_test!("adv_loadw", &[0, 0, 0, 0]);
     expect_exec_error_matches!(
         test,
-        ExecutionError::AdviceError {
-            err: AdviceError::StackReadFailed, clk, ..
-        } if clk == RowIndex::from(2),
+        ExecutionError::AdviceStackReadFailed { clk, ctx, .. }
+            if clk == RowIndex::from(2) && ctx == ContextId::root(),
     );
 }
 
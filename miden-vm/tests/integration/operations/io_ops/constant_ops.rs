@@ -76,3 +76,14 @@ fn push_without_separator() {
     let test = build_op_test!(asm_op);
     test.expect_stack(&expected);
 }
+
+#[test]
+fn push_string() {
+    // --- the bytes of the string are pushed in order, so the last byte ends up on top ---------
+    let test = build_op_test!(r#"push."abc""#);
+    test.expect_stack(&[b'c' as u64, b'b' as u64, b'a' as u64]);
+
+    // --- escape sequences are replaced by the characters they denote ----------------------------
+    let test = build_op_test!(r#"push."\n\t\\\"""#);
+    test.expect_stack(&[b'"' as u64, b'\\' as u64, b'\t' as u64, b'\n' as u64]);
+}
@@ -1,3 +1,6 @@
+use miden_assembly::testing::regex;
+use miden_utils_testing::{assert_assembler_diagnostic, assert_diagnostic_lines};
+
 use super::{Felt, TRUNCATE_STACK_PROC, ToElements, apply_permutation, build_op_test, build_test};
 
 // LOADING SINGLE ELEMENT ONTO THE STACK (MLOAD)
@@ -170,6 +173,170 @@ fn mem_stream_with_hperm() {
     test.expect_stack(&final_stack);
 }
 
+#[test]
+fn mem_stream_n() {
+    let addr = 8_u64;
+
+    // --- read 4 words: [a, ...] -> [a + 16, W_3, W_2, W_1, W_0, ...] ---------------------------
+    let test = build_test!(&mem_stream_n_source(addr, 4, ""));
+    test.expect_stack(&[addr + 16, 16, 15, 14, 13, 12, 11, 10, 9, 8, 7, 6, 5, 4, 3, 2]);
+
+    let test = build_test!(&mem_stream_n_source(addr, 4, "drop dropw"));
+    test.expect_stack(&[12, 11, 10, 9, 8, 7, 6, 5, 4, 3, 2, 1]);
+
+    // --- read 8 words: [a, ...] -> [a + 32, W_7, ..., W_0, ...] --------------------------------
+    let test = build_test!(&mem_stream_n_source(addr, 8, ""));
+    test.expect_stack(&[addr + 32, 32, 31, 30, 29, 28, 27, 26, 25, 24, 23, 22, 21, 20, 19, 18]);
+
+    let test = build_test!(&mem_stream_n_source(addr, 8, "drop dropw dropw dropw dropw"));
+    test.expect_stack(&[16, 15, 14, 13, 12, 11, 10, 9, 8, 7, 6, 5, 4, 3, 2, 1]);
+
+    // --- an odd number of words is read correctly -----------------------------------------------
+    let test = build_test!(&mem_stream_n_source(addr, 3, ""));
+    test.expect_stack(&[addr + 12, 12, 11, 10, 9, 8, 7, 6, 5, 4, 3, 2, 1]);
+}
+
+#[test]
+fn mem_stream_n_fail() {
+    // reading 9 words would push 36 elements onto the stack, which exceeds the limit
+    let test = build_test!("begin push.0 mem_stream.9 end");
+
+    assert_assembler_diagnostic!(
+        test,
+        "invalid argument",
+        regex!(r#",-\[test[\d]+:[\d]+:[\d]+\]"#),
+        "1 | begin push.0 mem_stream.9 end",
+        "  :                         |",
+        "  :                         `-- this instruction argument is out of range",
+        "  `----",
+        " help: value must be in the range 1..=8"
+    );
+
+    let test = build_test!("begin push.0 mem_stream.0 end");
+
+    assert_assembler_diagnostic!(
+        test,
+        "invalid argument",
+        regex!(r#",-\[test[\d]+:[\d]+:[\d]+\]"#),
+        "1 | begin push.0 mem_stream.0 end",
+        "  :                         |",
+        "  :                         `-- this instruction argument is out of range",
+        "  `----",
+        " help: value must be in the range 1..=8"
+    );
+}
+
+/// Returns a program which stores `num_words` words with values `[4i + 1, 4i + 2, 4i + 3, 4i + 4]`
+/// at consecutive word addresses starting at `addr`, reads them back with `mem_stream.<num_words>`,
+/// and then executes `epilogue`.
+fn mem_stream_n_source(addr: u64, num_words: u64, epilogue: &str) -> String {
+    let stores = store_words_source(addr, num_words);
+
+    format!(
+        "
+        {TRUNCATE_STACK_PROC}
+
+        begin
+            {stores}
+            push.{addr}
+            mem_stream.{num_words}
+            {epilogue}
+            exec.truncate_stack
+        end"
+    )
+}
+
+// COPYING WORDS WITHIN MEMORY
+// ================================================================================================
+
+#[test]
+fn mem_copy() {
+    // --- non-overlapping regions, the rest of the stack is unchanged ----------------------------
+    let test = build_test!(&mem_copy_source(0, 3, "push.3.40.0 mem_copy", 40, 3), &[99]);
+    test.expect_stack(&[12, 11, 10, 9, 8, 7, 6, 5, 4, 3, 2, 1, 99]);
+
+    // --- the number of words provided as an immediate value -------------------------------------
+    let test = build_test!(&mem_copy_source(0, 3, "push.40.0 mem_copy.3", 40, 3), &[99]);
+    test.expect_stack(&[12, 11, 10, 9, 8, 7, 6, 5, 4, 3, 2, 1, 99]);
+
+    // --- the addresses and the number of words provided as immediate values ---------------------
+    let test = build_test!(&mem_copy_source(0, 3, "mem_copy.0.40.3", 40, 3), &[99]);
+    test.expect_stack(&[12, 11, 10, 9, 8, 7, 6, 5, 4, 3, 2, 1, 99]);
+
+    // --- the source region is left unchanged ----------------------------------------------------
+    let test = build_test!(&mem_copy_source(0, 3, "push.40.0 mem_copy.3", 0, 3), &[99]);
+    test.expect_stack(&[12, 11, 10, 9, 8, 7, 6, 5, 4, 3, 2, 1, 99]);
+
+    // --- overlapping regions with the destination after the source ------------------------------
+    let test = build_test!(&mem_copy_source(0, 4, "push.4.8.0 mem_copy", 8, 4));
+    test.expect_stack(&[16, 15, 14, 13, 12, 11, 10, 9, 8, 7, 6, 5, 4, 3, 2, 1]);
+
+    // --- overlapping regions with the destination before the source -----------------------------
+    let test = build_test!(&mem_copy_source(8, 4, "push.4.0.8 mem_copy", 0, 4));
+    test.expect_stack(&[16, 15, 14, 13, 12, 11, 10, 9, 8, 7, 6, 5, 4, 3, 2, 1]);
+
+    let test = build_test!(&mem_copy_source(8, 4, "mem_copy.8.0.4", 0, 4));
+    test.expect_stack(&[16, 15, 14, 13, 12, 11, 10, 9, 8, 7, 6, 5, 4, 3, 2, 1]);
+
+    // --- copying zero words leaves memory unchanged ---------------------------------------------
+    let test = build_test!(&mem_copy_source(0, 1, "push.0.40.0 mem_copy", 40, 1), &[99]);
+    test.expect_stack(&[0, 0, 0, 0, 99]);
+}
+
+#[test]
+fn mem_copy_fail() {
+    let test = build_test!("begin push.40.0 mem_copy.0 end");
+
+    assert_assembler_diagnostic!(
+        test,
+        "invalid argument",
+        regex!(r#",-\[test[\d]+:[\d]+:[\d]+\]"#),
+        "1 | begin push.40.0 mem_copy.0 end",
+        "  :                          |",
+        "  :                          `-- this instruction argument is out of range",
+        "  `----",
+        " help: value must be in the range 1..=1073741823"
+    );
+}
+
+/// Returns a program which stores `num_words` words at `src` (see [`store_words_source`]),
+/// executes `copy`, and then reads `read_words` words starting at `read_addr` onto the stack.
+fn mem_copy_source(
+    src: u64,
+    num_words: u64,
+    copy: &str,
+    read_addr: u64,
+    read_words: u64,
+) -> String {
+    let stores = store_words_source(src, num_words);
+
+    format!(
+        "
+        {TRUNCATE_STACK_PROC}
+
+        begin
+            {stores}
+            {copy}
+            push.{read_addr}
+            mem_stream.{read_words}
+            drop
+            exec.truncate_stack
+        end"
+    )
+}
+
+/// Returns the instructions which store `num_words` words with values
+/// `[4i + 1, 4i + 2, 4i + 3, 4i + 4]` at consecutive word addresses starting at `addr`.
+fn store_words_source(addr: u64, num_words: u64) -> String {
+    (0..num_words)
+        .map(|i| {
+            let values = (1..=4).map(|j| (4 * i + j).to_string()).collect::<Vec<_>>().join(".");
+            format!("push.{values} mem_storew.{} dropw", addr + 4 * i)
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
 // PAIRED OPERATIONS
 // ================================================================================================
 
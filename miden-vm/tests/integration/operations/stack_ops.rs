@@ -1,7 +1,8 @@
 use miden_assembly::testing::regex;
+use miden_processor::{ExecutionError, ZERO};
 use miden_utils_testing::{
     MIN_STACK_DEPTH, WORD_SIZE, assert_assembler_diagnostic, assert_diagnostic_lines,
-    build_op_test, proptest::prelude::*,
+    build_op_test, expect_exec_error_matches, proptest::prelude::*,
 };
 
 // STACK OPERATIONS TESTS
@@ -432,6 +433,24 @@ fn cdropw() {
     test.expect_stack(&[1, 2, 3, 4, 9, 10, 11, 12, 13, 14, 15, 0, 0, 0, 0, 0]);
 }
 
+#[test]
+fn select() {
+    let asm_op = "select";
+    // --- simple cases ----------------------------------------------------------------------------
+    let test = build_op_test!(asm_op, &[15, 14, 13, 12, 11, 10, 9, 8, 7, 6, 5, 4, 3, 2, 1, 0]);
+    test.expect_stack(&[2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 0, 0]);
+
+    let test = build_op_test!(asm_op, &[15, 14, 13, 12, 11, 10, 9, 8, 7, 6, 5, 4, 3, 2, 1, 1]);
+    test.expect_stack(&[1, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 0, 0]);
+
+    // --- the selector must be binary -------------------------------------------------------------
+    let test = build_op_test!(asm_op, &[2, 1, 2]);
+    expect_exec_error_matches!(
+        test,
+        ExecutionError::FailedAssertion{ err_code, .. } if err_code == ZERO
+    );
+}
+
 proptest! {
 
     #[test]
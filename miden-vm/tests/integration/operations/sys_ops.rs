@@ -1,6 +1,6 @@
 use miden_core::mast;
 use miden_processor::{ExecutionError, RowIndex, ZERO};
-use miden_utils_testing::{build_op_test, expect_exec_error_matches};
+use miden_utils_testing::{Felt, StarkField, build_op_test, expect_exec_error_matches};
 
 // SYSTEM OPS ASSERTIONS - MANUAL TESTS
 // ================================================================================================
@@ -77,6 +77,96 @@ fn assert_eq_fail() {
     );
 }
 
+#[test]
+fn assert_eqw() {
+    let asm_op = "assert_eqw";
+
+    let test = build_op_test!(asm_op, &[1, 2, 3, 4, 1, 2, 3, 4]);
+    test.expect_stack(&[]);
+}
+
+#[test]
+fn assert_eqw_fail() {
+    let asm_op = "assert_eqw.err=\"words differ\"";
+    let code = mast::error_code_from_msg("words differ");
+
+    // words differ in every element, so the first comparison fails
+    let test = build_op_test!(asm_op, &[1, 2, 3, 4, 5, 6, 7, 8]);
+    expect_exec_error_matches!(
+        test,
+        ExecutionError::FailedAssertion{ err_code, err_msg, .. }
+        if err_code == code && err_msg.as_deref() == Some("words differ")
+    );
+
+    // words differ only in their last element, so the last comparison fails
+    let test = build_op_test!(asm_op, &[1, 2, 3, 4, 5, 2, 3, 4]);
+    expect_exec_error_matches!(
+        test,
+        ExecutionError::FailedAssertion{ err_code, .. }
+        if err_code == code
+    );
+}
+
+#[test]
+fn assert_lt() {
+    // --- small bound ----------------------------------------------------------------------------
+    let asm_op = "assert_lt.10";
+    build_op_test!(asm_op, &[0]).expect_stack(&[]);
+    build_op_test!(asm_op, &[9]).expect_stack(&[]);
+
+    // --- bounds which do not fit into u32 -------------------------------------------------------
+    let bound = 1_u64 << 32;
+    let asm_op = format!("assert_lt.{bound}");
+    build_op_test!(&asm_op, &[bound - 1]).expect_stack(&[]);
+
+    // the low 32 bits of the value are larger than those of the bound, but the high ones are not
+    let bound = (1_u64 << 33) + 5;
+    let asm_op = format!("assert_lt.{bound}");
+    build_op_test!(&asm_op, &[(1_u64 << 32) + 6]).expect_stack(&[]);
+    build_op_test!(&asm_op, &[bound - 1]).expect_stack(&[]);
+
+    let bound = Felt::MODULUS - 1;
+    let asm_op = format!("assert_lt.{bound}");
+    build_op_test!(&asm_op, &[bound - 1]).expect_stack(&[]);
+
+    // --- test that the rest of the stack isn't affected -----------------------------------------
+    build_op_test!("assert_lt.10", &[3, 9]).expect_stack(&[3]);
+}
+
+#[test]
+fn assert_lt_fail() {
+    for (bound, value) in [
+        (10, 10),
+        (10, 11),
+        (0, 0),
+        (1_u64 << 32, 1_u64 << 32),
+        ((1_u64 << 33) + 5, (1_u64 << 33) + 5),
+        ((1_u64 << 33) + 5, 1_u64 << 34),
+        (Felt::MODULUS - 1, Felt::MODULUS - 1),
+    ] {
+        let asm_op = format!("assert_lt.{bound}");
+        let test = build_op_test!(&asm_op, &[value]);
+        expect_exec_error_matches!(
+            test,
+            ExecutionError::FailedAssertion{ clk, err_code, err_msg, label: _, source_file: _ }
+            if clk == RowIndex::from(17) && err_code == ZERO && err_msg.is_none()
+        );
+    }
+
+    // the error code is attached to the failed assertion
+    let asm_op = "assert_lt.10.err=\"out of range\"";
+    let code = mast::error_code_from_msg("out of range");
+
+    build_op_test!(asm_op, &[9]).expect_stack(&[]);
+
+    let test = build_op_test!(asm_op, &[10]);
+    expect_exec_error_matches!(
+        test,
+        ExecutionError::FailedAssertion{ err_code, err_msg, .. }
+        if err_code == code && err_msg.as_deref() == Some("out of range")
+    );
+}
+
 // EMITTING EVENTS
 // ================================================================================================
 
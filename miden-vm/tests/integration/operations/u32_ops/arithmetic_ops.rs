@@ -1,3 +1,4 @@
+use miden_core::{Felt, ZERO};
 use miden_processor::{ExecutionError, RowIndex};
 use miden_utils_testing::{
     U32_BOUND, build_op_test, expect_exec_error_matches, proptest::prelude::*, rand::rand_value,
@@ -402,6 +403,76 @@ fn u32overflowing_madd() {
     test.expect_stack(&[e, d, f]);
 }
 
+#[test]
+fn u32overflowing_madd_fail() {
+    let asm_op = "u32overflowing_madd";
+
+    // should report the first multiplicand if it is not a u32 value
+    let test = build_op_test!(asm_op, &[0, 0, U32_BOUND]);
+    expect_exec_error_matches!(
+        test,
+        ExecutionError::NotU32Value{ value, err_code, .. }
+        if value == Felt::new(U32_BOUND) && err_code == ZERO
+    );
+
+    // should report the second multiplicand if it is not a u32 value
+    let test = build_op_test!(asm_op, &[0, U32_BOUND, 0]);
+    expect_exec_error_matches!(
+        test,
+        ExecutionError::NotU32Value{ value, err_code, .. }
+        if value == Felt::new(U32_BOUND) && err_code == ZERO
+    );
+}
+
+#[test]
+fn u32checked_madd() {
+    // --- simple cases ---------------------------------------------------------------------------
+    let test = build_op_test!("u32checked_madd.3.4", &[5]);
+    test.expect_stack(&[19]);
+
+    // immediates which are lowered to PAD and PAD INCR
+    let test = build_op_test!("u32checked_madd.1.0", &[5]);
+    test.expect_stack(&[5]);
+
+    let test = build_op_test!("u32checked_madd.0.1", &[5]);
+    test.expect_stack(&[1]);
+
+    // --- result equal to the largest u32 value --------------------------------------------------
+    let test = build_op_test!("u32checked_madd.2.1", &[(u32::MAX / 2) as u64]);
+    test.expect_stack(&[u32::MAX as u64]);
+
+    // --- random u32 values ----------------------------------------------------------------------
+    let a = rand_value::<u16>() as u32;
+    let b = rand_value::<u16>() as u32;
+    let x = rand_value::<u16>() as u64;
+    let asm_op = format!("u32checked_madd.{a}.{b}");
+    let test = build_op_test!(&asm_op, &[x]);
+    test.expect_stack(&[x * a as u64 + b as u64]);
+
+    // --- test that the rest of the stack isn't affected -----------------------------------------
+    let c = rand_value::<u64>();
+    let test = build_op_test!(&asm_op, &[c, x]);
+    test.expect_stack(&[x * a as u64 + b as u64, c]);
+}
+
+#[test]
+fn u32checked_madd_fail() {
+    // should fail if the result does not fit into 32 bits
+    let test = build_op_test!("u32checked_madd.2.2", &[(u32::MAX / 2) as u64]);
+    expect_exec_error_matches!(
+        test,
+        ExecutionError::FailedAssertion{ err_code, .. } if err_code == ZERO
+    );
+
+    // should fail if the value on top of the stack is not a u32 value
+    let test = build_op_test!("u32checked_madd.2.2", &[U32_BOUND]);
+    expect_exec_error_matches!(
+        test,
+        ExecutionError::NotU32Value{ value, err_code, .. }
+        if value == Felt::new(U32_BOUND) && err_code == ZERO
+    );
+}
+
 #[test]
 fn u32div() {
     // --- simple cases ---------------------------------------------------------------------------
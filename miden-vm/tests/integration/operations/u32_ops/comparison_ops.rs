@@ -53,6 +53,26 @@ fn u32max() {
     test_max(asm_op);
 }
 
+#[test]
+fn i32lt() {
+    test_signed_comparison_op("i32lt", |a, b| a < b);
+}
+
+#[test]
+fn i32lte() {
+    test_signed_comparison_op("i32lte", |a, b| a <= b);
+}
+
+#[test]
+fn i32gt() {
+    test_signed_comparison_op("i32gt", |a, b| a > b);
+}
+
+#[test]
+fn i32gte() {
+    test_signed_comparison_op("i32gte", |a, b| a >= b);
+}
+
 // U32 OPERATIONS TESTS - RANDOMIZED - COMPARISON OPERATIONS
 // ================================================================================================
 
@@ -126,6 +146,14 @@ proptest! {
         let test = build_op_test!(&asm_op, &[a as u64, b as u64]);
         test.prop_expect_stack(&[expected as u64])?;
     }
+    #[test]
+    fn i32_comparison_proptest(a in any::<i32>(), b in any::<i32>()) {
+        let inputs = [a as u32 as u64, b as u32 as u64];
+        for (asm_op, expected) in [("i32lt", a < b), ("i32lte", a <= b), ("i32gt", a > b), ("i32gte", a >= b)] {
+            let test = build_op_test!(asm_op, &inputs);
+            test.prop_expect_stack(&[expected as u64])?;
+        }
+    }
 }
 
 // HELPER FUNCTIONS
@@ -291,3 +319,28 @@ fn test_max(asm_op: &str) {
     let test = build_op_test!(asm_op_imm, &[c, a as u64]);
     test.expect_stack(&[expected as u64, c]);
 }
+
+/// This helper function tests that the provided signed comparison operation, which interprets its
+/// inputs as 32-bit integers in two's complement form, agrees with the provided comparison on
+/// `i32` values, including pairs which straddle zero.
+fn test_signed_comparison_op(asm_op: &str, expected: fn(i32, i32) -> bool) {
+    let pairs = [
+        (-1, 1),
+        (1, -1),
+        (-5, -3),
+        (-3, -5),
+        (0, -1),
+        (-1, 0),
+        (0, 0),
+        (-7, -7),
+        (i32::MIN, i32::MAX),
+        (i32::MAX, i32::MIN),
+        (i32::MIN, 0),
+        (i32::MIN, i32::MIN),
+    ];
+
+    for (a, b) in pairs {
+        let test = build_op_test!(asm_op, &[a as u32 as u64, b as u32 as u64]);
+        test.expect_stack(&[expected(a, b) as u64]);
+    }
+}
@@ -16,9 +16,23 @@
 //!     - `MAGIC_LIBRARY` if the artifact is a `Library`
 //!   - If `Program`:
 //!     - `program` (`Program`)
+//!
+//!       The serialization format of `Program` has its own header:
+//!       - `MAGIC` (`b"PROG\0"`)
+//!       - `VERSION` (`[u8; 3]`)
+//!       - `mast_forest` (`MastForest`)
+//!       - `kernel` (`Kernel`)
+//!       - `entrypoint` (`u32`)
 //!   - If `Library`:
 //!     - `library` (`Library`)
 //!
+//!       The serialization format of `Library` has its own header:
+//!       - `MAGIC` (`b"MASL\0"`)
+//!       - `VERSION` (`[u8; 3]`)
+//!       - `mast_forest` (`MastForest`)
+//!       - `exports` (names and MAST node IDs of the exported procedures)
+//!       - `output_arities` (names and declared output arities of the exported procedures)
+//!
 //! (Package Manifest)
 //! - `manifest` (`PackageManifest`)
 //!
@@ -58,8 +72,14 @@ const MAGIC_LIBRARY: &[u8; 4] = b"LIB\0";
 
 /// The format version.
 ///
-/// If future modifications are made to this format, the version should be incremented by 1.
-const VERSION: [u8; 3] = [0, 0, 0];
+/// If future modifications are made to this format, the version should be incremented by 1. This
+/// includes modifications to the formats of the embedded [`Program`] and [`Library`].
+///
+/// Version history:
+/// - `[0, 0, 1]`: the embedded [`Program`] starts with its own magic string and version.
+/// - `[0, 0, 2]`: the embedded [`Library`] starts with its own magic string and version, and
+///   records the declared output arities of its exports.
+const VERSION: [u8; 3] = [0, 0, 2];
 
 // PACKAGE SERIALIZATION/DESERIALIZATION
 // ================================================================================================
@@ -212,3 +232,65 @@ impl Deserializable for PackageExport {
         Ok(Self { name, digest })
     }
 }
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use alloc::{string::ToString, vec};
+
+    use miden_core::{Kernel, Operation, mast::MastForest};
+
+    use super::*;
+
+    fn test_package() -> Package {
+        let mut mast_forest = MastForest::new();
+        let entrypoint =
+            mast_forest.add_block(vec![Operation::Pad, Operation::Incr], None).unwrap();
+        mast_forest.make_root(entrypoint);
+        let program = Program::with_kernel(Arc::new(mast_forest), entrypoint, Kernel::default());
+
+        Package {
+            name: "test".to_string(),
+            mast: MastArtifact::Executable(Arc::new(program)),
+            manifest: PackageManifest::default(),
+            account_component_metadata_bytes: None,
+        }
+    }
+
+    #[test]
+    fn package_serialization_round_trip() {
+        let package = test_package();
+        let bytes = package.to_bytes();
+
+        assert_eq!(&bytes[..5], MAGIC_PACKAGE);
+        assert_eq!(&bytes[5..8], &VERSION);
+        assert_eq!(Package::read_from_bytes(&bytes).unwrap(), package);
+    }
+
+    #[test]
+    fn package_deserialization_rejects_old_layout() {
+        // build a package in the layout of version [0, 0, 0], in which the program was not
+        // preceded by its own magic string and version
+        let package = test_package();
+        let program = package.unwrap_program();
+
+        let mut bytes = Vec::new();
+        bytes.write_bytes(MAGIC_PACKAGE);
+        bytes.write_bytes(&[0, 0, 0]);
+        package.name.write_into(&mut bytes);
+        bytes.write_bytes(MAGIC_PROGRAM);
+        program.mast_forest().write_into(&mut bytes);
+        program.kernel().write_into(&mut bytes);
+        bytes.write_u32(program.entrypoint().as_u32());
+        package.manifest.write_into(&mut bytes);
+        package.account_component_metadata_bytes.write_into(&mut bytes);
+
+        let err = Package::read_from_bytes(&bytes).unwrap_err();
+        assert!(
+            matches!(&err, DeserializationError::InvalidValue(msg) if msg.contains("unsupported version")),
+            "unexpected error: {err:?}"
+        );
+    }
+}
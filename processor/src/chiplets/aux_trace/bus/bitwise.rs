@@ -2,11 +2,13 @@ use core::fmt::{Display, Formatter, Result as FmtResult};
 
 use miden_air::{
     RowIndex,
-    trace::{chiplets::bitwise::OP_CYCLE_LEN as BITWISE_OP_CYCLE_LEN, main_trace::MainTrace},
+    trace::{
+        chiplets::{bitwise::OP_CYCLE_LEN as BITWISE_OP_CYCLE_LEN, utils::get_op_label},
+        main_trace::MainTrace,
+    },
 };
 use miden_core::{Felt, FieldElement, ONE, ZERO};
 
-use super::get_op_label;
 use crate::{
     chiplets::aux_trace::build_value,
     debug::{BusDebugger, BusMessage},
@@ -9,6 +9,7 @@ use miden_air::{
                 HASH_CYCLE_LEN, LINEAR_HASH_LABEL, MP_VERIFY_LABEL, MR_UPDATE_NEW_LABEL,
                 MR_UPDATE_OLD_LABEL, NUM_ROUNDS, RETURN_HASH_LABEL, RETURN_STATE_LABEL,
             },
+            utils::get_op_label,
         },
         main_trace::MainTrace,
     },
@@ -18,7 +19,6 @@ use miden_core::{
     utils::range,
 };
 
-use super::get_op_label;
 use crate::{
     chiplets::aux_trace::build_value,
     debug::{BusDebugger, BusMessage},
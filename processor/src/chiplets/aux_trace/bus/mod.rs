@@ -247,12 +247,3 @@ where
 
     combined_value
 }
-
-// HELPER FUNCTIONS
-// ================================================================================================
-
-/// Returns the operation unique label.
-#[inline(always)]
-fn get_op_label(s0: Felt, s1: Felt, s2: Felt, s3: Felt) -> Felt {
-    s3.mul_small(1 << 3) + s2.mul_small(1 << 2) + s1.mul_small(2) + s0 + ONE
-}
@@ -79,10 +79,9 @@ impl KernelRom {
         err_ctx: &impl ErrorContext,
     ) -> Result<(), ExecutionError> {
         let proc_hash_bytes: ProcHashBytes = proc_hash.into();
-        let access_info = self
-            .access_map
-            .get_mut(&proc_hash_bytes)
-            .ok_or(ExecutionError::syscall_target_not_in_kernel(proc_hash, err_ctx))?;
+        let access_info = self.access_map.get_mut(&proc_hash_bytes).ok_or_else(|| {
+            ExecutionError::syscall_target_not_in_kernel(proc_hash, &self.kernel, err_ctx)
+        })?;
 
         self.trace_len += 1;
         access_info.num_accesses += 1;
@@ -119,6 +118,16 @@ impl KernelRom {
     pub const fn kernel(&self) -> &Kernel {
         &self.kernel
     }
+
+    /// Returns the number of times each procedure of the kernel has been accessed so far.
+    ///
+    /// Procedures which have not been accessed are included with the count of 0.
+    pub fn access_counts(&self) -> BTreeMap<Digest, usize> {
+        self.access_map
+            .values()
+            .map(|access_info| (access_info.proc_hash, access_info.num_accesses))
+            .collect()
+    }
 }
 
 // PROCEDURE ACCESS INFO
@@ -150,6 +150,34 @@ impl Memory {
         }
     }
 
+    /// Returns the number of times each address has been accessed, counting both reads and
+    /// writes.
+    ///
+    /// An element access is attributed to the address of the element, while a word access is
+    /// attributed to the address at which the word starts. Addresses which have never been
+    /// accessed are not included.
+    ///
+    /// Addresses are keyed by [MemoryAddress] rather than by [Felt], since field elements are not
+    /// ordered and so cannot be used as [BTreeMap] keys. Any accessed address is a valid u32
+    /// value, and so `Felt::from(u32::from(addr))` recovers the address as a field element.
+    pub fn access_histogram(&self) -> BTreeMap<(ContextId, MemoryAddress), usize> {
+        let mut histogram = BTreeMap::new();
+        for (&ctx, segment) in self.trace.iter() {
+            for (&word_addr, addr_trace) in segment.inner().iter() {
+                for memory_access in addr_trace {
+                    let addr = match memory_access.access_type() {
+                        segment::MemoryAccessType::Element { addr_idx_in_word } => {
+                            word_addr + addr_idx_in_word as u32
+                        },
+                        segment::MemoryAccessType::Word => word_addr,
+                    };
+                    *histogram.entry((ctx, MemoryAddress(addr))).or_default() += 1;
+                }
+            }
+        }
+        histogram
+    }
+
     // STATE MUTATORS
     // --------------------------------------------------------------------------------------------
 
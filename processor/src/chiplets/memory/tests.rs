@@ -477,6 +477,37 @@ fn mem_get_state_at() {
     assert_eq!(mem.get_state_at(3.into(), clk), vec![]);
 }
 
+#[test]
+fn mem_access_histogram() {
+    let mut mem = Memory::default();
+    let ctx = ContextId::from(3);
+    let word1234 = Word::from([1_u32, 2, 3, 4]);
+
+    // address 5 is written once and read twice in the root context
+    mem.write(ContextId::root(), 5_u32.into(), 1.into(), ONE, &()).unwrap();
+    mem.read(ContextId::root(), 5_u32.into(), 2.into(), &()).unwrap();
+    mem.read(ContextId::root(), 5_u32.into(), 3.into(), &()).unwrap();
+
+    // address 6 is in the same word as address 5, and is read once
+    mem.read(ContextId::root(), 6_u32.into(), 4.into(), &()).unwrap();
+
+    // the word at address 8 is written and read once
+    mem.write_word(ContextId::root(), 8_u32.into(), 5.into(), word1234, &())
+        .unwrap();
+    mem.read_word(ContextId::root(), 8_u32.into(), 6.into(), &()).unwrap();
+
+    // address 5 is read once in another context
+    mem.read(ctx, 5_u32.into(), 7.into(), &()).unwrap();
+
+    let histogram = mem.access_histogram();
+    assert_eq!(histogram.len(), 4);
+    assert_eq!(histogram[&(ContextId::root(), MemoryAddress(5))], 3);
+    assert_eq!(histogram[&(ContextId::root(), MemoryAddress(6))], 1);
+    assert_eq!(histogram[&(ContextId::root(), MemoryAddress(8))], 2);
+    assert_eq!(histogram[&(ctx, MemoryAddress(5))], 1);
+    assert_eq!(histogram.values().sum::<usize>(), mem.trace_len());
+}
+
 // HELPER STRUCT & FUNCTIONS
 // ================================================================================================
 
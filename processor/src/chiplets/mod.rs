@@ -1,4 +1,4 @@
-use alloc::vec::Vec;
+use alloc::{collections::BTreeMap, vec::Vec};
 
 use miden_air::{RowIndex, trace::chiplets::hasher::HasherState};
 use miden_core::{Kernel, mast::OpBatch};
@@ -144,35 +144,55 @@ pub struct Chiplets {
     pub memory: Memory,
     pub ace: Ace,
     pub kernel_rom: KernelRom,
+    num_padding_rows: usize,
 }
 
 impl Chiplets {
-    // CONSTRUCTOR
+    /// The number of mandatory padding rows used by [Chiplets::new].
+    pub const DEFAULT_NUM_PADDING_ROWS: usize = 1;
+
+    // CONSTRUCTORS
     // --------------------------------------------------------------------------------------------
     /// Returns a new [Chiplets] component instantiated with the provided Kernel.
     pub fn new(kernel: Kernel) -> Self {
+        Self::new_with_padding_rows(kernel, Self::DEFAULT_NUM_PADDING_ROWS)
+    }
+
+    /// Returns a new [Chiplets] component instantiated with the provided Kernel, which reserves
+    /// `num_padding_rows` mandatory padding rows at the end of the chiplet traces.
+    ///
+    /// This is intended for experimenting with alternative trace layouts: the constraints of the
+    /// auxiliary connector columns assume [Chiplets::DEFAULT_NUM_PADDING_ROWS] padding rows, and
+    /// traces built with fewer rows may not be provable.
+    pub fn new_with_padding_rows(kernel: Kernel, num_padding_rows: usize) -> Self {
         Self {
             hasher: Hasher::default(),
             bitwise: Bitwise::default(),
             memory: Memory::default(),
             kernel_rom: KernelRom::new(kernel),
             ace: Ace::default(),
+            num_padding_rows,
         }
     }
 
     // PUBLIC ACCESSORS
     // --------------------------------------------------------------------------------------------
 
-    /// Returns the length of the trace required to accommodate chiplet components and 1
-    /// mandatory padding row required for ensuring sufficient trace length for auxiliary connector
-    /// columns that rely on the memory chiplet.
+    /// Returns the length of the trace required to accommodate chiplet components and the
+    /// mandatory padding rows (1 by default) required for ensuring sufficient trace length for
+    /// auxiliary connector columns that rely on the memory chiplet.
     pub fn trace_len(&self) -> usize {
         self.hasher.trace_len()
             + self.bitwise.trace_len()
             + self.memory.trace_len()
             + self.kernel_rom.trace_len()
             + self.ace.trace_len()
-            + 1
+            + self.num_padding_rows
+    }
+
+    /// Returns the number of mandatory padding rows at the end of the chiplet traces.
+    pub fn num_padding_rows(&self) -> usize {
+        self.num_padding_rows
     }
 
     /// Returns the index of the first row of [Bitwise] execution trace.
@@ -200,6 +220,34 @@ impl Chiplets {
         self.kernel_rom_start() + self.kernel_rom.trace_len()
     }
 
+    /// Returns the number of rows occupied by each chiplet segment of the trace.
+    ///
+    /// The padding segment comprises only the mandatory padding rows; the rows added when the
+    /// trace is padded to its final length are not included.
+    pub fn segment_lengths(&self) -> ChipletSegmentLengths {
+        ChipletSegmentLengths {
+            hasher: self.bitwise_start().into(),
+            bitwise: self.memory_start() - self.bitwise_start(),
+            memory: self.ace_start() - self.memory_start(),
+            ace: self.kernel_rom_start() - self.ace_start(),
+            kernel_rom: self.padding_start() - self.kernel_rom_start(),
+            padding: self.num_padding_rows,
+        }
+    }
+
+    /// Returns the roots of the kernel procedures.
+    ///
+    /// The roots are returned in the same order regardless of the order in which the procedures
+    /// were provided when the kernel was instantiated.
+    pub fn kernel_procedures(&self) -> &[Word] {
+        self.kernel_rom.kernel().proc_hashes()
+    }
+
+    /// Returns the number of times each kernel procedure has been invoked via `syscall` so far.
+    pub fn kernel_access_counts(&self) -> BTreeMap<Word, usize> {
+        self.kernel_rom.access_counts()
+    }
+
     // EXECUTION TRACE
     // --------------------------------------------------------------------------------------------
 
@@ -249,7 +297,9 @@ impl Chiplets {
         let kernel_rom_start: usize = self.kernel_rom_start().into();
         let padding_start: usize = self.padding_start().into();
 
-        let Chiplets { hasher, bitwise, memory, kernel_rom, ace } = self;
+        let Chiplets {
+            hasher, bitwise, memory, kernel_rom, ace, ..
+        } = self;
 
         // populate external selector columns for all chiplets
         trace[0][bitwise_start..].fill(ONE);
@@ -341,6 +391,25 @@ impl Chiplets {
 // HELPER STRUCTS
 // ================================================================================================
 
+/// The number of rows occupied by each segment of the chiplets trace, as returned by
+/// `Chiplets::segment_lengths()`.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct ChipletSegmentLengths {
+    pub hasher: usize,
+    pub bitwise: usize,
+    pub memory: usize,
+    pub ace: usize,
+    pub kernel_rom: usize,
+    pub padding: usize,
+}
+
+impl ChipletSegmentLengths {
+    /// Returns the total number of rows occupied by all segments.
+    pub fn total(&self) -> usize {
+        self.hasher + self.bitwise + self.memory + self.ace + self.kernel_rom + self.padding
+    }
+}
+
 /// Result of a Merkle tree node update. The result contains the old Merkle_root, which
 /// corresponding to the old_value, and the new merkle_root, for the updated value. As well as the
 /// row address of the execution trace at which the computation started.
@@ -12,9 +12,10 @@ use miden_air::trace::{
 };
 use miden_core::{Felt, ONE, Program, Word, ZERO, mast::MastForest};
 
+use super::Chiplets;
 use crate::{
-    AdviceInputs, DefaultHost, ExecutionOptions, ExecutionTrace, Kernel, Operation, Process,
-    StackInputs,
+    AdviceInputs, ChipletsLengths, DefaultHost, ExecutionOptions, ExecutionTrace, Kernel,
+    Operation, Process, StackInputs,
 };
 
 type ChipletsTrace = [Vec<Felt>; CHIPLETS_WIDTH];
@@ -99,6 +100,127 @@ fn stacked_chiplet_trace() {
     validate_padding(&chiplets_trace, kernel_rom_end, trace_len);
 }
 
+#[test]
+fn kernel_access_counts() {
+    // build a program which invokes the `foo` kernel procedure 3 times, while the other kernel
+    // procedure is never invoked
+    let mut mast_forest = MastForest::new();
+
+    let foo_id = mast_forest.add_block(vec![Operation::Noop], None).unwrap();
+    mast_forest.make_root(foo_id);
+    let foo_hash = mast_forest[foo_id].digest();
+    let bar_hash = Word::from([1_u32, 1, 1, 1]);
+    let kernel = Kernel::new(&[foo_hash, bar_hash]).unwrap();
+
+    let syscall_ids: Vec<_> = (0..3).map(|_| mast_forest.add_syscall(foo_id).unwrap()).collect();
+    let join_id = mast_forest.add_join(syscall_ids[0], syscall_ids[1]).unwrap();
+    let root_id = mast_forest.add_join(join_id, syscall_ids[2]).unwrap();
+    mast_forest.make_root(root_id);
+
+    let program = Program::with_kernel(mast_forest.into(), root_id, kernel.clone());
+
+    let mut host = DefaultHost::default();
+    let mut process = Process::new(
+        kernel,
+        StackInputs::default(),
+        AdviceInputs::default(),
+        ExecutionOptions::default(),
+    );
+    process.execute(&program, &mut host).unwrap();
+
+    let access_counts = process.chiplets.kernel_access_counts();
+    assert_eq!(access_counts.len(), 2);
+    assert_eq!(access_counts[&foo_hash], 3);
+    assert_eq!(access_counts[&bar_hash], 0);
+}
+
+#[test]
+fn kernel_procedures() {
+    let proc_hash1 = Word::from([1_u32, 0, 1, 0]);
+    let proc_hash2 = Word::from([1_u32, 1, 1, 1]);
+
+    let chiplets = Chiplets::new(Kernel::new(&[proc_hash1, proc_hash2]).unwrap());
+    let procedures = chiplets.kernel_procedures();
+    assert_eq!(procedures.len(), 2);
+    assert!(procedures.contains(&proc_hash1));
+    assert!(procedures.contains(&proc_hash2));
+
+    // the order of the roots does not depend on the order in which they were provided
+    let chiplets_rev = Chiplets::new(Kernel::new(&[proc_hash2, proc_hash1]).unwrap());
+    assert_eq!(chiplets_rev.kernel_procedures(), procedures);
+}
+
+#[test]
+fn chiplets_padding_rows() {
+    // by default, a single mandatory padding row is reserved
+    let chiplets = Chiplets::new(Kernel::default());
+    assert_eq!(chiplets.num_padding_rows(), Chiplets::DEFAULT_NUM_PADDING_ROWS);
+    assert_eq!(chiplets.trace_len(), 1);
+
+    // the number of padding rows is reflected in the length of the trace
+    let num_padding_rows = 4;
+    let chiplets = Chiplets::new_with_padding_rows(Kernel::default(), num_padding_rows);
+    assert_eq!(chiplets.num_padding_rows(), num_padding_rows);
+    assert_eq!(chiplets.trace_len(), num_padding_rows);
+
+    // the reported chiplet lengths account for the configured padding rows
+    let lengths = ChipletsLengths::new(&chiplets);
+    assert_eq!(lengths.num_padding_rows(), num_padding_rows);
+    assert_eq!(lengths.trace_len(), chiplets.trace_len());
+
+    // the whole trace, except for the random rows, is padding
+    let trace_len = 8;
+    let trace = chiplets.into_trace(trace_len, ExecutionTrace::NUM_RAND_ROWS).trace;
+    validate_padding(&trace, 0, trace_len - ExecutionTrace::NUM_RAND_ROWS);
+}
+
+#[test]
+#[should_panic(expected = "target trace length too small")]
+fn chiplets_padding_rows_trace_too_small() {
+    // the padding rows and the random rows do not fit into a trace of length 4
+    let chiplets = Chiplets::new_with_padding_rows(Kernel::default(), 4);
+    chiplets.into_trace(4, ExecutionTrace::NUM_RAND_ROWS);
+}
+
+#[test]
+fn segment_lengths() {
+    // a hash-heavy program: each permutation takes a full hash cycle in the hasher chiplet
+    let stack = [8, 0, 0, 0, 1, 2, 3, 4, 5, 6, 7, 8, 1];
+    let mut operations = vec![Operation::U32xor, Operation::Push(ZERO), Operation::MStoreW];
+    operations.extend([Operation::HPerm; 10]);
+
+    let stack_inputs = StackInputs::try_from_ints(stack).unwrap();
+    let mut process = Process::new(
+        build_kernel(),
+        stack_inputs,
+        AdviceInputs::default(),
+        ExecutionOptions::default(),
+    );
+    let mut mast_forest = MastForest::new();
+    let basic_block_id = mast_forest.add_block(operations, None).unwrap();
+    mast_forest.make_root(basic_block_id);
+    let program = Program::new(mast_forest.into(), basic_block_id);
+    process.execute(&program, &mut DefaultHost::default()).unwrap();
+
+    let lengths = process.chiplets.segment_lengths();
+    assert_eq!(lengths.hasher, 11 * HASH_CYCLE_LEN);
+    assert_eq!(lengths.bitwise, OP_CYCLE_LEN);
+    assert_eq!(lengths.memory, 1);
+    assert_eq!(lengths.ace, 0);
+    assert_eq!(lengths.kernel_rom, 2);
+    assert_eq!(lengths.padding, Chiplets::DEFAULT_NUM_PADDING_ROWS);
+    assert_eq!(lengths.total(), process.chiplets.trace_len());
+
+    let others = [
+        lengths.bitwise,
+        lengths.memory,
+        lengths.ace,
+        lengths.kernel_rom,
+        lengths.padding,
+    ];
+    assert!(others.iter().all(|len| *len < lengths.hasher));
+}
+
 // HELPER FUNCTIONS
 // ================================================================================================
 
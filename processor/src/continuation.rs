@@ -0,0 +1,575 @@
+use alloc::sync::Arc;
+
+use miden_core::{
+    AssemblyOp, Decorator, ONE, Operation, ZERO,
+    mast::{BasicBlockNode, CallNode, DynNode, MastForest, MastNode, MastNodeId, OP_GROUP_SIZE},
+};
+
+use crate::{
+    ExecutionError, Process, SyncHost, add_error_ctx_to_external_error, err_ctx,
+    utils::resolve_external_node,
+};
+
+// CONTINUATION
+// ================================================================================================
+
+/// A unit of work which remains to be done to complete the execution of a program.
+///
+/// A [Process] keeps the remainder of the program it executes as a stack of continuations, which
+/// refer to the nodes of the MAST forest on top of its stack of forests. Executing a continuation
+/// either executes a single operation, or replaces the continuation with the ones it is made of;
+/// this is what allows the execution of a program to be suspended after any clock cycle, and
+/// resumed later.
+#[derive(Debug)]
+pub(crate) enum Continuation {
+    /// Executes the `before_enter` decorators of the node and the operation starting it.
+    StartNode(MastNodeId),
+    /// Executes the next operation of a basic block node.
+    ResumeBasicBlock(MastNodeId, BasicBlockCursor),
+    /// Either executes the body of a loop node once more, or finishes the loop node, depending
+    /// on the condition on top of the stack.
+    ResumeLoop(MastNodeId),
+    /// Executes the operation ending the node and its `after_exit` decorators.
+    ///
+    /// Basic block nodes are finished by [Continuation::ResumeBasicBlock], and loop nodes whose
+    /// body has been executed at least once are finished by [Continuation::ResumeLoop].
+    FinishNode(MastNodeId),
+    /// Leaves the MAST forest on top of the stack of forests, and resumes the execution in the
+    /// MAST forest which was executed before it was entered.
+    LeaveForest,
+}
+
+/// The position of the next operation to be executed within a basic block node.
+#[derive(Debug)]
+pub(crate) struct BasicBlockCursor {
+    /// Index of the operation batch being executed.
+    batch_idx: usize,
+    /// Index of the first operation of the current batch within the basic block.
+    op_offset: usize,
+    /// Index of the next operation within the current batch.
+    op_idx_in_batch: usize,
+    /// Index of the next operation within the current operation group.
+    op_idx_in_group: usize,
+    /// Index of the current operation group within the current batch.
+    group_idx: usize,
+    /// Index of the operation group which follows the current one, accounting for the groups
+    /// holding immediate values.
+    next_group_idx: usize,
+    /// Index of the next decorator to be executed in the decorator list of the basic block.
+    decorator_idx: usize,
+    /// Set when the last operation of a group carried an immediate value, and hence must be
+    /// followed by a NOOP.
+    pending_noop: bool,
+}
+
+impl BasicBlockCursor {
+    fn new() -> Self {
+        Self {
+            batch_idx: 0,
+            op_offset: 0,
+            op_idx_in_batch: 0,
+            op_idx_in_group: 0,
+            group_idx: 0,
+            next_group_idx: 1,
+            decorator_idx: 0,
+            pending_noop: false,
+        }
+    }
+}
+
+// CONTINUATION EXECUTION
+// ================================================================================================
+
+impl Process {
+    /// Executes the specified continuation, which refers to a node in the `program` MAST forest.
+    pub(crate) fn execute_continuation(
+        &mut self,
+        program: &Arc<MastForest>,
+        continuation: Continuation,
+        host: &mut impl SyncHost,
+    ) -> Result<(), ExecutionError> {
+        match continuation {
+            Continuation::StartNode(node_id) => self.start_node(program, node_id, host),
+            Continuation::ResumeBasicBlock(node_id, cursor) => {
+                self.resume_basic_block_node(program, node_id, cursor, host)
+            },
+            Continuation::ResumeLoop(node_id) => self.resume_loop_node(program, node_id, host),
+            Continuation::FinishNode(node_id) => self.finish_node(program, node_id, host),
+            Continuation::LeaveForest => {
+                self.forests.pop();
+                Ok(())
+            },
+        }
+    }
+
+    /// Adds the error context of the call and dyn nodes which enclose the continuation that
+    /// failed to the provided error.
+    pub(crate) fn add_error_ctx_of_enclosing_calls(&self, err: ExecutionError) -> ExecutionError {
+        let mut forests = self.forests.iter().rev();
+        let mut program = forests.next();
+        self.continuations.iter().rev().fold(err, |err, continuation| {
+            let node_id = match continuation {
+                Continuation::FinishNode(node_id) => node_id,
+                Continuation::LeaveForest => {
+                    program = forests.next();
+                    return err;
+                },
+                _ => return err,
+            };
+            let program =
+                program.expect("a continuation must refer to a node of an entered forest");
+            let result: Result<(), _> = match &program[*node_id] {
+                MastNode::Call(node) => add_error_ctx_to_external_error(
+                    Err(err),
+                    err_ctx!(program, node, self.source_manager.clone()),
+                ),
+                MastNode::Dyn(node) => add_error_ctx_to_external_error(
+                    Err(err),
+                    err_ctx!(program, node, self.source_manager.clone()),
+                ),
+                _ => Err(err),
+            };
+            result.expect_err("adding error context must preserve the error")
+        })
+    }
+
+    /// Returns the assembly instruction which starts with the next operation to be executed, if
+    /// any.
+    ///
+    /// Assembly instructions are known only for programs assembled in debug mode.
+    pub(crate) fn next_asmop(&self) -> Option<&AssemblyOp> {
+        let Continuation::ResumeBasicBlock(node_id, cursor) = self.continuations.last()? else {
+            return None;
+        };
+        let program = self.forests.last()?;
+        let MastNode::Block(basic_block) = &program[*node_id] else {
+            return None;
+        };
+
+        let batch = &basic_block.op_batches()[cursor.batch_idx];
+        if cursor.pending_noop || cursor.op_idx_in_batch >= batch.ops().len() {
+            return None;
+        }
+
+        let op_idx = cursor.op_offset + cursor.op_idx_in_batch;
+        basic_block.decorators()[cursor.decorator_idx..]
+            .iter()
+            .take_while(|(pos, _)| *pos == op_idx)
+            .find_map(|&(_, decorator_id)| match &program[decorator_id] {
+                Decorator::AsmOp(asmop) => Some(asmop),
+                _ => None,
+            })
+    }
+
+    // NODE STARTERS
+    // --------------------------------------------------------------------------------------------
+
+    fn start_node(
+        &mut self,
+        program: &Arc<MastForest>,
+        node_id: MastNodeId,
+        host: &mut impl SyncHost,
+    ) -> Result<(), ExecutionError> {
+        let node = program
+            .get_node_by_id(node_id)
+            .ok_or(ExecutionError::MastNodeNotFoundInForest { node_id })?;
+
+        for &decorator_id in node.before_enter() {
+            self.execute_decorator(&program[decorator_id], host)?;
+        }
+
+        match node {
+            MastNode::Block(node) => {
+                self.start_basic_block_node(node, program, host)?;
+                self.push_continuation(Continuation::ResumeBasicBlock(
+                    node_id,
+                    BasicBlockCursor::new(),
+                ));
+            },
+            MastNode::Join(node) => {
+                self.start_join_node(node, program, host)?;
+
+                // the first child of the join block is executed first, and so it must be pushed
+                // onto the continuation stack last
+                self.push_continuation(Continuation::FinishNode(node_id));
+                self.push_continuation(Continuation::StartNode(node.second()));
+                self.push_continuation(Continuation::StartNode(node.first()));
+            },
+            MastNode::Split(node) => {
+                // start the SPLIT block; this also pops the stack and returns the popped element
+                let condition = self.start_split_node(node, program, host)?;
+
+                // execute either the true or the false branch of the split block based on the
+                // condition
+                let branch = if condition == ONE {
+                    node.on_true()
+                } else if condition == ZERO {
+                    node.on_false()
+                } else {
+                    let err_ctx = err_ctx!(program, node, self.source_manager.clone());
+                    return Err(ExecutionError::not_binary_value_if(condition, &err_ctx));
+                };
+                self.push_continuation(Continuation::FinishNode(node_id));
+                self.push_continuation(Continuation::StartNode(branch));
+            },
+            MastNode::Loop(node) => {
+                // start the LOOP block; this also pops the stack and returns the popped element
+                let condition = self.start_loop_node(node, program, host)?;
+
+                // if the top of the stack is ONE, execute the loop body; otherwise skip the loop
+                // body
+                if condition == ONE {
+                    self.push_continuation(Continuation::ResumeLoop(node_id));
+                    self.push_continuation(Continuation::StartNode(node.body()));
+                } else if condition == ZERO {
+                    self.push_continuation(Continuation::FinishNode(node_id));
+                } else {
+                    let err_ctx = err_ctx!(program, node, self.source_manager.clone());
+                    return Err(ExecutionError::not_binary_value_loop(condition, &err_ctx));
+                }
+            },
+            MastNode::Call(node) => {
+                let err_ctx = err_ctx!(program, node, self.source_manager.clone());
+                add_error_ctx_to_external_error(self.start_call(node, program, host), err_ctx)?;
+                let callee = program[node.callee()].digest();
+                self.enter_call_frame(callee);
+                self.push_continuation(Continuation::FinishNode(node_id));
+                self.push_continuation(Continuation::StartNode(node.callee()));
+            },
+            MastNode::Dyn(node) => {
+                let err_ctx = err_ctx!(program, node, self.source_manager.clone());
+                let (callee_program, callee_id) =
+                    add_error_ctx_to_external_error(self.start_dyn(node, program, host), err_ctx)?;
+                let callee = callee_program[callee_id].digest();
+                self.enter_call_frame(callee);
+                self.push_continuation(Continuation::FinishNode(node_id));
+                self.enter_forest(callee_program);
+                self.push_continuation(Continuation::StartNode(callee_id));
+            },
+            MastNode::External(external_node) => {
+                let (root_id, mast_forest) =
+                    resolve_external_node(external_node, &mut self.advice, host)?;
+                self.push_continuation(Continuation::FinishNode(node_id));
+                self.enter_forest(mast_forest);
+                self.push_continuation(Continuation::StartNode(root_id));
+            },
+        }
+
+        Ok(())
+    }
+
+    /// Starts the specified [CallNode].
+    fn start_call(
+        &mut self,
+        call_node: &CallNode,
+        program: &MastForest,
+        host: &mut impl SyncHost,
+    ) -> Result<(), ExecutionError> {
+        // call or syscall are not allowed inside a syscall
+        if self.system.in_syscall() {
+            let instruction = if call_node.is_syscall() { "syscall" } else { "call" };
+            return Err(ExecutionError::CallInSyscall(instruction));
+        }
+
+        let callee = program.get_node_by_id(call_node.callee()).ok_or_else(|| {
+            ExecutionError::MastNodeNotFoundInForest { node_id: call_node.callee() }
+        })?;
+
+        // let the host veto the call before the execution context of the callee is entered
+        host.on_call_enter(&mut self.state(), callee.digest())?;
+
+        // if this is a syscall, make sure the call target exists in the kernel
+        if call_node.is_syscall() {
+            let err_ctx = err_ctx!(program, call_node, self.source_manager.clone());
+            self.chiplets.kernel_rom.access_proc(callee.digest(), &err_ctx)?;
+        }
+
+        self.start_call_node(call_node, program, host)
+    }
+
+    /// Starts the specified [DynNode], and returns the MAST forest containing the callee together
+    /// with the ID of the callee within it.
+    ///
+    /// The MAST root of the callee is assumed to be at the top of the stack, and the callee is
+    /// expected to be either in the current `program` or in the host.
+    fn start_dyn(
+        &mut self,
+        node: &DynNode,
+        program: &Arc<MastForest>,
+        host: &mut impl SyncHost,
+    ) -> Result<(Arc<MastForest>, MastNodeId), ExecutionError> {
+        // dyn calls are not allowed inside a syscall
+        if node.is_dyncall() && self.system.in_syscall() {
+            return Err(ExecutionError::CallInSyscall("dyncall"));
+        }
+
+        let err_ctx = err_ctx!(program, node, self.source_manager.clone());
+
+        let callee_hash = if node.is_dyncall() {
+            self.start_dyncall_node(node, host, &err_ctx)?
+        } else {
+            self.start_dyn_node(node, program, host, &err_ctx)?
+        };
+
+        // if the callee is not in the program's MAST forest, try to find a MAST forest for it in
+        // the host (corresponding to an external library loaded in the host); if none are
+        // found, return an error.
+        match program.find_procedure_root(callee_hash) {
+            Some(callee_id) => Ok((program.clone(), callee_id)),
+            None => {
+                let mast_forest = host
+                    .get_mast_forest(&callee_hash)
+                    .ok_or_else(|| ExecutionError::dynamic_node_not_found(callee_hash, &err_ctx))?;
+
+                // We limit the parts of the program that can be called externally to procedure
+                // roots, even though MAST doesn't have that restriction.
+                let root_id = mast_forest
+                    .find_procedure_root(callee_hash)
+                    .ok_or(ExecutionError::malfored_mast_forest_in_host(callee_hash, &()))?;
+
+                Ok((mast_forest, root_id))
+            },
+        }
+    }
+
+    // NODE RESUMERS
+    // --------------------------------------------------------------------------------------------
+
+    /// Executes the next operation of the specified [BasicBlockNode]. This also ensures that all
+    /// alignment rules are satisfied by executing NOOPs as needed. Specifically:
+    /// - If an operation group ends with an operation carrying an immediate value, a NOOP is
+    ///   executed after it.
+    /// - If the number of groups in a batch is not a power of 2, NOOPs are executed (one per group)
+    ///   to bring it up to the next power of two (e.g., 3 -> 4, 5 -> 8).
+    /// - Each batch after the first one is preceded by a RESPAN operation.
+    fn resume_basic_block_node(
+        &mut self,
+        program: &Arc<MastForest>,
+        node_id: MastNodeId,
+        mut cursor: BasicBlockCursor,
+        host: &mut impl SyncHost,
+    ) -> Result<(), ExecutionError> {
+        let MastNode::Block(basic_block) = &program[node_id] else {
+            panic!("expected node {node_id} to be a basic block");
+        };
+        let batch = &basic_block.op_batches()[cursor.batch_idx];
+
+        // round up the number of groups to be processed to the next power of two; we do this
+        // because the processor requires the number of groups to be either 1, 2, 4, or 8; if
+        // the actual number of groups is smaller, we'll pad the batch with NOOPs at the end
+        let num_batch_groups = batch.num_groups().next_power_of_two();
+
+        if cursor.pending_noop {
+            // an operation with an immediate value cannot be the last operation in a group so, we
+            // need execute a NOOP after it. the assert also makes sure that there is enough room
+            // in the group to execute a NOOP (if there isn't, there is a bug somewhere in the
+            // assembler)
+            debug_assert!(cursor.op_idx_in_group < OP_GROUP_SIZE - 1, "invalid op index");
+            self.decoder.execute_user_op(Operation::Noop, cursor.op_idx_in_group + 1);
+            self.execute_op(Operation::Noop, program, host)?;
+
+            cursor.pending_noop = false;
+            self.start_next_op_group(&mut cursor, basic_block, num_batch_groups);
+        } else if cursor.op_idx_in_batch < batch.ops().len() {
+            let op_idx = cursor.op_offset + cursor.op_idx_in_batch;
+            let decorators = basic_block.decorators();
+            while let Some(&(_, decorator_id)) =
+                decorators.get(cursor.decorator_idx).filter(|(pos, _)| *pos == op_idx)
+            {
+                let decorator = program
+                    .get_decorator_by_id(decorator_id)
+                    .ok_or(ExecutionError::DecoratorNotFoundInForest { decorator_id })?;
+                self.execute_decorator(decorator, host)?;
+                cursor.decorator_idx += 1;
+            }
+
+            // decode and execute the operation
+            let op = batch.ops()[cursor.op_idx_in_batch];
+            let err_ctx = err_ctx!(program, basic_block, self.source_manager.clone(), op_idx);
+            self.decoder.execute_user_op(op, cursor.op_idx_in_group);
+            self.execute_op_with_error_ctx(op, program, host, &err_ctx)?;
+            cursor.op_idx_in_batch += 1;
+
+            // if the operation carries an immediate value, the value is stored at the next group
+            // pointer; so, we advance the pointer to the following group
+            let has_imm = op.imm_value().is_some();
+            if has_imm {
+                cursor.next_group_idx += 1;
+            }
+
+            // determine if we've executed all non-decorator operations in a group; if the last
+            // operation carries an immediate value, the group ends after the subsequent NOOP
+            if cursor.op_idx_in_group == batch.op_counts()[cursor.group_idx] - 1 {
+                if has_imm {
+                    cursor.pending_noop = true;
+                } else {
+                    self.start_next_op_group(&mut cursor, basic_block, num_batch_groups);
+                }
+            } else {
+                cursor.op_idx_in_group += 1;
+            }
+        } else if cursor.group_idx < num_batch_groups {
+            // make sure we execute the required number of operation groups; this would happen
+            // when the actual number of operation groups was not a power of two
+            self.decoder.execute_user_op(Operation::Noop, 0);
+            self.execute_op(Operation::Noop, program, host)?;
+
+            // if we are not at the last group yet, set up the decoder for decoding the next
+            // operation groups. the groups were are processing are just NOOPs - so, the op group
+            // value is ZERO
+            if cursor.group_idx < num_batch_groups - 1 {
+                self.decoder.start_op_group(ZERO);
+            }
+            cursor.group_idx += 1;
+        } else if let Some(next_batch) = basic_block.op_batches().get(cursor.batch_idx + 1) {
+            // each additional batch is preceded by a RESPAN operation; executing RESPAN operation
+            // does not change the state of the stack
+            self.respan(next_batch);
+            self.execute_op(Operation::Noop, program, host)?;
+
+            cursor = BasicBlockCursor {
+                batch_idx: cursor.batch_idx + 1,
+                op_offset: cursor.op_offset + batch.ops().len(),
+                decorator_idx: cursor.decorator_idx,
+                ..BasicBlockCursor::new()
+            };
+        } else {
+            self.end_basic_block_node(basic_block, program, host)?;
+
+            // execute any decorators which have not been executed during span ops execution; this
+            // can happen for decorators appearing after all operations in a block. these
+            // decorators are executed after SPAN block is closed to make sure the VM clock cycle
+            // advances beyond the last clock cycle of the SPAN block ops.
+            for &(_, decorator_id) in &basic_block.decorators()[cursor.decorator_idx..] {
+                let decorator = program
+                    .get_decorator_by_id(decorator_id)
+                    .ok_or(ExecutionError::DecoratorNotFoundInForest { decorator_id })?;
+                self.execute_decorator(decorator, host)?;
+            }
+
+            return self.execute_after_exit_decorators(program, node_id, host);
+        }
+
+        self.push_continuation(Continuation::ResumeBasicBlock(node_id, cursor));
+        Ok(())
+    }
+
+    /// Moves the cursor to the operation group following the current one, and sets up the decoder
+    /// for decoding it if the end of the batch has not been reached yet.
+    fn start_next_op_group(
+        &mut self,
+        cursor: &mut BasicBlockCursor,
+        basic_block: &BasicBlockNode,
+        num_batch_groups: usize,
+    ) {
+        cursor.group_idx = cursor.next_group_idx;
+        cursor.next_group_idx += 1;
+        cursor.op_idx_in_group = 0;
+
+        if cursor.group_idx < num_batch_groups {
+            let batch = &basic_block.op_batches()[cursor.batch_idx];
+            self.decoder.start_op_group(batch.groups()[cursor.group_idx]);
+        }
+    }
+
+    /// Executes the body of the specified loop node once more if the top of the stack is ONE, or
+    /// finishes the loop node if it is ZERO.
+    fn resume_loop_node(
+        &mut self,
+        program: &Arc<MastForest>,
+        node_id: MastNodeId,
+        host: &mut impl SyncHost,
+    ) -> Result<(), ExecutionError> {
+        let MastNode::Loop(node) = &program[node_id] else {
+            panic!("expected node {node_id} to be a loop");
+        };
+
+        let condition = self.stack.peek();
+        if condition == ONE {
+            // each iteration of the loop is preceded by executing REPEAT operation which drops the
+            // condition from the stack
+            self.decoder.repeat();
+            self.execute_op(Operation::Drop, program, host)?;
+            self.push_continuation(Continuation::ResumeLoop(node_id));
+            self.push_continuation(Continuation::StartNode(node.body()));
+            Ok(())
+        } else if condition == ZERO {
+            // end the LOOP block and drop the condition from the stack
+            self.end_loop_node(node, true, program, host)?;
+            self.execute_after_exit_decorators(program, node_id, host)
+        } else {
+            let err_ctx = err_ctx!(program, node, self.source_manager.clone());
+            Err(ExecutionError::not_binary_value_loop(condition, &err_ctx))
+        }
+    }
+
+    // NODE FINISHERS
+    // --------------------------------------------------------------------------------------------
+
+    fn finish_node(
+        &mut self,
+        program: &Arc<MastForest>,
+        node_id: MastNodeId,
+        host: &mut impl SyncHost,
+    ) -> Result<(), ExecutionError> {
+        match &program[node_id] {
+            MastNode::Block(_) => panic!("basic block {node_id} must be finished when resumed"),
+            MastNode::Join(node) => self.end_join_node(node, program, host)?,
+            MastNode::Split(node) => self.end_split_node(node, program, host)?,
+            // end the LOOP block, but don't drop the condition from the stack because it was
+            // already dropped when we started the LOOP block
+            MastNode::Loop(node) => self.end_loop_node(node, false, program, host)?,
+            MastNode::Call(node) => {
+                host.on_call_exit(&mut self.state(), program[node.callee()].digest());
+                self.exit_call_frame();
+                let err_ctx = err_ctx!(program, node, self.source_manager.clone());
+                add_error_ctx_to_external_error(
+                    self.end_call_node(node, program, host, &err_ctx),
+                    err_ctx,
+                )?
+            },
+            MastNode::Dyn(node) => {
+                if node.is_dyncall() {
+                    // the hash of the procedure invoked via dyncall is kept by the system registers
+                    // until the context of the caller is restored
+                    let callee = self.system.fn_hash();
+                    host.on_call_exit(&mut self.state(), callee);
+                }
+                self.exit_call_frame();
+                let err_ctx = err_ctx!(program, node, self.source_manager.clone());
+                let result = if node.is_dyncall() {
+                    self.end_dyncall_node(node, program, host, &err_ctx)
+                } else {
+                    self.end_dyn_node(node, program, host)
+                };
+                add_error_ctx_to_external_error(result, err_ctx)?
+            },
+            MastNode::External(_) => (),
+        }
+
+        self.execute_after_exit_decorators(program, node_id, host)
+    }
+
+    fn execute_after_exit_decorators(
+        &mut self,
+        program: &MastForest,
+        node_id: MastNodeId,
+        host: &mut impl SyncHost,
+    ) -> Result<(), ExecutionError> {
+        for &decorator_id in program[node_id].after_exit() {
+            self.execute_decorator(&program[decorator_id], host)?;
+        }
+        Ok(())
+    }
+
+    fn push_continuation(&mut self, continuation: Continuation) {
+        self.continuations.push(continuation);
+    }
+
+    /// Makes the specified MAST forest the one in which the continuations pushed next are
+    /// executed, until the matching [Continuation::LeaveForest] is executed.
+    pub(crate) fn enter_forest(&mut self, program: Arc<MastForest>) {
+        self.continuations.push(Continuation::LeaveForest);
+        self.forests.push(program);
+    }
+}
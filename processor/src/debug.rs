@@ -7,12 +7,48 @@ use core::fmt;
 
 use miden_air::RowIndex;
 use miden_core::{AssemblyOp, FieldElement, Operation, StackOutputs};
+use miden_debug_types::{LineNumber, SourceManager, Uri};
 
 use crate::{
     Chiplets, ChipletsLengths, Decoder, ExecutionError, Felt, MemoryAddress, Process, Stack,
     System, TraceLenSummary, range::RangeChecker, system::ContextId,
 };
 
+// BREAKPOINT
+// ================================================================================================
+
+/// A point at which [Process::run_with_breakpoints] pauses the execution of a program.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Breakpoint {
+    /// Pauses the execution when the clock reaches the specified cycle, before the operation at
+    /// that cycle is executed.
+    Clock(RowIndex),
+    /// Pauses the execution before the first operation of any assembly instruction located on the
+    /// specified line of the specified source file.
+    ///
+    /// Assembly instructions are located via the `AsmOp` decorators, and hence can be hit only
+    /// for programs assembled in debug mode.
+    AsmOp { uri: Uri, line: LineNumber },
+}
+
+impl Process {
+    /// Returns true if the process is paused at the specified breakpoint.
+    pub(crate) fn is_at_breakpoint(&self, breakpoint: &Breakpoint) -> bool {
+        match breakpoint {
+            Breakpoint::Clock(clk) => self.system.clk() == *clk,
+            Breakpoint::AsmOp { uri, line } => self
+                .next_asmop()
+                .and_then(AssemblyOp::location)
+                .and_then(|location| self.source_manager.location_to_span(location.clone()))
+                .and_then(|span| self.source_manager.file_line_col(span).ok())
+                .is_some_and(|location| location.uri() == uri && location.line() == *line),
+        }
+    }
+}
+
+// VM STATE
+// ================================================================================================
+
 /// VmState holds a current process state information at a specific clock cycle.
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct VmState {
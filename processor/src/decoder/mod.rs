@@ -11,9 +11,10 @@ use miden_air::{
     },
 };
 use miden_core::{
-    AssemblyOp,
+    AssemblyOp, Decorator,
     mast::{
-        BasicBlockNode, CallNode, DynNode, JoinNode, LoopNode, MastForest, OP_BATCH_SIZE, SplitNode,
+        BasicBlockNode, CallNode, DynNode, JoinNode, LoopNode, MastForest, MastNode, OP_BATCH_SIZE,
+        SplitNode,
     },
     stack::MIN_STACK_DEPTH,
 };
@@ -191,9 +192,40 @@ impl Process {
         // followed by an END operation.
         self.decoder.start_loop(body_hash, addr, condition);
         self.execute_op(Operation::Drop, program, host)?;
+
+        if condition == ONE {
+            self.reserve_loop_trace(node, program);
+        }
         Ok(condition)
     }
 
+    /// Pre-allocates the trace for the iterations of the specified loop if the loop carries an
+    /// iteration hint.
+    ///
+    /// The hint is only used to estimate the number of rows the loop will take; the loop may
+    /// still execute any number of iterations.
+    fn reserve_loop_trace(&mut self, node: &LoopNode, program: &MastForest) {
+        let Some(max_iterations) =
+            node.before_enter()
+                .iter()
+                .find_map(|&decorator_id| match program[decorator_id] {
+                    Decorator::LoopHint(max_iterations) => Some(max_iterations as usize),
+                    _ => None,
+                })
+        else {
+            return;
+        };
+
+        // every iteration takes the rows of the loop body and a REPEAT row; a basic block takes
+        // a row per operation, a SPAN or RESPAN row per batch and an END row, while any other
+        // node takes at least a row to start it and an END row
+        let body_rows = match &program[node.body()] {
+            MastNode::Block(block) => block.num_operations() as usize + block.num_op_batches() + 1,
+            _ => 2,
+        };
+        self.reserve_trace_capacity(max_iterations.saturating_mul(body_rows + 1));
+    }
+
     /// Ends decoding of a LOOP block. If pop_stack is set to true, this also removes the
     /// value at the top of the stack.
     pub(super) fn end_loop_node<H: SyncHost>(
@@ -358,6 +390,7 @@ impl Process {
     pub(super) fn start_dyncall_node(
         &mut self,
         dyn_node: &DynNode,
+        host: &mut impl SyncHost,
         err_ctx: &impl ErrorContext,
     ) -> Result<Word, ExecutionError> {
         debug_assert!(dyn_node.is_dyncall());
@@ -371,6 +404,9 @@ impl Process {
             .read_word(self.system.ctx(), mem_addr, self.system.clk(), err_ctx)
             .map_err(ExecutionError::MemoryError)?;
 
+        // let the host veto the call before the execution context of the callee is entered
+        host.on_call_enter(&mut self.state(), callee_hash)?;
+
         // Note: other functions end in "executing a Noop", which
         // 1. ensures trace capacity,
         // 2. copies the stack over to the next row,
@@ -949,7 +985,7 @@ pub fn build_op_group(ops: &[Operation]) -> Felt {
         group |= (op.op_code() as u64) << (Operation::OP_BITS * i);
         i += 1;
     }
-    assert!(i <= super::OP_GROUP_SIZE, "too many ops");
+    assert!(i <= miden_core::mast::OP_GROUP_SIZE, "too many ops");
     Felt::new(group)
 }
 
@@ -1,9 +1,14 @@
-use alloc::{boxed::Box, sync::Arc};
+use alloc::{
+    boxed::Box,
+    string::{String, ToString},
+    sync::Arc,
+    vec::Vec,
+};
 use core::error::Error;
 
 use miden_air::RowIndex;
 use miden_core::{
-    Felt, QuadFelt, Word,
+    Felt, Kernel, QuadFelt, SignatureKind, Word,
     mast::{DecoratorId, MastForest, MastNodeExt, MastNodeId},
     stack::MIN_STACK_DEPTH,
     utils::to_hex,
@@ -13,7 +18,7 @@ use miden_utils_diagnostics::{Diagnostic, miette};
 use winter_prover::ProverError;
 
 use super::system::{FMP_MAX, FMP_MIN};
-use crate::{MemoryError, host::advice::AdviceError};
+use crate::{ContextId, MemoryError, host::advice::AdviceError};
 
 // EXECUTION ERROR
 // ================================================================================================
@@ -32,6 +37,16 @@ pub enum ExecutionError {
         #[diagnostic_source]
         err: AdviceError,
     },
+    #[error("advice stack read failed at clock cycle {clk} in context {ctx}")]
+    #[diagnostic()]
+    AdviceStackReadFailed {
+        #[label]
+        label: SourceSpan,
+        #[source_code]
+        source_file: Option<Arc<SourceFile>>,
+        clk: RowIndex,
+        ctx: ContextId,
+    },
     /// This error is caught by the assembler, so we don't need diagnostics here.
     #[error("illegal use of instruction {0} while inside a syscall")]
     CallInSyscall(&'static str),
@@ -40,8 +55,8 @@ pub enum ExecutionError {
     CallerNotInSyscall,
     #[error("external node with mast root {0} resolved to an external node")]
     CircularExternalNode(Word),
-    #[error("exceeded the allowed number of max cycles {0}")]
-    CycleLimitExceeded(u32),
+    #[error("exceeded the allowed number of max cycles {limit}")]
+    CycleLimitExceeded { limit: u32 },
     #[error("decorator id {decorator_id} does not exist in MAST forest")]
     DecoratorNotFoundInForest { decorator_id: DecoratorId },
     #[error("division by zero at clock cycle {clk}")]
@@ -246,15 +261,30 @@ pub enum ExecutionError {
         node: Word,
         preimage_len: usize,
     },
+    #[error("stack depth {depth} exceeded the allowed maximum at clock cycle {clk}")]
+    StackDepthExceeded { clk: RowIndex, depth: usize },
     #[error("syscall failed: procedure with root {hex} was not found in the kernel",
       hex = to_hex(proc_root.as_bytes())
     )]
+    #[diagnostic(help("{}", describe_kernel_procs(kernel_proc_roots)))]
     SyscallTargetNotInKernel {
         #[label]
         label: SourceSpan,
         #[source_code]
         source_file: Option<Arc<SourceFile>>,
         proc_root: Word,
+        kernel_proc_roots: Vec<Word>,
+    },
+    #[error("signatures of kind {kind} are not supported")]
+    #[diagnostic(help(
+        "generating signature advice requires the `std` feature of the processor to be enabled"
+    ))]
+    UnsupportedSignatureKind {
+        #[label]
+        label: SourceSpan,
+        #[source_code]
+        source_file: Option<Arc<SourceFile>>,
+        kind: SignatureKind,
     },
     #[error("failed to execute arithmetic circuit evaluation operation: {error}")]
     #[diagnostic()]
@@ -277,6 +307,25 @@ impl ExecutionError {
         ExecutionError::AdviceError { label, source_file, err, clk }
     }
 
+    /// Returns an error for a failed attempt to pop elements from the advice stack.
+    ///
+    /// Advice stack underflows are reported as [ExecutionError::AdviceStackReadFailed], while all
+    /// other errors are reported as [ExecutionError::AdviceError].
+    pub fn advice_stack_error(
+        err: AdviceError,
+        clk: RowIndex,
+        ctx: ContextId,
+        err_ctx: &impl ErrorContext,
+    ) -> ExecutionError {
+        match err {
+            AdviceError::StackReadFailed => {
+                let (label, source_file) = err_ctx.label_and_source_file();
+                ExecutionError::AdviceStackReadFailed { label, source_file, clk, ctx }
+            },
+            err => ExecutionError::advice_error(err, clk, err_ctx),
+        }
+    }
+
     pub fn divide_by_zero(clk: RowIndex, err_ctx: &impl ErrorContext) -> Self {
         let (label, source_file) = err_ctx.label_and_source_file();
         Self::DivideByZero { clk, label, source_file }
@@ -399,9 +448,24 @@ impl ExecutionError {
         Self::SmtNodePreImageNotValid { label, source_file, node, preimage_len }
     }
 
-    pub fn syscall_target_not_in_kernel(proc_root: Word, err_ctx: &impl ErrorContext) -> Self {
+    pub fn syscall_target_not_in_kernel(
+        proc_root: Word,
+        kernel: &Kernel,
+        err_ctx: &impl ErrorContext,
+    ) -> Self {
+        let (label, source_file) = err_ctx.label_and_source_file();
+        let kernel_proc_roots = kernel.proc_hashes().to_vec();
+        Self::SyscallTargetNotInKernel {
+            label,
+            source_file,
+            proc_root,
+            kernel_proc_roots,
+        }
+    }
+
+    pub fn unsupported_signature_kind(kind: SignatureKind, err_ctx: &impl ErrorContext) -> Self {
         let (label, source_file) = err_ctx.label_and_source_file();
-        Self::SyscallTargetNotInKernel { label, source_file, proc_root }
+        Self::UnsupportedSignatureKind { label, source_file, kind }
     }
 
     pub fn failed_arithmetic_evaluation(err_ctx: &impl ErrorContext, error: AceError) -> Self {
@@ -410,6 +474,16 @@ impl ExecutionError {
     }
 }
 
+/// Returns a description of the procedures contained in a kernel, listing their roots.
+fn describe_kernel_procs(kernel_proc_roots: &[Word]) -> String {
+    if kernel_proc_roots.is_empty() {
+        return "the kernel does not contain any procedures".to_string();
+    }
+
+    let roots: Vec<String> = kernel_proc_roots.iter().map(|root| to_hex(root.as_bytes())).collect();
+    format!("the kernel contains procedures with roots: {}", roots.join(", "))
+}
+
 impl AsRef<dyn Diagnostic> for ExecutionError {
     fn as_ref(&self) -> &(dyn Diagnostic + 'static) {
         self
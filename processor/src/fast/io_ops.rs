@@ -3,9 +3,10 @@ use crate::ErrorContext;
 
 impl FastProcessor {
     /// Analogous to `Process::op_push`.
-    pub fn op_push(&mut self, element: Felt) {
-        self.increment_stack_size();
+    pub fn op_push(&mut self, element: Felt, op_idx: usize) -> Result<(), ExecutionError> {
+        self.increment_stack_size(op_idx)?;
         self.stack_write(0, element);
+        Ok(())
     }
 
     /// Analogous to `Process::op_advpop`.
@@ -15,11 +16,10 @@ impl FastProcessor {
         op_idx: usize,
         err_ctx: &impl ErrorContext,
     ) -> Result<(), ExecutionError> {
-        let value = self
-            .advice
-            .pop_stack()
-            .map_err(|err| ExecutionError::advice_error(err, self.clk + op_idx, err_ctx))?;
-        self.increment_stack_size();
+        let value = self.advice.pop_stack().map_err(|err| {
+            ExecutionError::advice_stack_error(err, self.clk + op_idx, self.ctx, err_ctx)
+        })?;
+        self.increment_stack_size(op_idx)?;
         self.stack_write(0, value);
         Ok(())
     }
@@ -31,10 +31,9 @@ impl FastProcessor {
         op_idx: usize,
         err_ctx: &impl ErrorContext,
     ) -> Result<(), ExecutionError> {
-        let word = self
-            .advice
-            .pop_stack_word()
-            .map_err(|err| ExecutionError::advice_error(err, self.clk + op_idx, err_ctx))?;
+        let word = self.advice.pop_stack_word().map_err(|err| {
+            ExecutionError::advice_stack_error(err, self.clk + op_idx, self.ctx, err_ctx)
+        })?;
         self.stack_write_word(0, &word);
 
         Ok(())
@@ -152,10 +151,9 @@ impl FastProcessor {
         let addr_second_word = addr_first_word + WORD_SIZE_FELT;
 
         // pop two words from the advice stack
-        let words = self
-            .advice
-            .pop_stack_dword()
-            .map_err(|err| ExecutionError::advice_error(err, self.clk + op_idx, err_ctx))?;
+        let words = self.advice.pop_stack_dword().map_err(|err| {
+            ExecutionError::advice_stack_error(err, self.clk + op_idx, self.ctx, err_ctx)
+        })?;
 
         // write the words to memory
         self.memory
@@ -16,9 +16,9 @@ use miden_core::{
 use miden_debug_types::{DefaultSourceManager, SourceManager};
 
 use crate::{
-    AdviceInputs, AdviceProvider, AsyncHost, ContextId, ErrorContext, ExecutionError, FMP_MIN,
-    ProcessState, SYSCALL_FMP_MIN, add_error_ctx_to_external_error, chiplets::Ace, err_ctx,
-    utils::resolve_external_node_async,
+    AdviceInputs, AdviceProvider, AsyncHost, ContextId, ErrorContext, ExecutionError,
+    ExecutionOptions, FMP_MIN, ProcessState, SYSCALL_FMP_MIN, add_error_ctx_to_external_error,
+    chiplets::Ace, err_ctx, utils::resolve_external_node_async,
 };
 
 mod memory;
@@ -144,6 +144,12 @@ pub struct FastProcessor {
     /// return. It is a stack since calls can be nested.
     call_stack: Vec<ExecutionContextInfo>,
 
+    /// The total number of elements in the overflow stacks saved in `call_stack`.
+    saved_overflow_len: usize,
+
+    /// The maximum depth the stack is allowed to reach across all execution contexts.
+    max_stack_depth: usize,
+
     /// Whether to enable debug statements and tracing.
     in_debug_mode: bool,
 
@@ -214,6 +220,8 @@ impl FastProcessor {
             caller_hash: EMPTY_WORD,
             memory: Memory::new(),
             call_stack: Vec::new(),
+            saved_overflow_len: 0,
+            max_stack_depth: usize::MAX,
             ace: Ace::default(),
             in_debug_mode,
             source_manager,
@@ -226,6 +234,17 @@ impl FastProcessor {
         self
     }
 
+    /// Applies the specified [ExecutionOptions] to this processor.
+    ///
+    /// Currently, this applies the maximum depth of the operand stack, the limit on the number of
+    /// elements which can be popped from the advice stack, and the seed of the pseudo-random
+    /// elements backing the advice stack.
+    pub fn with_execution_options(mut self, options: ExecutionOptions) -> Self {
+        self.max_stack_depth = options.max_stack_depth();
+        self.advice = self.advice.with_execution_options(&options);
+        self
+    }
+
     // ACCESSORS
     // -------------------------------------------------------------------------------------------
 
@@ -527,12 +546,19 @@ impl FastProcessor {
             .ok_or(ExecutionError::MastNodeNotFoundInForest { node_id: call_node.callee() })?
             .digest();
 
+        // let the host veto the call before the execution context of the callee is entered
+        host.on_call_enter(&mut self.state(0), callee_hash)?;
+
         self.save_context_and_truncate_stack();
 
         if call_node.is_syscall() {
             // check if the callee is in the kernel
             if !kernel.contains_proc(callee_hash) {
-                return Err(ExecutionError::syscall_target_not_in_kernel(callee_hash, &err_ctx));
+                return Err(ExecutionError::syscall_target_not_in_kernel(
+                    callee_hash,
+                    kernel,
+                    &err_ctx,
+                ));
             }
 
             // set the system registers to the syscall context
@@ -548,6 +574,7 @@ impl FastProcessor {
 
         // Execute the callee.
         self.execute_mast_node(call_node.callee(), program, kernel, host).await?;
+        host.on_call_exit(&mut self.state(0), callee_hash);
 
         // when returning from a function call or a syscall, restore the context of the
         // system registers and the operand stack to what it was prior to
@@ -585,6 +612,11 @@ impl FastProcessor {
                 .map_err(ExecutionError::MemoryError)?
         };
 
+        // let the host veto the call before the execution context of the callee is entered
+        if dyn_node.is_dyncall() {
+            host.on_call_enter(&mut self.state(0), callee_hash)?;
+        }
+
         // Drop the memory address from the stack. This needs to be done BEFORE saving the context,
         // because the next instruction starts with a "shifted left" stack.
         self.decrement_stack_size();
@@ -620,6 +652,7 @@ impl FastProcessor {
 
         // For dyncall, restore the context.
         if dyn_node.is_dyncall() {
+            host.on_call_exit(&mut self.state(0), callee_hash);
             self.restore_context(&err_ctx)?;
         }
 
@@ -819,6 +852,13 @@ impl FastProcessor {
                 let process = &mut self.state(op_idx_in_batch);
                 host.on_trace(process, *id)?;
             },
+            Decorator::ProcScope { name, enter } => {
+                let process = &mut self.state(op_idx_in_batch);
+                host.on_proc_scope(process, name, *enter)?;
+            },
+            Decorator::LoopHint(_) => {
+                // do nothing; no trace is built by the fast processor
+            },
         };
         Ok(())
     }
@@ -856,7 +896,7 @@ impl FastProcessor {
             },
             Operation::FmpAdd => self.op_fmpadd(),
             Operation::FmpUpdate => self.op_fmpupdate()?,
-            Operation::SDepth => self.op_sdepth(),
+            Operation::SDepth => self.op_sdepth(op_idx)?,
             Operation::Caller => self.op_caller()?,
             Operation::Clk => self.op_clk(op_idx)?,
             Operation::Emit(_event_id) => {
@@ -893,7 +933,7 @@ impl FastProcessor {
             Operation::Ext2Mul => self.op_ext2mul(),
 
             // ----- u32 operations ---------------------------------------------------------------
-            Operation::U32split => self.op_u32split(),
+            Operation::U32split => self.op_u32split(op_idx)?,
             Operation::U32add => self.op_u32add(err_ctx)?,
             Operation::U32add3 => self.op_u32add3(err_ctx)?,
             Operation::U32sub => self.op_u32sub(op_idx, err_ctx)?,
@@ -905,20 +945,20 @@ impl FastProcessor {
             Operation::U32assert2(err_code) => self.op_u32assert2(*err_code, err_ctx)?,
 
             // ----- stack manipulation -----------------------------------------------------------
-            Operation::Pad => self.op_pad(),
+            Operation::Pad => self.op_pad(op_idx)?,
             Operation::Drop => self.decrement_stack_size(),
-            Operation::Dup0 => self.dup_nth(0),
-            Operation::Dup1 => self.dup_nth(1),
-            Operation::Dup2 => self.dup_nth(2),
-            Operation::Dup3 => self.dup_nth(3),
-            Operation::Dup4 => self.dup_nth(4),
-            Operation::Dup5 => self.dup_nth(5),
-            Operation::Dup6 => self.dup_nth(6),
-            Operation::Dup7 => self.dup_nth(7),
-            Operation::Dup9 => self.dup_nth(9),
-            Operation::Dup11 => self.dup_nth(11),
-            Operation::Dup13 => self.dup_nth(13),
-            Operation::Dup15 => self.dup_nth(15),
+            Operation::Dup0 => self.dup_nth(0, op_idx)?,
+            Operation::Dup1 => self.dup_nth(1, op_idx)?,
+            Operation::Dup2 => self.dup_nth(2, op_idx)?,
+            Operation::Dup3 => self.dup_nth(3, op_idx)?,
+            Operation::Dup4 => self.dup_nth(4, op_idx)?,
+            Operation::Dup5 => self.dup_nth(5, op_idx)?,
+            Operation::Dup6 => self.dup_nth(6, op_idx)?,
+            Operation::Dup7 => self.dup_nth(7, op_idx)?,
+            Operation::Dup9 => self.dup_nth(9, op_idx)?,
+            Operation::Dup11 => self.dup_nth(11, op_idx)?,
+            Operation::Dup13 => self.dup_nth(13, op_idx)?,
+            Operation::Dup15 => self.dup_nth(15, op_idx)?,
             Operation::Swap => self.op_swap(),
             Operation::SwapW => self.swapw_nth(1),
             Operation::SwapW2 => self.swapw_nth(2),
@@ -942,7 +982,7 @@ impl FastProcessor {
             Operation::CSwapW => self.op_cswapw(err_ctx)?,
 
             // ----- input / output ---------------------------------------------------------------
-            Operation::Push(element) => self.op_push(*element),
+            Operation::Push(element) => self.op_push(*element, op_idx)?,
             Operation::AdvPop => self.op_advpop(op_idx, err_ctx)?,
             Operation::AdvPopW => self.op_advpopw(op_idx, err_ctx)?,
             Operation::MLoadW => self.op_mloadw(op_idx, err_ctx)?,
@@ -971,10 +1011,20 @@ impl FastProcessor {
     /// Increments the stack top pointer by 1.
     ///
     /// The bottom of the stack is never affected by this operation.
+    ///
+    /// # Errors
+    /// Returns an error if the depth of the stack across all execution contexts exceeds the
+    /// maximum allowed depth.
     #[inline(always)]
-    fn increment_stack_size(&mut self) {
+    fn increment_stack_size(&mut self, op_idx: usize) -> Result<(), ExecutionError> {
         self.stack_top_idx += 1;
         self.update_bounds_check_counter();
+
+        let depth = self.stack_size() + self.saved_overflow_len;
+        if depth > self.max_stack_depth {
+            return Err(ExecutionError::StackDepthExceeded { clk: self.clk + op_idx, depth });
+        }
+        Ok(())
     }
 
     /// Decrements the stack top pointer by 1.
@@ -1038,6 +1088,7 @@ impl FastProcessor {
         };
 
         self.stack_bot_idx = self.stack_top_idx - MIN_STACK_DEPTH;
+        self.saved_overflow_len += overflow_stack.len();
 
         self.call_stack.push(ExecutionContextInfo {
             overflow_stack,
@@ -1078,6 +1129,7 @@ impl FastProcessor {
             self.stack[range(self.stack_bot_idx - overflow_len, overflow_len)]
                 .copy_from_slice(&ctx_info.overflow_stack);
             self.stack_bot_idx -= overflow_len;
+            self.saved_overflow_len -= overflow_len;
         }
 
         // restore system parameters
@@ -3,7 +3,8 @@ use miden_core::{Felt, mast::MastForest, sys_events::SystemEvent};
 use super::{ExecutionError, FastProcessor, ONE};
 use crate::{
     AsyncHost, BaseHost, ErrorContext, FMP_MIN,
-    operations::sys_ops::sys_event_handlers::handle_system_event, system::FMP_MAX,
+    operations::sys_ops::sys_event_handlers::{handle_system_event, insert_host_signing_key},
+    system::FMP_MAX,
 };
 
 impl FastProcessor {
@@ -56,10 +57,11 @@ impl FastProcessor {
     }
 
     /// Analogous to `Process::op_sdepth`.
-    pub fn op_sdepth(&mut self) {
+    pub fn op_sdepth(&mut self, op_idx: usize) -> Result<(), ExecutionError> {
         let depth = self.stack_depth();
-        self.increment_stack_size();
+        self.increment_stack_size(op_idx)?;
         self.stack_write(0, depth.into());
+        Ok(())
     }
 
     /// Analogous to `Process::op_caller`.
@@ -76,7 +78,7 @@ impl FastProcessor {
 
     /// Analogous to `Process::op_clk`.
     pub fn op_clk(&mut self, op_idx: usize) -> Result<(), ExecutionError> {
-        self.increment_stack_size();
+        self.increment_stack_size(op_idx)?;
         self.stack_write(0, (self.clk + op_idx).into());
         Ok(())
     }
@@ -93,6 +95,9 @@ impl FastProcessor {
         let process = &mut self.state(op_idx);
         // If it's a system event, handle it directly. Otherwise, forward it to the host.
         if let Some(system_event) = SystemEvent::from_event_id(event_id) {
+            if let SystemEvent::Signature { kind } = system_event {
+                insert_host_signing_key(process, host, kind);
+            }
             handle_system_event(process, system_event, err_ctx)
         } else {
             host.on_event(process, event_id, err_ctx).await
@@ -231,10 +231,7 @@ fn test_syscall_fail() {
     let err = processor.execute_sync(&program, &mut host).unwrap_err();
 
     // Check that the error is due to the syscall target not being in the kernel
-    assert_matches!(
-        err,
-        ExecutionError::SyscallTargetNotInKernel { label: _, source_file: _, proc_root: _ }
-    );
+    assert_matches!(err, ExecutionError::SyscallTargetNotInKernel { .. });
 }
 
 #[test]
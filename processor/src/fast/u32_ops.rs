@@ -6,13 +6,14 @@ use crate::{ErrorContext, ExecutionError, utils::split_element};
 impl FastProcessor {
     /// Analogous to `Process::op_u32split`.
     #[inline(always)]
-    pub fn op_u32split(&mut self) {
+    pub fn op_u32split(&mut self, op_idx: usize) -> Result<(), ExecutionError> {
         let top = self.stack_get(0);
         let (hi, lo) = split_element(top);
 
-        self.increment_stack_size();
+        self.increment_stack_size(op_idx)?;
         self.stack_write(0, hi);
         self.stack_write(1, lo);
+        Ok(())
     }
 
     /// Analogous to `Process::op_u32add`.
@@ -94,10 +95,10 @@ impl FastProcessor {
 
             // Check that a, b, and c are u32 values.
             if b > u32::MAX as u64 {
-                return Err(ExecutionError::not_u32_value(Felt::new(a), ZERO, err_ctx));
+                return Err(ExecutionError::not_u32_value(Felt::new(b), ZERO, err_ctx));
             }
             if a > u32::MAX as u64 {
-                return Err(ExecutionError::not_u32_value(Felt::new(b), ZERO, err_ctx));
+                return Err(ExecutionError::not_u32_value(Felt::new(a), ZERO, err_ctx));
             }
             if c > u32::MAX as u64 {
                 return Err(ExecutionError::not_u32_value(Felt::new(c), ZERO, err_ctx));
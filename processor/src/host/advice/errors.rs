@@ -19,6 +19,19 @@ pub enum AdviceError {
     MapKeyNotFound { key: Word },
     #[error("advice stack read failed")]
     StackReadFailed,
+    #[error("advice stack pop limit of {limit} elements exceeded")]
+    #[diagnostic(help(
+        "this could be caused by a bug leading to runaway advice consumption, or the limit may need to be increased"
+    ))]
+    StackPopLimitExceeded { limit: usize },
+    #[error("cannot push {count} random elements onto the advice stack; the maximum is {max}")]
+    TooManyRandomElements { count: u32, max: u32 },
+    #[error("cannot insert {count} words from memory into the advice map; the maximum is {max}")]
+    TooManyMemWords { count: u32, max: u32 },
+    #[error("cannot invert {count} elements in a single batch; the maximum is {max}")]
+    TooManyBatchInvElements { count: u32, max: u32 },
+    #[error("cannot hash a message of {len} bytes; the maximum is {max}")]
+    MessageTooLong { len: u32, max: u32 },
     #[error(
         "provided merkle tree {depth} is out of bounds and cannot be represented as an unsigned 8-bit integer"
     )]
@@ -2,9 +2,14 @@ use alloc::{collections::BTreeMap, vec::Vec};
 
 use miden_core::{
     AdviceMap, Felt, Word,
-    crypto::merkle::{MerklePath, MerkleStore, NodeIndex, StoreNode},
+    crypto::{
+        merkle::{MerklePath, MerkleStore, NodeIndex, StoreNode},
+        random::{FeltRng, RpoRandomCoin},
+    },
 };
 
+use crate::ExecutionOptions;
+
 mod inputs;
 pub use inputs::AdviceInputs;
 
@@ -34,23 +39,78 @@ type SimpleMerkleMap = BTreeMap<Word, StoreNode>;
 ///    the store.
 ///
 /// Advice data is store in-memory using [BTreeMap]s as its backing storage.
+///
+/// Optionally, the total number of elements which can be popped from the advice stack can be
+/// limited (see [AdviceProvider::with_pop_limit] and [ExecutionOptions::with_max_advice_pops]). By
+/// default, the number of pops is unbounded.
+///
+/// Also optionally, the advice stack can be backed by a deterministic source of pseudo-random
+/// elements (see [AdviceProvider::with_seed] and [ExecutionOptions::with_advice_seed]), which is
+/// useful for fuzzing programs without having to construct their advice inputs.
 #[derive(Debug, Clone, Default)]
 pub struct AdviceProvider {
     pub stack: Vec<Felt>,
     pub map: AdviceMap,
     pub store: MerkleStore<SimpleMerkleMap>,
+    pop_limit: Option<usize>,
+    num_popped: usize,
+    rng: Option<RpoRandomCoin>,
 }
 
 impl AdviceProvider {
+    // CONSTRUCTORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Limits the total number of elements which can be popped from the advice stack to `limit`.
+    ///
+    /// Once the limit is reached, any further attempt to pop elements from the advice stack
+    /// results in an error. This is useful for catching bugs which lead to runaway advice
+    /// consumption.
+    pub fn with_pop_limit(mut self, limit: usize) -> Self {
+        self.pop_limit = Some(limit);
+        self
+    }
+
+    /// Backs the advice stack with elements drawn from an [RpoRandomCoin] instantiated with
+    /// `seed`.
+    ///
+    /// Whenever the advice stack does not contain enough elements to satisfy a pop, the missing
+    /// elements are drawn from the random coin on demand, rather than failing. Elements which
+    /// were pushed onto the advice stack explicitly are always popped before any drawn elements.
+    /// Thus, the same seed always yields the same sequence of advice.
+    ///
+    /// Only the advice stack is affected; the advice map and the Merkle store are not.
+    pub fn with_seed(mut self, seed: Word) -> Self {
+        self.rng = Some(RpoRandomCoin::new(seed));
+        self
+    }
+
+    /// Applies the advice-related settings of the specified [ExecutionOptions] to this advice
+    /// provider.
+    pub(crate) fn with_execution_options(mut self, options: &ExecutionOptions) -> Self {
+        if let Some(limit) = options.max_advice_pops() {
+            self = self.with_pop_limit(limit);
+        }
+        if let Some(seed) = options.advice_seed() {
+            self = self.with_seed(seed);
+        }
+        self
+    }
+
     // ADVICE STACK
     // --------------------------------------------------------------------------------------------
 
     /// Pops an element from the advice stack and returns it.
     ///
     /// # Errors
-    /// Returns an error if the advice stack is empty.
+    /// Returns an error if:
+    /// - The advice stack is empty.
+    /// - Popping the element would exceed the pop limit of this advice provider.
     pub fn pop_stack(&mut self) -> Result<Felt, AdviceError> {
-        self.stack.pop().ok_or(AdviceError::StackReadFailed)
+        self.ensure_stack_len(1)?;
+        self.consume_pop_budget(1)?;
+
+        Ok(self.stack.pop().expect("advice stack is not empty"))
     }
 
     /// Pops a word (4 elements) from the advice stack and returns it.
@@ -59,11 +119,12 @@ impl AdviceProvider {
     /// stack (i.e., `d` is at the top of the stack) will yield `[d, c, b, a]`.
     ///
     /// # Errors
-    /// Returns an error if the advice stack does not contain a full word.
+    /// Returns an error if:
+    /// - The advice stack does not contain a full word.
+    /// - Popping the word would exceed the pop limit of this advice provider.
     pub fn pop_stack_word(&mut self) -> Result<Word, AdviceError> {
-        if self.stack.len() < 4 {
-            return Err(AdviceError::StackReadFailed);
-        }
+        self.ensure_stack_len(4)?;
+        self.consume_pop_budget(4)?;
 
         let idx = self.stack.len() - 4;
         let result =
@@ -81,12 +142,26 @@ impl AdviceProvider {
     /// two words: `[h, g, f,e ], [d, c, b, a]`.
     ///
     /// # Errors
-    /// Returns an error if the advice stack does not contain two words.
+    /// Returns an error if:
+    /// - The advice stack does not contain two words.
+    /// - Popping the words would exceed the pop limit of this advice provider.
     pub fn pop_stack_dword(&mut self) -> Result<[Word; 2], AdviceError> {
-        let word0 = self.pop_stack_word()?;
-        let word1 = self.pop_stack_word()?;
+        self.ensure_stack_len(8)?;
+        self.consume_pop_budget(8)?;
+
+        let idx = self.stack.len() - 8;
+        let word0 = [
+            self.stack[idx + 7],
+            self.stack[idx + 6],
+            self.stack[idx + 5],
+            self.stack[idx + 4],
+        ];
+        let word1 =
+            [self.stack[idx + 3], self.stack[idx + 2], self.stack[idx + 1], self.stack[idx]];
+
+        self.stack.truncate(idx);
 
-        Ok([word0, word1])
+        Ok([word0.into(), word1.into()])
     }
 
     /// Pushes a single value onto the advice stack.
@@ -255,12 +330,199 @@ impl AdviceProvider {
     pub fn has_merkle_root(&self, root: Word) -> bool {
         self.store.get_node(root, NodeIndex::root()).is_ok()
     }
+
+    // SNAPSHOTS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a snapshot of the current state of this advice provider.
+    ///
+    /// The snapshot captures the advice stack, the advice map, the Merkle store, and the number of
+    /// elements popped so far. It can later be passed to [AdviceProvider::restore()] to revert
+    /// any changes made in the meantime, e.g., when backtracking during speculative execution.
+    pub fn snapshot(&self) -> AdviceSnapshot {
+        AdviceSnapshot {
+            stack: self.stack.clone(),
+            map: self.map.clone(),
+            store: self.store.clone(),
+            num_popped: self.num_popped,
+            rng: self.rng,
+        }
+    }
+
+    /// Reverts this advice provider to the state captured by the specified snapshot.
+    ///
+    /// The pop limit of the advice provider is not affected.
+    pub fn restore(&mut self, snapshot: AdviceSnapshot) {
+        let AdviceSnapshot { stack, map, store, num_popped, rng } = snapshot;
+        self.stack = stack;
+        self.map = map;
+        self.store = store;
+        self.num_popped = num_popped;
+        self.rng = rng;
+    }
+
+    // HELPER METHODS
+    // --------------------------------------------------------------------------------------------
+
+    /// Ensures that the advice stack contains at least `len` elements, drawing the missing ones
+    /// from the random coin of this advice provider, if it has one.
+    ///
+    /// Drawn elements are inserted beneath the existing elements of the advice stack, in the
+    /// order in which they were drawn (i.e., the first drawn element is popped first).
+    ///
+    /// # Errors
+    /// Returns an error if the advice stack contains fewer than `len` elements, and this advice
+    /// provider has no random coin.
+    fn ensure_stack_len(&mut self, len: usize) -> Result<(), AdviceError> {
+        let Some(num_missing) = len.checked_sub(self.stack.len()).filter(|&n| n > 0) else {
+            return Ok(());
+        };
+        let rng = self.rng.as_mut().ok_or(AdviceError::StackReadFailed)?;
+        let drawn: Vec<Felt> = (0..num_missing).map(|_| rng.draw_element()).collect();
+        self.stack.splice(0..0, drawn.into_iter().rev());
+
+        Ok(())
+    }
+
+    /// Records that `num_elements` elements are about to be popped from the advice stack.
+    ///
+    /// # Errors
+    /// Returns an error if popping the elements would exceed the pop limit of this advice
+    /// provider.
+    fn consume_pop_budget(&mut self, num_elements: usize) -> Result<(), AdviceError> {
+        let num_popped = self.num_popped + num_elements;
+        if let Some(limit) = self.pop_limit
+            && num_popped > limit
+        {
+            return Err(AdviceError::StackPopLimitExceeded { limit });
+        }
+        self.num_popped = num_popped;
+
+        Ok(())
+    }
 }
 
 impl From<AdviceInputs> for AdviceProvider {
     fn from(inputs: AdviceInputs) -> Self {
         let (mut stack, map, store) = inputs.into_parts();
         stack.reverse();
-        Self { stack, map, store }
+        Self {
+            stack,
+            map,
+            store,
+            pop_limit: None,
+            num_popped: 0,
+            rng: None,
+        }
+    }
+}
+
+// ADVICE SNAPSHOT
+// ================================================================================================
+
+/// An opaque snapshot of the state of an [AdviceProvider], created via
+/// [AdviceProvider::snapshot()].
+#[derive(Debug, Clone)]
+pub struct AdviceSnapshot {
+    stack: Vec<Felt>,
+    map: AdviceMap,
+    store: MerkleStore<SimpleMerkleMap>,
+    num_popped: usize,
+    rng: Option<RpoRandomCoin>,
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use miden_core::{Felt, Word, crypto::merkle::MerkleTree};
+
+    use super::{AdviceInputs, AdviceProvider};
+
+    #[test]
+    fn snapshot_restore() {
+        let key = Word::from([1_u32, 2, 3, 4]);
+        let leaves = [Word::from([5_u32, 6, 7, 8]), Word::from([9_u32, 10, 11, 12])];
+        let tree = MerkleTree::new(leaves).unwrap();
+
+        let inputs = AdviceInputs::default()
+            .with_stack_values([1, 2, 3])
+            .unwrap()
+            .with_map([(key, vec![Felt::new(7)])]);
+        let mut advice = AdviceProvider::from(inputs).with_pop_limit(3);
+        advice.store.extend(tree.inner_nodes());
+
+        let snapshot = advice.snapshot();
+
+        // mutate every component of the advice provider
+        assert_eq!(advice.pop_stack().unwrap(), Felt::new(1));
+        advice.push_stack(Felt::new(42));
+        advice.insert_into_map(key, vec![Felt::new(8), Felt::new(9)]);
+        let (_, new_root) = advice
+            .update_merkle_node(tree.root(), &Felt::new(1), &Felt::new(0), leaves[1])
+            .unwrap();
+        assert_eq!(advice.pop_stack().unwrap(), Felt::new(42));
+        assert!(advice.pop_stack().is_ok());
+        assert!(advice.pop_stack().is_err());
+
+        advice.restore(snapshot);
+
+        assert_eq!(advice.stack(), &[Felt::new(3), Felt::new(2), Felt::new(1)]);
+        assert_eq!(advice.get_mapped_values(&key).unwrap(), &[Felt::new(7)]);
+        assert!(advice.has_merkle_root(tree.root()));
+        assert!(!advice.has_merkle_root(new_root));
+
+        // the pop budget consumed after the snapshot was taken is restored as well
+        assert_eq!(advice.pop_stack().unwrap(), Felt::new(1));
+        assert_eq!(advice.pop_stack().unwrap(), Felt::new(2));
+        assert_eq!(advice.pop_stack().unwrap(), Felt::new(3));
+    }
+
+    #[test]
+    fn pop_stack_dword_is_atomic() {
+        let inputs = AdviceInputs::default().with_stack_values(1..=8).unwrap();
+
+        // exceeding the pop limit leaves the advice stack untouched
+        let mut advice = AdviceProvider::from(inputs.clone()).with_pop_limit(6);
+        assert!(advice.pop_stack_dword().is_err());
+        assert_eq!(advice.stack().len(), 8);
+
+        // so does popping more elements than the advice stack holds
+        let mut advice = AdviceProvider::from(inputs);
+        advice.pop_stack().unwrap();
+        assert!(advice.pop_stack_dword().is_err());
+        assert_eq!(advice.stack().len(), 7);
+    }
+
+    #[test]
+    fn seeded_advice() {
+        let seed = Word::from([1_u32, 2, 3, 4]);
+        let pop_all = |mut advice: AdviceProvider| {
+            let mut values = vec![advice.pop_stack().unwrap()];
+            values.extend(advice.pop_stack_word().unwrap());
+            let [word0, word1] = advice.pop_stack_dword().unwrap();
+            values.extend(word0);
+            values.extend(word1);
+            values
+        };
+
+        // the same seed yields the same advice
+        let values = pop_all(AdviceProvider::default().with_seed(seed));
+        assert_eq!(values.len(), 13);
+        assert_eq!(pop_all(AdviceProvider::default().with_seed(seed)), values);
+
+        // different seeds yield different advice
+        let other_seed = Word::from([5_u32, 6, 7, 8]);
+        assert_ne!(pop_all(AdviceProvider::default().with_seed(other_seed)), values);
+
+        // explicitly provided advice is consumed before any drawn advice
+        let inputs = AdviceInputs::default().with_stack_values([42, 43]).unwrap();
+        let mut advice = AdviceProvider::from(inputs).with_seed(seed);
+        let word = advice.pop_stack_word().unwrap();
+        assert_eq!(word, Word::from([Felt::new(42), Felt::new(43), values[0], values[1]]));
+
+        // without a seed, popping from an empty advice stack fails
+        assert!(AdviceProvider::default().pop_stack().is_err());
     }
 }
@@ -1,7 +1,8 @@
-use alloc::vec::Vec;
-use std::{cmp::min, println, string::ToString};
+use alloc::{string::String, vec::Vec};
+use core::fmt::{self, Write};
+use std::{cmp::min, print};
 
-use miden_core::{DebugOptions, Felt};
+use miden_core::{DebugOptions, Felt, StarkField, WatchedFieldOp, Word, utils::DisplayHex};
 
 use crate::{MemoryAddress, ProcessState};
 
@@ -10,34 +11,37 @@ use crate::{MemoryAddress, ProcessState};
 
 /// Prints the info about the VM state specified by the provided options to stdout.
 pub fn print_debug_info(process: &ProcessState, options: &DebugOptions) {
+    let mut output = String::new();
+    write_debug_info(&mut output, process, options).expect("failed to write debug info");
+    print!("{output}");
+}
+
+/// Writes the info about the VM state specified by the provided options into the provided writer.
+pub(crate) fn write_debug_info(
+    f: &mut impl Write,
+    process: &ProcessState,
+    options: &DebugOptions,
+) -> fmt::Result {
     match *options {
-        DebugOptions::StackAll => {
-            print_vm_stack(process, None);
-        },
-        DebugOptions::StackTop(n) => {
-            print_vm_stack(process, Some(n));
-        },
-        DebugOptions::MemAll => {
-            print_mem_all(process);
-        },
-        DebugOptions::MemInterval(n, m) => {
-            print_mem_interval(process, n, m);
-        },
+        DebugOptions::StackAll => write_vm_stack(f, process, None),
+        DebugOptions::StackTop(n) => write_vm_stack(f, process, Some(n)),
+        DebugOptions::StackHex(n) => write_vm_stack_hex(f, process, n),
+        DebugOptions::MemAll => write_mem_all(f, process),
+        DebugOptions::MemInterval(n, m) => write_mem_interval(f, process, n, m),
         DebugOptions::LocalInterval(n, m, num_locals) => {
-            print_local_interval(process, n, m, num_locals as u32);
-        },
-        DebugOptions::AdvStackTop(n) => {
-            print_vm_adv_stack(process, n);
+            write_local_interval(f, process, n, m, num_locals as u32)
         },
+        DebugOptions::AdvStackTop(n) => write_vm_adv_stack(f, process, n),
+        DebugOptions::WatchField(op, n) => write_field_wraparound_warning(f, process, op, n),
     }
 }
 
 // HELPER FUNCTIONS
 // ================================================================================================
 
-/// Prints the number of stack items specified by `n` if it is provided, otherwise prints
+/// Writes the number of stack items specified by `n` if it is provided, otherwise writes
 /// the whole stack.
-fn print_vm_stack(process: &ProcessState, n: Option<u8>) {
+fn write_vm_stack(f: &mut impl Write, process: &ProcessState, n: Option<u8>) -> fmt::Result {
     let stack = process.get_stack_state();
 
     // if n is empty, print the entire stack
@@ -51,24 +55,55 @@ fn print_vm_stack(process: &ProcessState, n: Option<u8>) {
 
     if let Some((last, front)) = stack.split_last() {
         // print all items except for the last one
-        println!("Stack state before step {}:", process.clk());
+        writeln!(f, "Stack state before step {}:", process.clk())?;
         for (i, element) in front.iter().enumerate() {
-            println!("├── {i:>2}: {element}");
+            writeln!(f, "├── {i:>2}: {element}")?;
         }
 
         // print the last item, and in case the stack has more items, print the total number of
         // un-printed items
         let i = num_items - 1;
-        println!("└── {i:>2}: {last}\n");
-        println!("└── ({} more items)\n", stack.len() - num_items);
+        writeln!(f, "└── {i:>2}: {last}\n")?;
+        writeln!(f, "└── ({} more items)\n", stack.len() - num_items)
+    } else {
+        writeln!(f, "Stack empty before step {}.", process.clk())
+    }
+}
+
+/// Writes the number of stack words specified by `n` as hex-encoded bytes. If `n` is 0 it writes
+/// all words of the stack.
+///
+/// The elements of each word are taken in reverse stack order, i.e., the topmost element of a word
+/// is its last element, so that a word pushed via `push.0x...` is written as the same hex string.
+fn write_vm_stack_hex(f: &mut impl Write, process: &ProcessState, n: u8) -> fmt::Result {
+    let stack = process.get_stack_state();
+    let words: Vec<_> = stack
+        .chunks_exact(4)
+        .map(|elements| Word::new([elements[3], elements[2], elements[1], elements[0]]))
+        .collect();
+
+    // If n = 0 print all words of the stack
+    let num_words = if n == 0 {
+        words.len()
     } else {
-        println!("Stack empty before step {}.", process.clk());
+        min(words.len(), n as usize)
+    };
+
+    writeln!(f, "Stack words before step {}:", process.clk())?;
+    for (i, word) in words[..num_words].iter().enumerate() {
+        let prefix = if i + 1 == num_words { "└──" } else { "├──" };
+        writeln!(f, "{prefix} {i:>2}: {:#x}", DisplayHex(word.as_bytes().as_slice()))?;
     }
+
+    Ok(())
 }
 
-/// Prints length items from the top of the  advice stack. If length is 0 it returns the whole
+/// Writes length items from the top of the advice stack. If length is 0 it writes the whole
 /// stack.
-fn print_vm_adv_stack(process: &ProcessState, n: u16) {
+///
+/// If the advice stack contains fewer items than requested, all available items are written
+/// followed by a note about the number of missing items.
+fn write_vm_adv_stack(f: &mut impl Write, process: &ProcessState, n: u16) -> fmt::Result {
     let stack = process.advice_provider().stack();
 
     // If n = 0 print the entire stack
@@ -78,24 +113,64 @@ fn print_vm_adv_stack(process: &ProcessState, n: u16) {
         min(stack.len(), n as usize)
     };
 
-    let stack = &stack[..num_items];
+    // the top of the advice stack is located at the end of the underlying vector
+    let stack: Vec<_> = stack.iter().rev().take(num_items).collect();
 
     if let Some((last, front)) = stack.split_last() {
         // print all items except for the last one
-        println!("Advice Stack state before step {}:", process.clk());
+        writeln!(f, "Advice Stack state before step {}:", process.clk())?;
         for (i, element) in front.iter().enumerate() {
-            println!("├── {i:>2}: {element}");
+            writeln!(f, "├── {i:>2}: {element}")?;
         }
 
         let i = num_items - 1;
-        println!("└── {i:>2}: {last}\n");
+        writeln!(f, "└── {i:>2}: {last}\n")?;
     } else {
-        println!("Advice Stack empty before step {}.", process.clk());
+        writeln!(f, "Advice Stack empty before step {}.", process.clk())?;
+    }
+
+    // in case the advice stack has fewer items than requested, print the number of missing items
+    let num_missing = (n as usize).saturating_sub(num_items);
+    if num_missing > 0 {
+        writeln!(f, "└── ({num_missing} items requested but not available)")?;
+    }
+
+    Ok(())
+}
+
+/// Writes a warning if applying the specified field operation to the stack items at positions `n`
+/// and `n + 1` would wrap around the field modulus. Nothing is written otherwise.
+fn write_field_wraparound_warning(
+    f: &mut impl Write,
+    process: &ProcessState,
+    op: WatchedFieldOp,
+    n: u8,
+) -> fmt::Result {
+    let stack = process.get_stack_state();
+    let a = stack.get(n as usize).copied().unwrap_or_default();
+    let b = stack.get(n as usize + 1).copied().unwrap_or_default();
+
+    let (a_int, b_int) = (a.as_int() as u128, b.as_int() as u128);
+    let (wraps, op_name, op_symbol) = match op {
+        WatchedFieldOp::Add => (a_int + b_int >= Felt::MODULUS as u128, "addition", "+"),
+        WatchedFieldOp::Mul => (a_int * b_int >= Felt::MODULUS as u128, "multiplication", "*"),
+    };
+
+    if wraps {
+        writeln!(
+            f,
+            "Warning: field {op_name} of stack items {} and {} wraps around the modulus before step {}: {a} {op_symbol} {b}",
+            n,
+            n as usize + 1,
+            process.clk()
+        )?;
     }
+
+    Ok(())
 }
 
-/// Prints the whole memory state at the cycle `clk` in context `ctx`.
-fn print_mem_all(process: &ProcessState) {
+/// Writes the whole memory state at the cycle `clk` in context `ctx`.
+fn write_mem_all(f: &mut impl Write, process: &ProcessState) -> fmt::Result {
     let mem = process.get_mem_state(process.ctx());
     let element_width = mem
         .iter()
@@ -103,50 +178,62 @@ fn print_mem_all(process: &ProcessState) {
         .max()
         .unwrap_or(0);
 
-    println!("Memory state before step {} for the context {}:", process.clk(), process.ctx());
+    writeln!(
+        f,
+        "Memory state before step {} for the context {}:",
+        process.clk(),
+        process.ctx()
+    )?;
 
     if let Some(((last_addr, last_value), front)) = mem.split_last() {
         // print the main part of the memory (wihtout the last value)
         for (addr, value) in front.iter() {
-            print_mem_address(*addr, Some(*value), false, false, element_width);
+            write_mem_address(f, *addr, Some(*value), false, false, element_width)?;
         }
 
         // print the last memory value
-        print_mem_address(*last_addr, Some(*last_value), true, false, element_width);
+        write_mem_address(f, *last_addr, Some(*last_value), true, false, element_width)?;
     }
+
+    Ok(())
 }
 
-/// Prints memory values in the provided addresses interval.
-fn print_mem_interval(process: &ProcessState, n: u32, m: u32) {
-    let addr_range = n..m + 1;
-    let mem_interval: Vec<_> = addr_range
-        .map(|addr| (MemoryAddress(addr), process.get_mem_value(process.ctx(), addr)))
-        .collect();
+/// Writes memory values in the provided addresses interval.
+fn write_mem_interval(f: &mut impl Write, process: &ProcessState, n: u32, m: u32) -> fmt::Result {
+    let mem_interval = process.get_mem_interval(process.ctx(), n, m);
 
     if n == m {
-        println!(
+        writeln!(
+            f,
             "Memory state before step {} for the context {} at address {}:",
             process.clk(),
             process.ctx(),
             n
-        )
+        )?;
     } else {
-        println!(
+        writeln!(
+            f,
             "Memory state before step {} for the context {} in the interval [{}, {}]:",
             process.clk(),
             process.ctx(),
             n,
             m
-        )
+        )?;
     };
 
-    print_interval(mem_interval, false);
+    write_interval(f, mem_interval, false)
 }
 
-/// Prints locals in provided indexes interval.
+/// Writes locals in provided indexes interval.
 ///
 /// The interval given is inclusive on *both* ends.
-fn print_local_interval(process: &ProcessState, start: u16, end: u16, num_locals: u32) {
+fn write_local_interval(
+    f: &mut impl Write,
+    process: &ProcessState,
+    start: u16,
+    end: u16,
+    num_locals: u32,
+) -> fmt::Result {
     let local_memory_offset = process.fmp() as u32 - num_locals;
 
     // Account for a case where start is 0 and end is 2^16. In that case we should simply print
@@ -166,21 +253,25 @@ fn print_local_interval(process: &ProcessState, start: u16, end: u16, num_locals
         .collect();
 
     if start != end {
-        println!("State of procedure locals [{start}, {end}] before step {}:", process.clk());
+        writeln!(f, "State of procedure locals [{start}, {end}] before step {}:", process.clk())?;
     } else {
-        println!("State of procedure local {start} before step {}:", process.clk());
+        writeln!(f, "State of procedure local {start} before step {}:", process.clk())?;
     }
-    print_interval(locals, true);
+    write_interval(f, locals, true)
 }
 
 // HELPER FUNCTIONS
 // ================================================================================================
 
-/// Prints the provided memory interval.
+/// Writes the provided memory interval.
 ///
 /// If `is_local` is true, the output addresses are formatted as decimal values, otherwise as hex
 /// strings.
-fn print_interval(mem_interval: Vec<(MemoryAddress, Option<Felt>)>, is_local: bool) {
+fn write_interval(
+    f: &mut impl Write,
+    mem_interval: Vec<(MemoryAddress, Option<Felt>)>,
+    is_local: bool,
+) -> fmt::Result {
     let element_width = mem_interval
         .iter()
         .filter_map(|(_addr, value)| value.map(element_printed_width))
@@ -190,29 +281,32 @@ fn print_interval(mem_interval: Vec<(MemoryAddress, Option<Felt>)>, is_local: bo
     if let Some(((last_addr, last_value), front_elements)) = mem_interval.split_last() {
         // print the main part of the memory (wihtout the last value)
         for (addr, mem_value) in front_elements {
-            print_mem_address(*addr, *mem_value, false, is_local, element_width)
+            write_mem_address(f, *addr, *mem_value, false, is_local, element_width)?;
         }
 
         // print the last memory value
-        print_mem_address(*last_addr, *last_value, true, is_local, element_width);
+        write_mem_address(f, *last_addr, *last_value, true, is_local, element_width)?;
     }
+
+    Ok(())
 }
 
-/// Prints single memory value with its address.
+/// Writes single memory value with its address.
 ///
 /// If `is_local` is true, the output address is formatted as decimal value, otherwise as hex
 /// string.
-fn print_mem_address(
+fn write_mem_address(
+    f: &mut impl Write,
     addr: MemoryAddress,
     mem_value: Option<Felt>,
     is_last: bool,
     is_local: bool,
     element_width: u32,
-) {
+) -> fmt::Result {
     let value_string = if let Some(value) = mem_value {
         format!("{:>width$}", value, width = element_width as usize)
     } else {
-        "EMPTY".to_string()
+        "EMPTY".into()
     };
 
     let addr_string = if is_local {
@@ -222,9 +316,9 @@ fn print_mem_address(
     };
 
     if is_last {
-        println!("└── {addr_string}: {value_string}");
+        writeln!(f, "└── {addr_string}: {value_string}")
     } else {
-        println!("├── {addr_string}: {value_string}");
+        writeln!(f, "├── {addr_string}: {value_string}")
     }
 }
 
@@ -232,3 +326,196 @@ fn print_mem_address(
 fn element_printed_width(element: Felt) -> u32 {
     element.as_int().checked_ilog10().unwrap_or(1) + 1
 }
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use alloc::{string::String, sync::Arc, vec::Vec};
+
+    use miden_assembly::Assembler;
+    use miden_core::{DebugOptions, Felt, StarkField, Word, mast::MastForest};
+
+    use super::write_debug_info;
+    use crate::{
+        AdviceInputs, BaseHost, ErrorContext, ExecutionError, ExecutionOptions, Kernel, Process,
+        ProcessState, StackInputs, StackOutputs, SyncHost,
+    };
+
+    #[test]
+    fn debug_mem_interval() {
+        let source = "
+        begin
+            push.1.2.3.4 mem_storew.4 dropw
+            push.5 mem_store.9
+            debug.mem.4.12
+        end";
+
+        let (output, _) = run_debug_program(source, &[], Vec::new());
+        assert_eq!(output.len(), 1);
+
+        // memory is accessed in words, and so the untouched elements of the word at address 8 are
+        // reported as zeros, while the never accessed address 12 is marked as empty
+        let lines: Vec<_> = output[0].lines().collect();
+        assert_eq!(
+            lines,
+            [
+                "Memory state before step 17 for the context 0 in the interval [4, 12]:",
+                "├── 0x00000004: 1",
+                "├── 0x00000005: 2",
+                "├── 0x00000006: 3",
+                "├── 0x00000007: 4",
+                "├── 0x00000008: 0",
+                "├── 0x00000009: 5",
+                "├── 0x0000000a: 0",
+                "├── 0x0000000b: 0",
+                "└── 0x0000000c: EMPTY",
+            ]
+        );
+    }
+
+    #[test]
+    fn debug_adv_stack() {
+        let source = "
+        begin
+            debug.adv_stack.2
+            debug.adv_stack.5
+            adv_push.3
+            movupw.3 dropw
+        end";
+
+        let (debug_output, stack_outputs) = run_debug_program(source, &[], vec![7, 8, 9]);
+
+        // the printed elements should be the ones which are subsequently popped from the advice
+        // stack, in the same order (i.e., the first popped element ends up deepest on the stack)
+        assert_eq!(stack_outputs.stack_truncated(3), &[Felt::new(9), Felt::new(8), Felt::new(7)]);
+
+        assert_eq!(
+            debug_output[0].lines().collect::<Vec<_>>(),
+            ["Advice Stack state before step 1:", "├──  0: 7", "└──  1: 8", ""]
+        );
+        assert_eq!(
+            debug_output[1].lines().collect::<Vec<_>>(),
+            [
+                "Advice Stack state before step 1:",
+                "├──  0: 7",
+                "├──  1: 8",
+                "└──  2: 9",
+                "",
+                "└── (2 items requested but not available)"
+            ]
+        );
+    }
+
+    #[test]
+    fn debug_stack_hex() {
+        let source = "
+        begin
+            push.0x0100000000000000020000000000000003000000000000000400000000000000
+            push.0xabcd000000000000000000000000000000000000000000000000000000000000
+            debug.stack.hex.2
+            dropw dropw
+        end";
+
+        let (debug_output, _) = run_debug_program(source, &[], vec![]);
+
+        // each word is written as the hex string it was pushed with, starting with the top word
+        assert_eq!(
+            debug_output[0].lines().collect::<Vec<_>>(),
+            [
+                "Stack words before step 10:",
+                "├──  0: 0xabcd000000000000000000000000000000000000000000000000000000000000",
+                "└──  1: 0x0100000000000000020000000000000003000000000000000400000000000000",
+            ]
+        );
+    }
+
+    #[test]
+    fn debug_watch_field() {
+        let source = "
+        begin
+            debug.watch_field.add
+            debug.watch_field.mul.1
+            add
+            push.3
+            debug.watch_field.add
+            debug.watch_field.mul
+            mul
+        end";
+
+        let (debug_output, stack_outputs) =
+            run_debug_program(source, &[Felt::MODULUS - 1, 2], vec![]);
+        assert_eq!(stack_outputs.stack_truncated(1), &[Felt::new(3)]);
+
+        // only the addition of `2` and `p - 1` wraps around the modulus; `(p - 1) * 0`, `3 + 1`
+        // and `3 * 1` do not
+        assert_eq!(
+            debug_output[0].lines().collect::<Vec<_>>(),
+            [format!(
+                "Warning: field addition of stack items 0 and 1 wraps around the modulus before step 1: 2 + {}",
+                Felt::MODULUS - 1
+            )]
+        );
+        assert_eq!(debug_output[1..], ["", "", ""]);
+    }
+
+    // HELPERS
+    // --------------------------------------------------------------------------------------------
+
+    /// Executes the provided program in debug mode and returns the output of each executed debug
+    /// decorator together with the outputs of the program.
+    fn run_debug_program(
+        source: &str,
+        stack_inputs: &[u64],
+        advice_stack: Vec<u64>,
+    ) -> (Vec<String>, StackOutputs) {
+        let program = Assembler::default().with_debug_mode(true).assemble_program(source).unwrap();
+        let advice_inputs = AdviceInputs::default().with_stack_values(advice_stack).unwrap();
+
+        let mut host = DebugOutputHost::default();
+        let mut process = Process::new(
+            Kernel::default(),
+            StackInputs::try_from_ints(stack_inputs.iter().copied()).unwrap(),
+            advice_inputs,
+            ExecutionOptions::default().with_debugging(true),
+        );
+        let stack_outputs = process.execute(&program, &mut host).unwrap();
+
+        (host.output, stack_outputs)
+    }
+
+    /// A host which records the output of the debug decorators instead of printing it.
+    #[derive(Default)]
+    struct DebugOutputHost {
+        output: Vec<String>,
+    }
+
+    impl BaseHost for DebugOutputHost {
+        fn on_debug(
+            &mut self,
+            process: &mut ProcessState,
+            options: &DebugOptions,
+        ) -> Result<(), ExecutionError> {
+            let mut output = String::new();
+            write_debug_info(&mut output, process, options).unwrap();
+            self.output.push(output);
+            Ok(())
+        }
+    }
+
+    impl SyncHost for DebugOutputHost {
+        fn get_mast_forest(&self, _node_digest: &Word) -> Option<Arc<MastForest>> {
+            None
+        }
+
+        fn on_event(
+            &mut self,
+            _process: &mut ProcessState,
+            _event_id: u32,
+            _err_ctx: &impl ErrorContext,
+        ) -> Result<(), ExecutionError> {
+            Ok(())
+        }
+    }
+}
@@ -1,7 +1,10 @@
-use alloc::sync::Arc;
-use core::future::Future;
+use alloc::{collections::BTreeMap, string::String, sync::Arc, vec::Vec};
+use core::{fmt, future::Future};
 
-use miden_core::{DebugOptions, Felt, Word, mast::MastForest};
+use miden_core::{
+    DebugOptions, Felt, SignatureKind, Word, crypto::dsa::rpo_falcon512::SecretKey,
+    mast::MastForest, utils::Serializable,
+};
 
 use crate::{ExecutionError, ProcessState, errors::ErrorContext};
 
@@ -56,8 +59,91 @@ pub trait BaseHost {
         Ok(())
     }
 
+    /// Handles the VM entering or leaving (as specified by `enter`) the procedure with the
+    /// specified fully-qualified name.
+    ///
+    /// This is driven by the [Decorator::ProcScope](miden_core::Decorator::ProcScope) decorators
+    /// which the assembler inserts at procedure boundaries in debug mode, and is invoked only
+    /// when tracing is enabled.
+    fn on_proc_scope(
+        &mut self,
+        _process: &mut ProcessState,
+        _name: &str,
+        _enter: bool,
+    ) -> Result<(), ExecutionError> {
+        Ok(())
+    }
+
     /// Handles the failure of the assertion instruction.
     fn on_assert_failed(&mut self, _process: &mut ProcessState, _err_code: Felt) {}
+
+    /// Handles the VM entering the procedure with the specified MAST root via `call`, `syscall` or
+    /// `dyncall`.
+    ///
+    /// This is invoked before the VM switches to the execution context of the callee. Returning an
+    /// error aborts the execution before the callee is entered, leaving the execution context of
+    /// the caller in place.
+    fn on_call_enter(
+        &mut self,
+        _process: &mut ProcessState,
+        _callee: Word,
+    ) -> Result<(), ExecutionError> {
+        Ok(())
+    }
+
+    /// Handles the VM leaving the procedure with the specified MAST root which was invoked via
+    /// `call`, `syscall` or `dyncall`.
+    ///
+    /// This is invoked after the last operation of the callee has been executed, and before the
+    /// VM has switched back to the execution context of the caller.
+    fn on_call_exit(&mut self, _process: &mut ProcessState, _callee: Word) {}
+
+    /// Returns the node at the specified depth and index in the Merkle tree with the specified
+    /// root, as known to the advice provider of the process.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - A Merkle tree for the specified root cannot be found in the advice provider.
+    /// - The specified depth is greater than the depth of the Merkle tree identified by the
+    ///   specified root, or the index is out of bounds for the specified depth.
+    /// - Value of the node at the specified depth and index is not known to the advice provider.
+    fn get_merkle_node(
+        &mut self,
+        process: &ProcessState,
+        root: Word,
+        depth: u8,
+        index: Felt,
+    ) -> Result<Word, ExecutionError> {
+        process
+            .advice_provider()
+            .get_tree_node(root, &Felt::from(depth), &index)
+            .map_err(|err| ExecutionError::advice_error(err, process.clk(), &()))
+    }
+
+    /// Returns the number of elements stored in the advice map under the key located at the top of
+    /// the operand stack of the process.
+    ///
+    /// # Errors
+    /// Returns an error if the advice map does not contain the key.
+    fn get_map_value_len(&mut self, process: &ProcessState) -> Result<usize, ExecutionError> {
+        let key = process.get_stack_word(0);
+        process
+            .advice_provider()
+            .get_mapped_values(&key)
+            .map(<[Felt]>::len)
+            .map_err(|err| ExecutionError::advice_error(err, process.clk(), &()))
+    }
+
+    /// Returns the serialized secret key of the specified kind associated with the specified
+    /// public key, if it is known to the host.
+    ///
+    /// When the [`SystemEvent::Signature`](miden_core::sys_events::SystemEvent::Signature) event
+    /// is emitted and the advice map does not contain an entry for the public key on top of the
+    /// operand stack, the secret key returned by this method is inserted into the advice map
+    /// under the public key before the signature is generated.
+    fn get_signing_key(&self, _kind: SignatureKind, _pub_key: Word) -> Option<Vec<Felt>> {
+        None
+    }
 }
 
 /// Defines an interface by which the VM can interact with the host.
@@ -89,9 +175,14 @@ pub trait SyncHost: BaseHost {
 
 /// A default [BaseHost], [SyncHost] and [AsyncHost] implementation that provides the essential
 /// functionality required by the VM.
-#[derive(Debug, Clone, Default)]
+#[derive(Clone, Default)]
 pub struct DefaultHost {
     store: MemMastForestStore,
+    /// Serialized Falcon secret keys, with each byte encoded as a field element, indexed by the
+    /// digests of the corresponding public keys.
+    falcon_signers: BTreeMap<Word, Vec<Felt>>,
+    /// Human-readable names of events, indexed by event ids.
+    event_names: BTreeMap<u32, String>,
 }
 
 impl DefaultHost {
@@ -99,9 +190,61 @@ impl DefaultHost {
         self.store.insert(mast_forest);
         Ok(())
     }
+
+    /// Registers the provided RPO Falcon512 secret key as a signer with this host.
+    ///
+    /// When the signature event of [`SignatureKind::RpoFalcon512`] is emitted with the digest of
+    /// the corresponding public key on top of the operand stack, followed by the message, the host
+    /// provides the serialized secret key via [`BaseHost::get_signing_key`], so that it is
+    /// inserted into the advice map under the public key digest (unless an entry for it already
+    /// exists) before the message is signed. This way, the advice map entries needed by the
+    /// `std::crypto::dsa::rpo_falcon512::verify` procedure do not need to be provided manually.
+    pub fn with_falcon_signer(mut self, secret_key: SecretKey) -> Self {
+        let pub_key: Word = secret_key.public_key().into();
+        let secret_key = secret_key.to_bytes().into_iter().map(Felt::from).collect();
+        self.falcon_signers.insert(pub_key, secret_key);
+        self
+    }
+
+    /// Registers human-readable names for the specified event ids with this host.
+    ///
+    /// When an event with one of these ids is emitted, the message printed by the host includes
+    /// the name of the event alongside its id.
+    pub fn with_event_names(mut self, event_names: BTreeMap<u32, String>) -> Self {
+        self.event_names.extend(event_names);
+        self
+    }
+
+    /// Returns a message describing the emission of the event with the specified id, including
+    /// the name of the event if one was registered for it.
+    #[cfg(feature = "std")]
+    fn describe_event(&self, process: &ProcessState, event_id: u32) -> String {
+        let event = match self.event_names.get(&event_id) {
+            Some(name) => alloc::format!("Event '{name}' with id {event_id}"),
+            None => alloc::format!("Event with id {event_id}"),
+        };
+        alloc::format!("{event} emitted at step {} in context {}", process.clk(), process.ctx())
+    }
 }
 
-impl BaseHost for DefaultHost {}
+impl fmt::Debug for DefaultHost {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // only the public keys of the registered signers are printed, never their secret keys
+        f.debug_struct("DefaultHost")
+            .field("store", &self.store)
+            .field("falcon_signers", &self.falcon_signers.keys().collect::<Vec<_>>())
+            .field("event_names", &self.event_names)
+            .finish()
+    }
+}
+
+impl BaseHost for DefaultHost {
+    fn get_signing_key(&self, kind: SignatureKind, pub_key: Word) -> Option<Vec<Felt>> {
+        match kind {
+            SignatureKind::RpoFalcon512 => self.falcon_signers.get(&pub_key).cloned(),
+        }
+    }
+}
 
 impl SyncHost for DefaultHost {
     fn get_mast_forest(&self, node_digest: &Word) -> Option<Arc<MastForest>> {
@@ -116,12 +259,7 @@ impl SyncHost for DefaultHost {
     ) -> Result<(), ExecutionError> {
         let _ = (&process, event_id, err_ctx);
         #[cfg(feature = "std")]
-        std::println!(
-            "Event with id {} emitted at step {} in context {}",
-            event_id,
-            process.clk(),
-            process.ctx()
-        );
+        std::println!("{}", self.describe_event(process, event_id));
         Ok(())
     }
 }
@@ -170,3 +308,128 @@ pub trait AsyncHost: BaseHost {
         err_ctx: &impl ErrorContext,
     ) -> impl Future<Output = Result<(), ExecutionError>> + Send;
 }
+
+// TESTS
+// ================================================================================================
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use alloc::{collections::BTreeMap, string::String};
+
+    use miden_core::{
+        Felt, Kernel, Word,
+        crypto::merkle::{MerkleStore, MerkleTree, NodeIndex},
+    };
+
+    use super::{BaseHost, DefaultHost};
+    use crate::{
+        AdviceError, AdviceInputs, ExecutionError, ExecutionOptions, Process, StackInputs,
+    };
+
+    #[test]
+    fn describe_named_event() {
+        let event_names = BTreeMap::from([(7, String::from("note_created"))]);
+        let host = DefaultHost::default().with_event_names(event_names);
+        let mut process = Process::new(
+            Kernel::default(),
+            StackInputs::default(),
+            AdviceInputs::default(),
+            ExecutionOptions::default(),
+        );
+
+        assert_eq!(
+            host.describe_event(&process.state(), 7),
+            "Event 'note_created' with id 7 emitted at step 0 in context 0"
+        );
+        assert_eq!(
+            host.describe_event(&process.state(), 8),
+            "Event with id 8 emitted at step 0 in context 0"
+        );
+    }
+
+    #[test]
+    fn get_merkle_node() {
+        let leaves: [Word; 4] = core::array::from_fn(|i| Word::from([i as u32, 0, 0, 0]));
+        let tree = MerkleTree::new(leaves).unwrap();
+        let store = MerkleStore::from(&tree);
+        let mut process = Process::new(
+            Kernel::default(),
+            StackInputs::default(),
+            AdviceInputs::default().with_merkle_store(store),
+            ExecutionOptions::default(),
+        );
+        let mut host = DefaultHost::default();
+        let root = tree.root();
+
+        // valid queries
+        let node = host.get_merkle_node(&process.state(), root, 2, Felt::new(3)).unwrap();
+        assert_eq!(node, leaves[3]);
+        let node = host.get_merkle_node(&process.state(), root, 1, Felt::new(0)).unwrap();
+        assert_eq!(node, tree.get_node(NodeIndex::new(1, 0).unwrap()).unwrap());
+        let node = host.get_merkle_node(&process.state(), root, 0, Felt::new(0)).unwrap();
+        assert_eq!(node, root);
+
+        // unknown root
+        let err = host.get_merkle_node(&process.state(), leaves[0], 2, Felt::new(0)).unwrap_err();
+        assert!(matches!(
+            err,
+            ExecutionError::AdviceError {
+                err: AdviceError::MerkleStoreLookupFailed(_),
+                ..
+            }
+        ));
+
+        // depth greater than the depth of the tree
+        let err = host.get_merkle_node(&process.state(), root, 3, Felt::new(0)).unwrap_err();
+        assert!(matches!(
+            err,
+            ExecutionError::AdviceError {
+                err: AdviceError::MerkleStoreLookupFailed(_),
+                ..
+            }
+        ));
+
+        // index out of bounds for the depth
+        let err = host.get_merkle_node(&process.state(), root, 2, Felt::new(4)).unwrap_err();
+        assert!(matches!(
+            err,
+            ExecutionError::AdviceError {
+                err: AdviceError::InvalidMerkleTreeNodeIndex { .. },
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn get_map_value_len() {
+        let keys: [Word; 4] = core::array::from_fn(|i| Word::from([i as u32, 1, 2, 3]));
+        let map = [
+            (keys[0], vec![]),
+            (keys[1], vec![Felt::new(7)]),
+            (keys[2], (0..5).map(Felt::new).collect()),
+        ];
+
+        let map_value_len = |key: Word| {
+            // the key is read from the top word of the stack, with its last element on top
+            let stack_inputs = StackInputs::new(key.iter().copied().collect()).unwrap();
+            let mut process = Process::new(
+                Kernel::default(),
+                stack_inputs,
+                AdviceInputs::default().with_map(map.clone()),
+                ExecutionOptions::default(),
+            );
+            DefaultHost::default().get_map_value_len(&process.state())
+        };
+
+        assert_eq!(map_value_len(keys[0]).unwrap(), 0);
+        assert_eq!(map_value_len(keys[1]).unwrap(), 1);
+        assert_eq!(map_value_len(keys[2]).unwrap(), 5);
+
+        let err = map_value_len(keys[3]).unwrap_err();
+        assert!(matches!(
+            err,
+            ExecutionError::AdviceError { err: AdviceError::MapKeyNotFound { key }, .. }
+                if key == keys[3]
+        ));
+    }
+}
@@ -6,7 +6,7 @@ extern crate alloc;
 #[cfg(feature = "std")]
 extern crate std;
 
-use alloc::{sync::Arc, vec::Vec};
+use alloc::{collections::BTreeMap, sync::Arc, vec::Vec};
 use core::fmt::{Display, LowerHex};
 
 use miden_air::trace::{
@@ -23,14 +23,8 @@ pub use miden_core::{
     sys_events::SystemEvent,
     utils::{DeserializationError, collections::KvMap},
 };
-use miden_core::{
-    Decorator, DecoratorIterator, FieldElement, WORD_SIZE,
-    mast::{
-        BasicBlockNode, CallNode, DynNode, JoinNode, LoopNode, OP_GROUP_SIZE, OpBatch, SplitNode,
-    },
-};
+use miden_core::{Decorator, FieldElement, WORD_SIZE, mast::OpBatch, stack::MIN_STACK_DEPTH};
 use miden_debug_types::{DefaultSourceManager, SourceManager, SourceSpan};
-use utils::resolve_external_node;
 pub use winter_prover::matrix::ColMatrix;
 
 pub mod fast;
@@ -38,6 +32,13 @@ use fast::FastProcessState;
 
 mod operations;
 
+mod continuation;
+use continuation::Continuation;
+
+mod profiler;
+use profiler::Profiler;
+pub use profiler::{CallFrameProfile, CallProfilingReport};
+
 mod system;
 use system::System;
 pub use system::{ContextId, FMP_MIN, SYSCALL_FMP_MIN};
@@ -54,12 +55,12 @@ use range::RangeChecker;
 mod host;
 pub use host::{
     AsyncHost, BaseHost, DefaultHost, MastForestStore, MemMastForestStore, SyncHost,
-    advice::{AdviceError, AdviceInputs, AdviceProvider},
+    advice::{AdviceError, AdviceInputs, AdviceProvider, AdviceSnapshot},
 };
 
 mod chiplets;
 use chiplets::Chiplets;
-pub use chiplets::MemoryError;
+pub use chiplets::{ChipletSegmentLengths, MemoryError};
 
 mod trace;
 use trace::TraceFragment;
@@ -74,7 +75,7 @@ pub mod utils;
 mod tests;
 
 mod debug;
-pub use debug::{AsmOpInfo, VmState, VmStateIterator};
+pub use debug::{AsmOpInfo, Breakpoint, VmState, VmStateIterator};
 
 // RE-EXPORTS
 // ================================================================================================
@@ -98,7 +99,7 @@ pub mod crypto {
 // TYPE ALIASES
 // ================================================================================================
 
-#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash)]
 pub struct MemoryAddress(u32);
 
 impl From<u32> for MemoryAddress {
@@ -187,6 +188,30 @@ pub fn execute(
     Ok(trace)
 }
 
+/// Returns the length of the execution trace which would result from executing the provided
+/// program against the provided inputs.
+///
+/// This performs a full execution of the program, recording the trace columns of all components
+/// in the same way as [execute] does; only the final step of padding the columns and assembling
+/// them into an [ExecutionTrace] is skipped. As such, this is not meaningfully cheaper than
+/// calling [execute] and inspecting the resulting trace. It is useful when only the trace length
+/// is needed, e.g., for pre-allocating buffers, estimating the cost of proving, or detecting
+/// programs which would exceed trace length limits.
+#[tracing::instrument("estimate_trace_len", skip_all)]
+pub fn estimate_trace_len(
+    program: &Program,
+    stack_inputs: StackInputs,
+    advice_inputs: AdviceInputs,
+    host: &mut impl SyncHost,
+    options: ExecutionOptions,
+    source_manager: Arc<dyn SourceManager>,
+) -> Result<usize, ExecutionError> {
+    let mut process = Process::new(program.kernel().clone(), stack_inputs, advice_inputs, options)
+        .with_source_manager(source_manager);
+    process.execute(program, host)?;
+    Ok(trace::estimate_trace_len(process))
+}
+
 /// Returns an iterator which allows callers to step through the execution and inspect VM state at
 /// each execution step.
 pub fn execute_iter(
@@ -232,6 +257,9 @@ pub struct Process {
     max_cycles: u32,
     enable_tracing: bool,
     source_manager: Arc<dyn SourceManager>,
+    continuations: Vec<Continuation>,
+    forests: Vec<Arc<MastForest>>,
+    profiler: Option<Profiler>,
 }
 
 #[cfg(any(test, feature = "testing"))]
@@ -245,6 +273,9 @@ pub struct Process {
     pub max_cycles: u32,
     pub enable_tracing: bool,
     pub source_manager: Arc<dyn SourceManager>,
+    continuations: Vec<Continuation>,
+    forests: Vec<Arc<MastForest>>,
+    profiler: Option<Profiler>,
 }
 
 impl Process {
@@ -283,15 +314,19 @@ impl Process {
         let in_debug_mode = execution_options.enable_debugging();
         let source_manager = Arc::new(DefaultSourceManager::default());
         Self {
-            advice: advice_inputs.into(),
+            advice: AdviceProvider::from(advice_inputs).with_execution_options(&execution_options),
             system: System::new(execution_options.expected_cycles() as usize),
             decoder: Decoder::new(in_debug_mode),
-            stack: Stack::new(&stack, execution_options.expected_cycles() as usize, in_debug_mode),
+            stack: Stack::new(&stack, execution_options.expected_cycles() as usize, in_debug_mode)
+                .with_max_depth(execution_options.max_stack_depth()),
             range: RangeChecker::new(),
             chiplets: Chiplets::new(kernel),
             max_cycles: execution_options.max_cycles(),
             enable_tracing: execution_options.enable_tracing(),
             source_manager,
+            continuations: Vec::new(),
+            forests: Vec::new(),
+            profiler: execution_options.enable_profiling().then(Profiler::default),
         }
     }
 
@@ -305,388 +340,125 @@ impl Process {
     // --------------------------------------------------------------------------------------------
 
     /// Executes the provided [`Program`] in this process.
+    ///
+    /// If the execution of the program has been started via [Process::step] or
+    /// [Process::run_until], it is resumed from the current clock cycle and run to completion.
     pub fn execute(
         &mut self,
         program: &Program,
         host: &mut impl SyncHost,
     ) -> Result<StackOutputs, ExecutionError> {
-        if self.system.clk() != 0 {
-            return Err(ExecutionError::ProgramAlreadyExecuted);
-        }
-
-        self.advice
-            .merge_advice_map(program.mast_forest().advice_map())
-            .map_err(|err| ExecutionError::advice_error(err, RowIndex::from(0), &()))?;
-
-        self.execute_mast_node(program.entrypoint(), &program.mast_forest().clone(), host)?;
-
-        self.stack.build_stack_outputs()
-    }
-
-    // NODE EXECUTORS
-    // --------------------------------------------------------------------------------------------
-
-    fn execute_mast_node(
-        &mut self,
-        node_id: MastNodeId,
-        program: &MastForest,
-        host: &mut impl SyncHost,
-    ) -> Result<(), ExecutionError> {
-        let node = program
-            .get_node_by_id(node_id)
-            .ok_or(ExecutionError::MastNodeNotFoundInForest { node_id })?;
-
-        for &decorator_id in node.before_enter() {
-            self.execute_decorator(&program[decorator_id], host)?;
-        }
-
-        match node {
-            MastNode::Block(node) => self.execute_basic_block_node(node, program, host)?,
-            MastNode::Join(node) => self.execute_join_node(node, program, host)?,
-            MastNode::Split(node) => self.execute_split_node(node, program, host)?,
-            MastNode::Loop(node) => self.execute_loop_node(node, program, host)?,
-            MastNode::Call(node) => {
-                let err_ctx = err_ctx!(program, node, self.source_manager.clone());
-                add_error_ctx_to_external_error(
-                    self.execute_call_node(node, program, host),
-                    err_ctx,
-                )?
-            },
-            MastNode::Dyn(node) => {
-                let err_ctx = err_ctx!(program, node, self.source_manager.clone());
-                add_error_ctx_to_external_error(
-                    self.execute_dyn_node(node, program, host),
-                    err_ctx,
-                )?
-            },
-            MastNode::External(external_node) => {
-                let (root_id, mast_forest) =
-                    resolve_external_node(external_node, &mut self.advice, host)?;
-
-                self.execute_mast_node(root_id, &mast_forest, host)?;
-            },
-        }
-
-        for &decorator_id in node.after_exit() {
-            self.execute_decorator(&program[decorator_id], host)?;
+        loop {
+            if let Some(stack_outputs) = self.step(program, host)? {
+                return Ok(stack_outputs);
+            }
         }
-
-        Ok(())
     }
 
-    /// Executes the specified [JoinNode].
-    #[inline(always)]
-    fn execute_join_node(
-        &mut self,
-        node: &JoinNode,
-        program: &MastForest,
-        host: &mut impl SyncHost,
-    ) -> Result<(), ExecutionError> {
-        self.start_join_node(node, program, host)?;
-
-        // execute first and then second child of the join block
-        self.execute_mast_node(node.first(), program, host)?;
-        self.execute_mast_node(node.second(), program, host)?;
-
-        self.end_join_node(node, program, host)
-    }
-
-    /// Executes the specified [SplitNode].
-    #[inline(always)]
-    fn execute_split_node(
+    /// Executes the provided [`Program`] in this process until the clock reaches the specified
+    /// cycle, or the program completes, whichever happens first.
+    ///
+    /// Returns the outputs of the program if it completed; otherwise, the execution can be resumed
+    /// by subsequent calls to this method, [Process::step], or [Process::execute] with the same
+    /// program.
+    pub fn run_until(
         &mut self,
-        node: &SplitNode,
-        program: &MastForest,
+        program: &Program,
         host: &mut impl SyncHost,
-    ) -> Result<(), ExecutionError> {
-        // start the SPLIT block; this also pops the stack and returns the popped element
-        let condition = self.start_split_node(node, program, host)?;
-
-        // execute either the true or the false branch of the split block based on the condition
-        if condition == ONE {
-            self.execute_mast_node(node.on_true(), program, host)?;
-        } else if condition == ZERO {
-            self.execute_mast_node(node.on_false(), program, host)?;
-        } else {
-            let err_ctx = err_ctx!(program, node, self.source_manager.clone());
-            return Err(ExecutionError::not_binary_value_if(condition, &err_ctx));
+        clk: RowIndex,
+    ) -> Result<Option<StackOutputs>, ExecutionError> {
+        while self.system.clk() < clk {
+            if let Some(stack_outputs) = self.step(program, host)? {
+                return Ok(Some(stack_outputs));
+            }
         }
-
-        self.end_split_node(node, program, host)
+        Ok(None)
     }
 
-    /// Executes the specified [LoopNode].
-    #[inline(always)]
-    fn execute_loop_node(
+    /// Executes the provided [`Program`] in this process until one of the specified breakpoints is
+    /// hit, or the program completes, whichever happens first.
+    ///
+    /// Returns the outputs of the program if it completed; otherwise, the execution can be resumed
+    /// by subsequent calls to this method, [Process::step], or [Process::execute] with the same
+    /// program. At least one clock cycle is executed by each call, so that resuming the execution
+    /// does not stop at the breakpoint which paused it.
+    pub fn run_with_breakpoints(
         &mut self,
-        node: &LoopNode,
-        program: &MastForest,
+        program: &Program,
         host: &mut impl SyncHost,
-    ) -> Result<(), ExecutionError> {
-        // start the LOOP block; this also pops the stack and returns the popped element
-        let condition = self.start_loop_node(node, program, host)?;
-
-        // if the top of the stack is ONE, execute the loop body; otherwise skip the loop body
-        if condition == ONE {
-            // execute the loop body at least once
-            self.execute_mast_node(node.body(), program, host)?;
-
-            // keep executing the loop body until the condition on the top of the stack is no
-            // longer ONE; each iteration of the loop is preceded by executing REPEAT operation
-            // which drops the condition from the stack
-            while self.stack.peek() == ONE {
-                self.decoder.repeat();
-                self.execute_op(Operation::Drop, program, host)?;
-                self.execute_mast_node(node.body(), program, host)?;
+        breakpoints: &[Breakpoint],
+    ) -> Result<Option<StackOutputs>, ExecutionError> {
+        loop {
+            if let Some(stack_outputs) = self.step(program, host)? {
+                return Ok(Some(stack_outputs));
             }
-
-            if self.stack.peek() != ZERO {
-                let err_ctx = err_ctx!(program, node, self.source_manager.clone());
-                return Err(ExecutionError::not_binary_value_loop(self.stack.peek(), &err_ctx));
+            if breakpoints.iter().any(|breakpoint| self.is_at_breakpoint(breakpoint)) {
+                return Ok(None);
             }
-
-            // end the LOOP block and drop the condition from the stack
-            self.end_loop_node(node, true, program, host)
-        } else if condition == ZERO {
-            // end the LOOP block, but don't drop the condition from the stack because it was
-            // already dropped when we started the LOOP block
-            self.end_loop_node(node, false, program, host)
-        } else {
-            let err_ctx = err_ctx!(program, node, self.source_manager.clone());
-            Err(ExecutionError::not_binary_value_loop(condition, &err_ctx))
         }
     }
 
-    /// Executes the specified [CallNode].
-    #[inline(always)]
-    fn execute_call_node(
-        &mut self,
-        call_node: &CallNode,
-        program: &MastForest,
-        host: &mut impl SyncHost,
-    ) -> Result<(), ExecutionError> {
-        // call or syscall are not allowed inside a syscall
-        if self.system.in_syscall() {
-            let instruction = if call_node.is_syscall() { "syscall" } else { "call" };
-            return Err(ExecutionError::CallInSyscall(instruction));
-        }
-
-        // if this is a syscall, make sure the call target exists in the kernel
-        if call_node.is_syscall() {
-            let callee = program.get_node_by_id(call_node.callee()).ok_or_else(|| {
-                ExecutionError::MastNodeNotFoundInForest { node_id: call_node.callee() }
-            })?;
-            let err_ctx = err_ctx!(program, call_node, self.source_manager.clone());
-            self.chiplets.kernel_rom.access_proc(callee.digest(), &err_ctx)?;
-        }
-        let err_ctx = err_ctx!(program, call_node, self.source_manager.clone());
-
-        self.start_call_node(call_node, program, host)?;
-        self.execute_mast_node(call_node.callee(), program, host)?;
-        self.end_call_node(call_node, program, host, &err_ctx)
-    }
-
-    /// Executes the specified [miden_core::mast::DynNode].
+    /// Executes a single clock cycle of the provided [`Program`] in this process, and returns the
+    /// outputs of the program if this completed its execution.
     ///
-    /// The MAST root of the callee is assumed to be at the top of the stack, and the callee is
-    /// expected to be either in the current `program` or in the host.
-    #[inline(always)]
-    fn execute_dyn_node(
+    /// The first call starts the execution of the program, and each subsequent call resumes it
+    /// where the previous one left off; all calls must be made with the same program. Decorators
+    /// are executed together with the operation they precede, or, for decorators following the
+    /// last operation of a node, with the operation which ends the node. Consequently, the final
+    /// call may execute decorators only, without advancing the clock.
+    ///
+    /// The state of the process between steps can be inspected via [Process::state]. Once an
+    /// error is returned, the execution cannot be resumed.
+    pub fn step(
         &mut self,
-        node: &DynNode,
-        program: &MastForest,
+        program: &Program,
         host: &mut impl SyncHost,
-    ) -> Result<(), ExecutionError> {
-        // dyn calls are not allowed inside a syscall
-        if node.is_dyncall() && self.system.in_syscall() {
-            return Err(ExecutionError::CallInSyscall("dyncall"));
-        }
-
-        let err_ctx = err_ctx!(program, node, self.source_manager.clone());
-
-        let callee_hash = if node.is_dyncall() {
-            self.start_dyncall_node(node, &err_ctx)?
-        } else {
-            self.start_dyn_node(node, program, host, &err_ctx)?
-        };
-
-        // if the callee is not in the program's MAST forest, try to find a MAST forest for it in
-        // the host (corresponding to an external library loaded in the host); if none are
-        // found, return an error.
-        match program.find_procedure_root(callee_hash) {
-            Some(callee_id) => self.execute_mast_node(callee_id, program, host)?,
-            None => {
-                let mast_forest = host
-                    .get_mast_forest(&callee_hash)
-                    .ok_or_else(|| ExecutionError::dynamic_node_not_found(callee_hash, &err_ctx))?;
-
-                // We limit the parts of the program that can be called externally to procedure
-                // roots, even though MAST doesn't have that restriction.
-                let root_id = mast_forest
-                    .find_procedure_root(callee_hash)
-                    .ok_or(ExecutionError::malfored_mast_forest_in_host(callee_hash, &()))?;
-
-                self.execute_mast_node(root_id, &mast_forest, host)?
-            },
-        }
-
-        if node.is_dyncall() {
-            self.end_dyncall_node(node, program, host, &err_ctx)
-        } else {
-            self.end_dyn_node(node, program, host)
-        }
-    }
+    ) -> Result<Option<StackOutputs>, ExecutionError> {
+        if self.continuations.is_empty() {
+            if self.system.clk() != 0 {
+                return Err(ExecutionError::ProgramAlreadyExecuted);
+            }
 
-    /// Executes the specified [BasicBlockNode].
-    #[inline(always)]
-    fn execute_basic_block_node(
-        &mut self,
-        basic_block: &BasicBlockNode,
-        program: &MastForest,
-        host: &mut impl SyncHost,
-    ) -> Result<(), ExecutionError> {
-        self.start_basic_block_node(basic_block, program, host)?;
-
-        let mut op_offset = 0;
-        let mut decorator_ids = basic_block.decorator_iter();
-
-        // execute the first operation batch
-        self.execute_op_batch(
-            basic_block,
-            &basic_block.op_batches()[0],
-            &mut decorator_ids,
-            op_offset,
-            program,
-            host,
-        )?;
-        op_offset += basic_block.op_batches()[0].ops().len();
-
-        // if the span contains more operation batches, execute them. each additional batch is
-        // preceded by a RESPAN operation; executing RESPAN operation does not change the state
-        // of the stack
-        for op_batch in basic_block.op_batches().iter().skip(1) {
-            self.respan(op_batch);
-            self.execute_op(Operation::Noop, program, host)?;
-            self.execute_op_batch(
-                basic_block,
-                op_batch,
-                &mut decorator_ids,
-                op_offset,
-                program,
-                host,
-            )?;
-            op_offset += op_batch.ops().len();
-        }
+            self.advice
+                .merge_advice_map(program.mast_forest().advice_map())
+                .map_err(|err| ExecutionError::advice_error(err, RowIndex::from(0), &()))?;
 
-        self.end_basic_block_node(basic_block, program, host)?;
-
-        // execute any decorators which have not been executed during span ops execution; this
-        // can happen for decorators appearing after all operations in a block. these decorators
-        // are executed after SPAN block is closed to make sure the VM clock cycle advances beyond
-        // the last clock cycle of the SPAN block ops.
-        for &decorator_id in decorator_ids {
-            let decorator = program
-                .get_decorator_by_id(decorator_id)
-                .ok_or(ExecutionError::DecoratorNotFoundInForest { decorator_id })?;
-            self.execute_decorator(decorator, host)?;
+            self.forests.push(program.mast_forest().clone());
+            self.continuations.push(Continuation::StartNode(program.entrypoint()));
+            self.enter_call_frame(program.hash());
         }
 
-        Ok(())
-    }
-
-    /// Executes all operations in an [OpBatch]. This also ensures that all alignment rules are
-    /// satisfied by executing NOOPs as needed. Specifically:
-    /// - If an operation group ends with an operation carrying an immediate value, a NOOP is
-    ///   executed after it.
-    /// - If the number of groups in a batch is not a power of 2, NOOPs are executed (one per group)
-    ///   to bring it up to the next power of two (e.g., 3 -> 4, 5 -> 8).
-    #[inline(always)]
-    fn execute_op_batch(
-        &mut self,
-        basic_block: &BasicBlockNode,
-        batch: &OpBatch,
-        decorators: &mut DecoratorIterator,
-        op_offset: usize,
-        program: &MastForest,
-        host: &mut impl SyncHost,
-    ) -> Result<(), ExecutionError> {
-        let op_counts = batch.op_counts();
-        let mut op_idx = 0;
-        let mut group_idx = 0;
-        let mut next_group_idx = 1;
-
-        // round up the number of groups to be processed to the next power of two; we do this
-        // because the processor requires the number of groups to be either 1, 2, 4, or 8; if
-        // the actual number of groups is smaller, we'll pad the batch with NOOPs at the end
-        let num_batch_groups = batch.num_groups().next_power_of_two();
-
-        // execute operations in the batch one by one
-        for (i, &op) in batch.ops().iter().enumerate() {
-            while let Some(&decorator_id) = decorators.next_filtered(i + op_offset) {
-                let decorator = program
-                    .get_decorator_by_id(decorator_id)
-                    .ok_or(ExecutionError::DecoratorNotFoundInForest { decorator_id })?;
-                self.execute_decorator(decorator, host)?;
+        // execute continuations until the clock advances or there are none left; a continuation
+        // either executes a single operation or pushes other continuations onto the stack. The
+        // forest in which continuations are executed changes only when a forest is entered or
+        // left.
+        let clk = self.system.clk();
+        let mut mast_forest = self.forests.last().cloned();
+        while self.system.clk() == clk {
+            let Some(continuation) = self.continuations.pop() else {
+                break;
+            };
+            let num_forests = self.forests.len();
+            let program = mast_forest.as_ref().expect("continuations must execute within a forest");
+            if let Err(err) = self.execute_continuation(program, continuation, host) {
+                let err = self.add_error_ctx_of_enclosing_calls(err);
+                // the execution cannot be resumed after an error
+                self.continuations.clear();
+                self.forests.clear();
+                return Err(err);
             }
-
-            // decode and execute the operation
-            let err_ctx =
-                err_ctx!(program, basic_block, self.source_manager.clone(), i + op_offset);
-            self.decoder.execute_user_op(op, op_idx);
-            self.execute_op_with_error_ctx(op, program, host, &err_ctx)?;
-
-            // if the operation carries an immediate value, the value is stored at the next group
-            // pointer; so, we advance the pointer to the following group
-            let has_imm = op.imm_value().is_some();
-            if has_imm {
-                next_group_idx += 1;
-            }
-
-            // determine if we've executed all non-decorator operations in a group
-            if op_idx == op_counts[group_idx] - 1 {
-                // if we are at the end of the group, first check if the operation carries an
-                // immediate value
-                if has_imm {
-                    // an operation with an immediate value cannot be the last operation in a group
-                    // so, we need execute a NOOP after it. the assert also makes sure that there
-                    // is enough room in the group to execute a NOOP (if there isn't, there is a
-                    // bug somewhere in the assembler)
-                    debug_assert!(op_idx < OP_GROUP_SIZE - 1, "invalid op index");
-                    self.decoder.execute_user_op(Operation::Noop, op_idx + 1);
-                    self.execute_op(Operation::Noop, program, host)?;
-                }
-
-                // then, move to the next group and reset operation index
-                group_idx = next_group_idx;
-                next_group_idx += 1;
-                op_idx = 0;
-
-                // if we haven't reached the end of the batch yet, set up the decoder for
-                // decoding the next operation group
-                if group_idx < num_batch_groups {
-                    self.decoder.start_op_group(batch.groups()[group_idx]);
-                }
-            } else {
-                // if we are not at the end of the group, just increment the operation index
-                op_idx += 1;
+            if self.forests.len() != num_forests {
+                mast_forest = self.forests.last().cloned();
             }
         }
 
-        // make sure we execute the required number of operation groups; this would happen when
-        // the actual number of operation groups was not a power of two
-        for group_idx in group_idx..num_batch_groups {
-            self.decoder.execute_user_op(Operation::Noop, 0);
-            self.execute_op(Operation::Noop, program, host)?;
-
-            // if we are not at the last group yet, set up the decoder for decoding the next
-            // operation groups. the groups were are processing are just NOOPs - so, the op group
-            // value is ZERO
-            if group_idx < num_batch_groups - 1 {
-                self.decoder.start_op_group(ZERO);
-            }
+        if self.continuations.is_empty() {
+            self.forests.clear();
+            self.exit_call_frame();
+            self.stack.build_stack_outputs().map(Some)
+        } else {
+            Ok(None)
         }
-
-        Ok(())
     }
 
     /// Executes the specified decorator
@@ -713,6 +485,15 @@ impl Process {
                     host.on_trace(process, *id)?;
                 }
             },
+            Decorator::ProcScope { name, enter } => {
+                if self.enable_tracing {
+                    let process = &mut self.state();
+                    host.on_proc_scope(process, name, *enter)?;
+                }
+            },
+            Decorator::LoopHint(_) => {
+                // the hint is used to pre-allocate the trace when the loop is entered
+            },
         };
         Ok(())
     }
@@ -724,6 +505,16 @@ impl Process {
         self.chiplets.kernel_rom.kernel()
     }
 
+    /// Returns the number of times each memory address has been accessed by this process,
+    /// counting both reads and writes.
+    ///
+    /// A word access is attributed to the address at which the word starts. Addresses which have
+    /// never been accessed are not included. Addresses are keyed by [MemoryAddress] rather than
+    /// by [Felt], since field elements are not ordered.
+    pub fn memory_access_histogram(&self) -> BTreeMap<(ContextId, MemoryAddress), usize> {
+        self.chiplets.memory.access_histogram()
+    }
+
     pub fn into_parts(self) -> (System, Decoder, Stack, RangeChecker, Chiplets) {
         (self.system, self.decoder, self.stack, self.range, self.chiplets)
     }
@@ -831,6 +622,16 @@ impl<'a> ProcessState<'a> {
         }
     }
 
+    /// Returns the top `n` items of the stack at the current clock cycle, with the top of the
+    /// stack at index 0.
+    ///
+    /// If the stack contains fewer than `n` items, all items of the stack are returned.
+    pub fn get_stack_top(&self, n: usize) -> Vec<Felt> {
+        let mut stack = self.get_stack_state();
+        stack.truncate(n);
+        stack
+    }
+
     /// Returns stack state at the current clock cycle. This includes the top 16 items of the
     /// stack + overflow entries.
     #[inline(always)]
@@ -841,6 +642,26 @@ impl<'a> ProcessState<'a> {
         }
     }
 
+    /// Returns the number of elements which were spilled from the top 16 items of the stack into
+    /// the overflow table in the current execution context.
+    #[inline(always)]
+    pub fn overflow_len(&self) -> usize {
+        let depth = match self {
+            ProcessState::Slow(state) => state.stack.depth(),
+            ProcessState::Fast(state) => state.processor.stack_depth() as usize,
+        };
+        depth - MIN_STACK_DEPTH
+    }
+
+    /// Returns the elements stored in the overflow table in the current execution context at the
+    /// current clock cycle.
+    ///
+    /// The element located directly below the top 16 items of the stack is at index 0.
+    pub fn overflow_elements(&self) -> Vec<Felt> {
+        let mut stack = self.get_stack_state();
+        stack.split_off(MIN_STACK_DEPTH)
+    }
+
     /// Returns the element located at the specified context/address, or None if the address hasn't
     /// been accessed previously.
     #[inline(always)]
@@ -851,6 +672,22 @@ impl<'a> ProcessState<'a> {
         }
     }
 
+    /// Returns the elements located in the specified context within the provided address
+    /// interval. Interval boundaries are both inclusive.
+    ///
+    /// Each address is paired with its value, or None if the address hasn't been accessed
+    /// previously. Reading the interval does not affect the state of the memory.
+    pub fn get_mem_interval(
+        &self,
+        ctx: ContextId,
+        start: u32,
+        end: u32,
+    ) -> Vec<(MemoryAddress, Option<Felt>)> {
+        (start..=end)
+            .map(|addr| (MemoryAddress(addr), self.get_mem_value(ctx, addr)))
+            .collect()
+    }
+
     /// Returns the batch of elements starting at the specified context/address.
     ///
     /// # Errors
@@ -892,12 +729,12 @@ impl<'a> From<&'a mut Process> for ProcessState<'a> {
 
 /// For errors generated from processing an `ExternalNode`, returns the same error except with
 /// proper error context.
-pub(crate) fn add_error_ctx_to_external_error(
-    result: Result<(), ExecutionError>,
+pub(crate) fn add_error_ctx_to_external_error<T>(
+    result: Result<T, ExecutionError>,
     err_ctx: impl ErrorContext,
-) -> Result<(), ExecutionError> {
+) -> Result<T, ExecutionError> {
     match result {
-        Ok(_) => Ok(()),
+        Ok(value) => Ok(value),
         // Add context information to any errors coming from executing an `ExternalNode`
         Err(err) => match err {
             ExecutionError::NoMastForestWithProcedure { label, source_file: _, root_digest }
@@ -214,7 +214,7 @@ impl Process {
         let words = self
             .advice
             .pop_stack_dword()
-            .map_err(|err| ExecutionError::advice_error(err, clk, err_ctx))?;
+            .map_err(|err| ExecutionError::advice_stack_error(err, clk, ctx, err_ctx))?;
 
         // write the words memory
         self.chiplets
@@ -255,10 +255,9 @@ impl Process {
     /// # Errors
     /// Returns an error if the advice stack is empty.
     pub(super) fn op_advpop(&mut self, err_ctx: &impl ErrorContext) -> Result<(), ExecutionError> {
-        let value = self
-            .advice
-            .pop_stack()
-            .map_err(|err| ExecutionError::advice_error(err, self.system.clk(), err_ctx))?;
+        let value = self.advice.pop_stack().map_err(|err| {
+            ExecutionError::advice_stack_error(err, self.system.clk(), self.system.ctx(), err_ctx)
+        })?;
         self.stack.set(0, value);
         self.stack.shift_right(0);
         Ok(())
@@ -270,10 +269,9 @@ impl Process {
     /// # Errors
     /// Returns an error if the advice stack contains fewer than four elements.
     pub(super) fn op_advpopw(&mut self, err_ctx: &impl ErrorContext) -> Result<(), ExecutionError> {
-        let word = self
-            .advice
-            .pop_stack_word()
-            .map_err(|err| ExecutionError::advice_error(err, self.system.clk(), err_ctx))?;
+        let word = self.advice.pop_stack_word().map_err(|err| {
+            ExecutionError::advice_stack_error(err, self.system.clk(), self.system.ctx(), err_ctx)
+        })?;
 
         self.stack.set(0, word[3]);
         self.stack.set(1, word[2]);
@@ -298,7 +296,7 @@ mod tests {
         super::{MIN_STACK_DEPTH, Operation},
         Felt, Process,
     };
-    use crate::{ContextId, DefaultHost, ExecutionError, MemoryError, SyncHost};
+    use crate::{AdviceError, ContextId, DefaultHost, ExecutionError, MemoryError, SyncHost};
 
     #[test]
     fn op_push() {
@@ -681,6 +679,47 @@ mod tests {
         assert_eq!(expected, process.stack.trace_state());
     }
 
+    #[test]
+    fn op_advpop_with_pop_limit() {
+        let (mut process, mut host) = Process::new_dummy_with_advice_stack(&[3, 4, 5]);
+        process.advice = core::mem::take(&mut process.advice).with_pop_limit(2);
+        let program = &MastForest::default();
+
+        // popping up to the limit should succeed
+        process.execute_op(Operation::AdvPop, program, &mut host).unwrap();
+        process.execute_op(Operation::AdvPop, program, &mut host).unwrap();
+        let expected = build_expected_stack(&[4, 3]);
+        assert_eq!(expected, process.stack.trace_state());
+
+        // popping beyond the limit should fail even though the advice stack is not empty
+        assert_matches!(
+            process.execute_op(Operation::AdvPop, program, &mut host),
+            Err(ExecutionError::AdviceError {
+                err: AdviceError::StackPopLimitExceeded { limit: 2 },
+                ..
+            })
+        );
+        assert_eq!(process.advice.stack.len(), 1);
+    }
+
+    #[test]
+    fn op_advpopw_with_pop_limit() {
+        let (mut process, mut host) = Process::new_dummy_with_advice_stack(&[3, 4, 5, 6, 7, 8]);
+        process.advice = core::mem::take(&mut process.advice).with_pop_limit(4);
+        let program = &MastForest::default();
+
+        // popping a single element and then a word exceeds the limit
+        process.execute_op(Operation::AdvPop, program, &mut host).unwrap();
+        assert_matches!(
+            process.execute_op(Operation::AdvPopW, program, &mut host),
+            Err(ExecutionError::AdviceError {
+                err: AdviceError::StackPopLimitExceeded { limit: 4 },
+                ..
+            })
+        );
+        assert_eq!(process.advice.stack.len(), 5);
+    }
+
     /// Ensures that reading and writing in the same clock cycle results in an error.
     #[test]
     fn read_and_write_in_same_clock_cycle() {
@@ -18,6 +18,12 @@ pub(crate) mod utils;
 #[cfg(test)]
 use super::Kernel;
 
+/// The maximum trace length which can be pre-allocated via [Process::reserve_trace_capacity].
+///
+/// Reservations are driven by hints embedded in the program, and so they are capped to avoid
+/// allocating large amounts of memory up front; the trace can still grow beyond this length.
+const MAX_RESERVED_TRACE_LEN: usize = 1 << 20;
+
 // OPERATION DISPATCHER
 // ================================================================================================
 
@@ -187,6 +193,7 @@ impl Process {
     /// Increments the clock cycle for all components of the process.
     pub(super) fn advance_clock(&mut self) -> Result<(), ExecutionError> {
         self.system.advance_clock(self.max_cycles)?;
+        self.stack.check_depth()?;
         self.stack.advance_clock();
         Ok(())
     }
@@ -196,6 +203,20 @@ impl Process {
         self.system.ensure_trace_capacity();
         self.stack.ensure_trace_capacity();
     }
+
+    /// Pre-allocates the trace so that it can accommodate the specified number of clock cycles
+    /// past the current one without having to grow.
+    ///
+    /// The reservation never exceeds the maximum number of cycles allowed for the process, nor
+    /// [MAX_RESERVED_TRACE_LEN] rows.
+    pub(super) fn reserve_trace_capacity(&mut self, num_cycles: usize) {
+        let trace_len = (self.system.clk().as_usize() + 1)
+            .saturating_add(num_cycles)
+            .min(self.max_cycles as usize + 1)
+            .min(MAX_RESERVED_TRACE_LEN);
+        self.system.reserve_trace_capacity(trace_len);
+        self.stack.reserve_trace_capacity(trace_len);
+    }
 }
 
 #[cfg(test)]
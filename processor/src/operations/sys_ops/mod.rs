@@ -8,7 +8,9 @@ use super::{
     ExecutionError, Process,
 };
 use crate::{
-    SyncHost, errors::ErrorContext, operations::sys_ops::sys_event_handlers::handle_system_event,
+    SyncHost,
+    errors::ErrorContext,
+    operations::sys_ops::sys_event_handlers::{handle_system_event, insert_host_signing_key},
 };
 
 pub(crate) mod sys_event_handlers;
@@ -149,6 +151,9 @@ impl Process {
         let process = &mut self.state();
         // If it's a system event, handle it directly. Otherwise, forward it to the host.
         if let Some(system_event) = SystemEvent::from_event_id(event_id) {
+            if let SystemEvent::Signature { kind } = system_event {
+                insert_host_signing_key(process, host, kind);
+            }
             handle_system_event(process, system_event, err_ctx)
         } else {
             host.on_event(process, event_id, err_ctx)
@@ -1,20 +1,39 @@
 use alloc::vec::Vec;
 
 use miden_core::{
-    Felt, FieldElement, QuadFelt, WORD_SIZE, Word, ZERO,
+    Felt, FieldElement, QuadFelt, SignatureKind, WORD_SIZE, Word, ZERO,
     crypto::{
         hash::Rpo256,
         merkle::{EmptySubtreeRoots, SMT_DEPTH, Smt},
+        random::{FeltRng, RpoRandomCoin},
     },
+    errors::SignatureError,
     sys_events::SystemEvent,
-    utils::collections::KvMap,
+    utils::{collections::KvMap, math::batch_inversion},
 };
 
-use crate::{ExecutionError, MemoryError, ProcessState, errors::ErrorContext};
+use crate::{
+    AdviceError, BaseHost, ExecutionError, MemoryError, ProcessState, errors::ErrorContext,
+};
 
 /// The offset of the domain value on the stack in the `hdword_to_map_with_domain` system event.
 pub const HDWORD_TO_MAP_WITH_DOMAIN_DOMAIN_OFFSET: usize = 8;
 
+/// The maximum number of elements which can be pushed onto the advice stack by a single
+/// `push_random` system event.
+pub const MAX_RANDOM_ELEMENTS: u32 = 1 << 16;
+
+/// The maximum number of words which can be inserted into the advice map by a single
+/// `hashed_mem_to_map` system event.
+pub const MAX_HASHED_MEM_WORDS: u32 = 1 << 16;
+
+/// The maximum number of elements which can be inverted by a single `push_batch_inv` system event.
+pub const MAX_BATCH_INV_ELEMENTS: u32 = 1 << 16;
+
+/// The maximum length in bytes of a message which can be hashed by a single `push_sha256` or
+/// `push_keccak256` system event.
+pub const MAX_HASHED_MESSAGE_BYTES: u32 = 1 << 20;
+
 /// Falcon signature prime.
 const M: u64 = 12289;
 
@@ -38,7 +57,13 @@ pub fn handle_system_event(
         SystemEvent::U32Clo => push_leading_ones(process, err_ctx),
         SystemEvent::U32Cto => push_trailing_ones(process, err_ctx),
         SystemEvent::ILog2 => push_ilog2(process, err_ctx),
+        SystemEvent::Sha256ToStack => push_sha256_digest(process, err_ctx),
+        SystemEvent::Keccak256ToStack => push_keccak256_digest(process, err_ctx),
+        SystemEvent::BatchInv => push_batch_inv_result(process, err_ctx),
+        SystemEvent::DrawRandom => push_random_elements(process, err_ctx),
+        SystemEvent::Signature { kind } => push_signature(process, kind, err_ctx),
         SystemEvent::MemToMap => insert_mem_values_into_adv_map(process),
+        SystemEvent::HashedMemToMap => insert_hashed_mem_values_into_adv_map(process, err_ctx),
         SystemEvent::HdwordToMap => insert_hdword_into_adv_map(process, ZERO),
         SystemEvent::HdwordToMapWithDomain => {
             let domain = process.get_stack_item(HDWORD_TO_MAP_WITH_DOMAIN_DOMAIN_OFFSET);
@@ -83,6 +108,68 @@ fn insert_mem_values_into_adv_map(process: &mut ProcessState) -> Result<(), Exec
     Ok(())
 }
 
+/// Reads `n` words from memory starting at address `ptr` and inserts them into the advice map
+/// under the key defined by the hash of these words.
+///
+/// Inputs:
+///   Operand stack: [ptr, n, ...]
+///   Advice map: {...}
+///
+/// Outputs:
+///   Operand stack: [ptr, n, ...]
+///   Advice map: {KEY: values}
+///
+/// Where `values` are the elements located in memory[ptr..ptr + 4 * n], and KEY is computed as
+/// hash(values). Memory which has not been initialized is treated as zeros.
+///
+/// # Errors
+/// Returns an error if:
+/// - `ptr` or `n` is not a u32 value.
+/// - `n` is greater than [MAX_HASHED_MEM_WORDS].
+/// - The words extend beyond the addressable memory.
+fn insert_hashed_mem_values_into_adv_map(
+    process: &mut ProcessState,
+    err_ctx: &impl ErrorContext,
+) -> Result<(), ExecutionError> {
+    let ptr = process.get_stack_item(0);
+    let n = process.get_stack_item(1);
+
+    let ptr: u32 = ptr
+        .as_int()
+        .try_into()
+        .map_err(|_| ExecutionError::not_u32_value(ptr, ZERO, err_ctx))?;
+    let n: u32 = n
+        .as_int()
+        .try_into()
+        .map_err(|_| ExecutionError::not_u32_value(n, ZERO, err_ctx))?;
+    if n > MAX_HASHED_MEM_WORDS {
+        return Err(ExecutionError::advice_error(
+            AdviceError::TooManyMemWords { count: n, max: MAX_HASHED_MEM_WORDS },
+            process.clk(),
+            err_ctx,
+        ));
+    }
+
+    let num_elements = n as u64 * WORD_SIZE as u64;
+    let end_addr = ptr as u64 + num_elements;
+    if end_addr > u32::MAX as u64 + 1 {
+        return Err(ExecutionError::MemoryError(MemoryError::address_out_of_bounds(
+            end_addr - 1,
+            err_ctx,
+        )));
+    }
+
+    let ctx = process.ctx();
+    let values: Vec<Felt> = (0..num_elements as u32)
+        .map(|i| process.get_mem_value(ctx, ptr + i).unwrap_or(ZERO))
+        .collect();
+
+    let key = Rpo256::hash_elements(&values);
+    process.advice_provider_mut().insert_into_map(key, values);
+
+    Ok(())
+}
+
 /// Reads two word from the operand stack and inserts them into the advice map under the key
 /// defined by the hash of these words.
 ///
@@ -447,6 +534,177 @@ fn push_ext2_inv_result(
     Ok(())
 }
 
+/// Reads `n` field elements from memory and pushes their multiplicative inverses onto the advice
+/// stack.
+///
+/// Inputs:
+///   Operand stack: [ptr, n, ...]
+///   Advice stack: [...]
+///
+/// Outputs:
+///   Operand stack: [ptr, n, ...]
+///   Advice stack: [b_0, b_1, ..., b_{n-1}, ...]
+///
+/// Where `b_i` is the inverse of the element `a_i` located in memory at address `ptr + i`, or `0`
+/// if `a_i` is zero. The inverses are computed using Montgomery's batch inversion trick, and so
+/// only a single field inversion is performed regardless of `n`.
+///
+/// # Errors
+/// Returns an error if:
+/// - `ptr` or `n` is not a u32 value.
+/// - `n` is greater than [MAX_BATCH_INV_ELEMENTS].
+/// - The elements extend beyond the addressable memory.
+fn push_batch_inv_result(
+    process: &mut ProcessState,
+    err_ctx: &impl ErrorContext,
+) -> Result<(), ExecutionError> {
+    let ptr = process.get_stack_item(0);
+    let n = process.get_stack_item(1);
+
+    let ptr: u32 = ptr
+        .as_int()
+        .try_into()
+        .map_err(|_| ExecutionError::not_u32_value(ptr, ZERO, err_ctx))?;
+    let n: u32 = n
+        .as_int()
+        .try_into()
+        .map_err(|_| ExecutionError::not_u32_value(n, ZERO, err_ctx))?;
+    if n > MAX_BATCH_INV_ELEMENTS {
+        return Err(ExecutionError::advice_error(
+            AdviceError::TooManyBatchInvElements { count: n, max: MAX_BATCH_INV_ELEMENTS },
+            process.clk(),
+            err_ctx,
+        ));
+    }
+
+    let end_addr = ptr as u64 + n as u64;
+    if end_addr > u32::MAX as u64 + 1 {
+        return Err(ExecutionError::MemoryError(MemoryError::address_out_of_bounds(
+            end_addr - 1,
+            err_ctx,
+        )));
+    }
+
+    let ctx = process.ctx();
+    let values: Vec<Felt> =
+        (0..n).map(|i| process.get_mem_value(ctx, ptr + i).unwrap_or(ZERO)).collect();
+
+    // zero elements are mapped to zero by the batch inversion
+    let inverses = batch_inversion(&values);
+
+    // push the inverses in reverse order so that the inverse of the first element ends up at the
+    // top of the advice stack
+    for inverse in inverses.into_iter().rev() {
+        process.advice_provider_mut().push_stack(inverse);
+    }
+
+    Ok(())
+}
+
+/// Draws pseudo-random field elements from a random coin seeded from the operand stack and pushes
+/// them onto the advice stack.
+///
+/// Inputs:
+///   Operand stack: [SEED, n, ...]
+///   Advice stack: [...]
+///
+/// Outputs:
+///   Operand stack: [SEED, n, ...]
+///   Advice stack: [r_0, r_1, ..., r_{n-1}, ...]
+///
+/// Where `r_i` is the i-th element drawn from an [RpoRandomCoin] instantiated with `SEED`.
+///
+/// # Errors
+/// Returns an error if:
+/// - `n` is not a u32 value.
+/// - `n` is greater than [MAX_RANDOM_ELEMENTS].
+fn push_random_elements(
+    process: &mut ProcessState,
+    err_ctx: &impl ErrorContext,
+) -> Result<(), ExecutionError> {
+    let seed = process.get_stack_word(0);
+    let n = process.get_stack_item(4);
+    let n: u32 = n
+        .as_int()
+        .try_into()
+        .map_err(|_| ExecutionError::not_u32_value(n, ZERO, err_ctx))?;
+    if n > MAX_RANDOM_ELEMENTS {
+        return Err(ExecutionError::advice_error(
+            AdviceError::TooManyRandomElements { count: n, max: MAX_RANDOM_ELEMENTS },
+            process.clk(),
+            err_ctx,
+        ));
+    }
+
+    let mut coin = RpoRandomCoin::new(seed);
+    let elements: Vec<Felt> = (0..n).map(|_| coin.draw_element()).collect();
+
+    // push the elements in reverse order so that the first drawn element ends up at the top of
+    // the advice stack
+    for element in elements.into_iter().rev() {
+        process.advice_provider_mut().push_stack(element);
+    }
+
+    Ok(())
+}
+
+/// Pushes onto the advice stack the values required to verify a signature of the specified kind
+/// in the VM.
+///
+/// See [`SystemEvent::Signature`] for the expected inputs and the produced outputs.
+///
+/// # Errors
+/// Returns an error if:
+/// - signatures of the specified kind cannot be generated by this processor.
+/// - the advice map does not contain an entry for PK.
+/// - the secret key associated with PK is malformed.
+fn push_signature(
+    process: &mut ProcessState,
+    kind: SignatureKind,
+    err_ctx: &impl ErrorContext,
+) -> Result<(), ExecutionError> {
+    let pub_key = process.get_stack_word(0);
+    let msg = process.get_stack_word(1);
+
+    let secret_key = process
+        .advice_provider()
+        .get_mapped_values(&pub_key)
+        .map_err(|err| ExecutionError::advice_error(err, process.clk(), err_ctx))?;
+
+    let signature = kind.sign(secret_key, msg).map_err(|err| match err {
+        SignatureError::MalformedSecretKey => {
+            ExecutionError::malformed_signature_key(kind.name(), err_ctx)
+        },
+        SignatureError::SigningUnsupported => {
+            ExecutionError::unsupported_signature_kind(kind, err_ctx)
+        },
+    })?;
+
+    for value in signature {
+        process.advice_provider_mut().push_stack(value);
+    }
+    Ok(())
+}
+
+/// Inserts the secret key which `host` associates with the public key on top of the operand stack
+/// into the advice map, unless the advice map already contains an entry for the public key.
+///
+/// This allows hosts to provide the secret keys required by the [`SystemEvent::Signature`] event
+/// via [`BaseHost::get_signing_key`].
+pub(crate) fn insert_host_signing_key(
+    process: &mut ProcessState,
+    host: &impl BaseHost,
+    kind: SignatureKind,
+) {
+    let pub_key = process.get_stack_word(0);
+    if process.advice_provider().get_mapped_values(&pub_key).is_ok() {
+        return;
+    }
+    if let Some(secret_key) = host.get_signing_key(kind, pub_key) {
+        process.advice_provider_mut().insert_into_map(pub_key, secret_key);
+    }
+}
+
 /// Pushes the number of the leading zeros of the top stack element onto the advice stack.
 ///
 /// Inputs:
@@ -536,6 +794,86 @@ fn push_ilog2(
     Ok(())
 }
 
+/// Reads a byte-encoded message from memory, computes its SHA-256 digest and pushes the digest
+/// onto the advice stack.
+///
+/// Inputs:
+///   Operand stack: [ptr, len, ...]
+///   Advice stack: [...]
+///
+/// Outputs:
+///   Operand stack: [ptr, len, ...]
+///   Advice stack: [d0, d1, d2, d3, d4, d5, d6, d7, ...]
+///
+/// Where `len` is the length of the message in bytes, and the message is read from memory
+/// starting at address `ptr` with each memory element holding 4 bytes of the message packed as a
+/// big-endian u32 value. The digest is represented by 8 big-endian u32 values `d0, ..., d7`.
+///
+/// # Errors
+/// Returns an error if:
+/// - `ptr` or `len` is not a u32 value.
+/// - `len` is greater than [MAX_HASHED_MESSAGE_BYTES].
+/// - The message extends beyond the addressable memory.
+/// - Any of the memory elements holding the message is not a u32 value.
+fn push_sha256_digest(
+    process: &mut ProcessState,
+    err_ctx: &impl ErrorContext,
+) -> Result<(), ExecutionError> {
+    use sha2::{Digest, Sha256};
+
+    let message = read_mem_bytes(process, u32::to_be_bytes, err_ctx)?;
+    let digest = Sha256::digest(&message);
+
+    // push the digest words in reverse order so that the first word ends up at the top of the
+    // advice stack
+    for chunk in digest.chunks_exact(4).rev() {
+        let word = u32::from_be_bytes(chunk.try_into().expect("chunk must have 4 bytes"));
+        process.advice_provider_mut().push_stack(Felt::from(word));
+    }
+
+    Ok(())
+}
+
+/// Reads a byte-encoded message from memory, computes its keccak-256 digest and pushes the digest
+/// onto the advice stack.
+///
+/// Inputs:
+///   Operand stack: [ptr, len, ...]
+///   Advice stack: [...]
+///
+/// Outputs:
+///   Operand stack: [ptr, len, ...]
+///   Advice stack: [d0, d1, d2, d3, d4, d5, d6, d7, ...]
+///
+/// Where `len` is the length of the message in bytes, and the message is read from memory
+/// starting at address `ptr` with each memory element holding 4 bytes of the message packed as a
+/// little-endian u32 value. The digest is represented by 8 little-endian u32 values `d0, ..., d7`.
+///
+/// # Errors
+/// Returns an error if:
+/// - `ptr` or `len` is not a u32 value.
+/// - `len` is greater than [MAX_HASHED_MESSAGE_BYTES].
+/// - The message extends beyond the addressable memory.
+/// - Any of the memory elements holding the message is not a u32 value.
+fn push_keccak256_digest(
+    process: &mut ProcessState,
+    err_ctx: &impl ErrorContext,
+) -> Result<(), ExecutionError> {
+    use sha3::{Digest, Keccak256};
+
+    let message = read_mem_bytes(process, u32::to_le_bytes, err_ctx)?;
+    let digest = Keccak256::digest(&message);
+
+    // push the digest words in reverse order so that the first word ends up at the top of the
+    // advice stack
+    for chunk in digest.chunks_exact(4).rev() {
+        let word = u32::from_le_bytes(chunk.try_into().expect("chunk must have 4 bytes"));
+        process.advice_provider_mut().push_stack(Felt::from(word));
+    }
+
+    Ok(())
+}
+
 /// Pushes onto the advice stack the value associated with the specified key in a Sparse
 /// Merkle Tree defined by the specified root.
 ///
@@ -621,6 +959,63 @@ fn get_mem_addr_range(
     Ok((start_addr as u32, end_addr as u32))
 }
 
+/// Reads a byte-encoded message from memory, where the memory pointer and the length of the
+/// message in bytes are specified by the first two elements of the operand stack.
+///
+/// Each memory element is expected to hold 4 bytes of the message packed as a u32 value, which is
+/// decoded into bytes using the provided `to_bytes` function; bytes of the last element beyond
+/// the message length are ignored. Memory which has not been initialized is treated as zeros.
+///
+/// The length of the message is capped at [MAX_HASHED_MESSAGE_BYTES].
+fn read_mem_bytes(
+    process: &ProcessState,
+    to_bytes: fn(u32) -> [u8; 4],
+    err_ctx: &impl ErrorContext,
+) -> Result<Vec<u8>, ExecutionError> {
+    let ptr = process.get_stack_item(0);
+    let len = process.get_stack_item(1);
+
+    let ptr: u32 = ptr
+        .as_int()
+        .try_into()
+        .map_err(|_| ExecutionError::not_u32_value(ptr, ZERO, err_ctx))?;
+    let len: u32 = len
+        .as_int()
+        .try_into()
+        .map_err(|_| ExecutionError::not_u32_value(len, ZERO, err_ctx))?;
+    if len > MAX_HASHED_MESSAGE_BYTES {
+        return Err(ExecutionError::advice_error(
+            AdviceError::MessageTooLong { len, max: MAX_HASHED_MESSAGE_BYTES },
+            process.clk(),
+            err_ctx,
+        ));
+    }
+
+    // make sure the whole message is located within the addressable memory
+    let num_elements = len.div_ceil(4);
+    let end_addr = ptr as u64 + num_elements as u64;
+    if end_addr > u32::MAX as u64 + 1 {
+        return Err(ExecutionError::MemoryError(MemoryError::address_out_of_bounds(
+            end_addr - 1,
+            err_ctx,
+        )));
+    }
+
+    let ctx = process.ctx();
+    let mut bytes = Vec::with_capacity(num_elements as usize * 4);
+    for i in 0..num_elements {
+        let element = process.get_mem_value(ctx, ptr + i).unwrap_or(ZERO);
+        let element: u32 = element
+            .as_int()
+            .try_into()
+            .map_err(|_| ExecutionError::not_u32_value(element, ZERO, err_ctx))?;
+        bytes.extend_from_slice(&to_bytes(element));
+    }
+    bytes.truncate(len as usize);
+
+    Ok(bytes)
+}
+
 fn u64_to_u32_elements(value: u64) -> (Felt, Felt) {
     let hi = Felt::from((value >> 32) as u32);
     let lo = Felt::from(value as u32);
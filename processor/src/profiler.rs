@@ -0,0 +1,138 @@
+use alloc::{collections::BTreeMap, vec::Vec};
+
+use miden_air::RowIndex;
+use miden_core::Word;
+
+use crate::Process;
+
+// PROFILING REPORT
+// ================================================================================================
+
+/// Cycles spent in each call frame entered during the execution of a program.
+///
+/// The profile is kept per call frame rather than per procedure: a frame is entered when a
+/// procedure is invoked via `call`, `syscall`, `dyncall` or `dynexec`, and by the program
+/// entrypoint. Frames are identified by the MAST root of the invoked procedure. Procedures invoked
+/// via `exec` do not enter a frame of their own, and so their cycles are attributed to the frame
+/// from which they were invoked.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct CallProfilingReport {
+    frames: BTreeMap<Word, CallFrameProfile>,
+}
+
+impl CallProfilingReport {
+    /// Returns the profile of the call frames of the procedure with the specified MAST root, or
+    /// `None` if the procedure was never invoked in a call frame of its own.
+    pub fn get(&self, procedure_root: &Word) -> Option<&CallFrameProfile> {
+        self.frames.get(procedure_root)
+    }
+
+    /// Returns an iterator over the MAST roots and profiles of the invoked procedures.
+    pub fn iter(&self) -> impl Iterator<Item = (&Word, &CallFrameProfile)> {
+        self.frames.iter()
+    }
+}
+
+/// Cycles spent in the call frames of a single procedure, accumulated over all of its
+/// invocations.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct CallFrameProfile {
+    num_calls: usize,
+    inclusive_cycles: u32,
+    exclusive_cycles: u32,
+}
+
+impl CallFrameProfile {
+    /// Returns the number of call frames in which the procedure was invoked.
+    pub fn num_calls(&self) -> usize {
+        self.num_calls
+    }
+
+    /// Returns the number of cycles spent in the call frames of the procedure, including the
+    /// cycles spent in the call frames they entered.
+    pub fn inclusive_cycles(&self) -> u32 {
+        self.inclusive_cycles
+    }
+
+    /// Returns the number of cycles spent in the call frames of the procedure, excluding the
+    /// cycles spent in the call frames they entered.
+    pub fn exclusive_cycles(&self) -> u32 {
+        self.exclusive_cycles
+    }
+}
+
+// PROFILER
+// ================================================================================================
+
+/// Builds a [CallProfilingReport] from the call frames entered and exited during execution.
+#[derive(Debug, Default)]
+pub(crate) struct Profiler {
+    frames: Vec<Frame>,
+    report: CallProfilingReport,
+}
+
+/// A call frame which has been entered, but not yet exited.
+#[derive(Debug)]
+struct Frame {
+    procedure_root: Word,
+    start_clk: RowIndex,
+    callee_cycles: u32,
+}
+
+impl Profiler {
+    pub fn report(&self) -> &CallProfilingReport {
+        &self.report
+    }
+
+    /// Records that a call frame of the procedure with the specified MAST root starts at the
+    /// specified cycle.
+    pub fn enter(&mut self, procedure_root: Word, clk: RowIndex) {
+        self.frames.push(Frame {
+            procedure_root,
+            start_clk: clk,
+            callee_cycles: 0,
+        });
+    }
+
+    /// Records that the most recently entered call frame ends before the specified cycle.
+    pub fn exit(&mut self, clk: RowIndex) {
+        let frame = self.frames.pop().expect("no call frame to exit");
+        let cycles = clk.as_u32() - frame.start_clk.as_u32();
+
+        let profile = self.report.frames.entry(frame.procedure_root).or_default();
+        profile.num_calls += 1;
+        profile.exclusive_cycles += cycles - frame.callee_cycles;
+        // cycles of a procedure which (indirectly) invokes itself are counted once
+        if self.frames.iter().all(|caller| caller.procedure_root != frame.procedure_root) {
+            profile.inclusive_cycles += cycles;
+        }
+
+        if let Some(caller) = self.frames.last_mut() {
+            caller.callee_cycles += cycles;
+        }
+    }
+}
+
+impl Process {
+    /// Returns the cycles spent in each call frame entered so far, or `None` if profiling was not
+    /// enabled via [ExecutionOptions::with_profiling](crate::ExecutionOptions::with_profiling).
+    pub fn call_profiling_report(&self) -> Option<&CallProfilingReport> {
+        self.profiler.as_ref().map(Profiler::report)
+    }
+
+    /// Records that a call frame of the procedure with the specified MAST root starts at the
+    /// current cycle, if profiling is enabled.
+    pub(crate) fn enter_call_frame(&mut self, procedure_root: Word) {
+        if let Some(profiler) = &mut self.profiler {
+            profiler.enter(procedure_root, self.system.clk());
+        }
+    }
+
+    /// Records that the most recently entered call frame ends before the current cycle, if
+    /// profiling is enabled.
+    pub(crate) fn exit_call_frame(&mut self) {
+        if let Some(profiler) = &mut self.profiler {
+            profiler.exit(self.system.clk());
+        }
+    }
+}
@@ -63,6 +63,7 @@ pub struct Stack {
     overflow: OverflowTable,
     active_depth: usize,
     full_depth: usize,
+    max_depth: usize,
 }
 
 impl Stack {
@@ -82,9 +83,16 @@ impl Stack {
             overflow: OverflowTable::new(save_overflow_history),
             active_depth: MIN_STACK_DEPTH,
             full_depth: MIN_STACK_DEPTH,
+            max_depth: usize::MAX,
         }
     }
 
+    /// Sets the maximum depth the stack is allowed to reach across all execution contexts.
+    pub fn with_max_depth(mut self, max_depth: usize) -> Self {
+        self.max_depth = max_depth;
+        self
+    }
+
     // PUBLIC ACCESSORS
     // --------------------------------------------------------------------------------------------
 
@@ -353,6 +361,23 @@ impl Stack {
         self.trace.ensure_trace_capacity(self.clk);
     }
 
+    /// Makes sure the trace can accommodate at least `trace_len` rows without being resized.
+    pub fn reserve_trace_capacity(&mut self, trace_len: usize) {
+        self.trace.reserve_trace_capacity(trace_len);
+    }
+
+    /// Returns an error if the depth of the stack across all execution contexts exceeds the
+    /// maximum allowed depth at the current clock cycle.
+    pub fn check_depth(&self) -> Result<(), ExecutionError> {
+        if self.full_depth > self.max_depth {
+            return Err(ExecutionError::StackDepthExceeded {
+                clk: self.clk,
+                depth: self.full_depth,
+            });
+        }
+        Ok(())
+    }
+
     /// Increments the clock cycle.
     pub fn advance_clock(&mut self) {
         self.clk += 1_u32;
@@ -166,6 +166,15 @@ impl StackTrace {
         }
     }
 
+    /// Makes sure the trace can accommodate at least `trace_len` rows without being resized.
+    pub fn reserve_trace_capacity(&mut self, trace_len: usize) {
+        if trace_len > get_trace_len(&self.stack) {
+            for column in self.stack.iter_mut().chain(self.helpers.iter_mut()) {
+                column.resize(trace_len, ZERO);
+            }
+        }
+    }
+
     /// Appends stack top state (16 items) at the specified clock cycle into the provided vector.
     pub fn append_state_into(&self, result: &mut Vec<Felt>, clk: RowIndex) {
         for column in self.stack.iter() {
@@ -146,7 +146,7 @@ impl System {
 
         // Check that maximum number of cycles is not exceeded.
         if self.clk.as_u32() > max_cycles {
-            return Err(ExecutionError::CycleLimitExceeded(max_cycles));
+            return Err(ExecutionError::CycleLimitExceeded { limit: max_cycles });
         }
 
         let clk: usize = self.clk.into();
@@ -298,6 +298,25 @@ impl System {
         }
     }
 
+    /// Makes sure the trace can accommodate at least `trace_len` rows without being resized.
+    pub fn reserve_trace_capacity(&mut self, trace_len: usize) {
+        if trace_len > self.clk_trace.len() {
+            self.clk_trace.resize(trace_len, ZERO);
+            self.ctx_trace.resize(trace_len, ZERO);
+            self.fmp_trace.resize(trace_len, ZERO);
+            self.in_syscall_trace.resize(trace_len, ZERO);
+            for column in self.fn_hash_trace.iter_mut() {
+                column.resize(trace_len, ZERO);
+            }
+        }
+    }
+
+    /// Returns the number of rows currently allocated for the system columns of the trace.
+    #[cfg(test)]
+    pub fn trace_capacity(&self) -> usize {
+        self.clk_trace.len()
+    }
+
     /// Returns the next context ID that would be created given the current state.
     ///
     /// Note: This only applies to the context created upon a `CALL` or `DYNCALL` operation;
@@ -152,8 +152,7 @@ fn test_diagnostic_advice_stack_read_failed() {
     let err = build_test.execute().expect_err("expected error");
     assert_diagnostic_lines!(
         err,
-        "advice provider error at clock cycle 2",
-        "stack read failed",
+        "advice stack read failed at clock cycle 2 in context 0",
         regex!(r#",-\[test[\d]+:3:18\]"#),
         " 2 |         begin",
         " 3 |             swap adv_push.1 trace.2",
@@ -323,6 +322,38 @@ fn test_diagnostic_failed_assertion() {
     );
 }
 
+/// Checks that the source location of the failing instruction is carried by the error itself, so
+/// that debuggers can point to the originating MASM line without rendering the diagnostic.
+#[test]
+fn test_failed_assertion_source_location() {
+    let source = "
+        begin
+            push.1.2
+            assertz.err=\"some error message\"
+            push.3.4
+        end";
+
+    let build_test = build_test_by_mode!(true, source, &[1, 2]);
+    let err = build_test.execute().expect_err("expected error");
+    let miden_utils_testing::ExecutionError::FailedAssertion {
+        label, source_file, err_msg, ..
+    } = err
+    else {
+        panic!("expected a failed assertion, got: {err}");
+    };
+    assert_eq!(err_msg.as_deref(), Some("some error message"));
+
+    let source_file = source_file.expect("expected the error to carry the source file");
+    assert_eq!(source_file.id(), build_test.source.id());
+    let location = source_file.location(label);
+    assert_eq!(location.line.to_u32(), 4);
+    assert_eq!(location.column.to_u32(), 13);
+    assert_eq!(
+        source_file.source_slice(label.into_slice_index()),
+        Some("assertz.err=\"some error message\"")
+    );
+}
+
 #[test]
 fn test_diagnostic_merkle_path_verification_failed() {
     // No message
@@ -985,7 +1016,30 @@ fn test_diagnostic_syscall_target_not_in_kernel() {
         " 3 |             syscall.dummy_proc",
         "   :             ^^^^^^^^^^^^^^^^^^",
         " 4 |         end",
-        "   `----"
+        "   `----",
+        "  help: the kernel does not contain any procedures"
+    );
+
+    // when the kernel is not empty, its procedure roots are listed in the error
+    let kernel = Kernel::new(&[Word::from([1_u32, 0, 1, 0])]).unwrap();
+    let mut process = Process::new(
+        kernel,
+        StackInputs::default(),
+        AdviceInputs::default(),
+        ExecutionOptions::default().with_debugging(true),
+    )
+    .with_source_manager(source_manager.clone());
+    let err = process.execute(&program, &mut DefaultHost::default()).unwrap_err();
+    assert_diagnostic_lines!(
+        err,
+        "syscall failed: procedure with root d754f5422c74afd0b094889be6b288f9ffd2cc630e3c44d412b1408b2be3b99c was not found in the kernel",
+        regex!(r#",-\[\$exec:3:13\]"#),
+        " 2 |         begin",
+        " 3 |             syscall.dummy_proc",
+        "   :             ^^^^^^^^^^^^^^^^^^",
+        " 4 |         end",
+        "   `----",
+        "  help: the kernel contains procedures with roots: 0100000000000000000000000000000001000000000000000000000000000000"
     );
 }
 
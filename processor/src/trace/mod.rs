@@ -12,7 +12,8 @@ use winter_prover::{EvaluationFrame, Trace, TraceInfo, crypto::RandomCoin};
 
 use super::{
     AdviceProvider, ColMatrix, Felt, FieldElement, Process,
-    chiplets::AuxTraceBuilder as ChipletsAuxTraceBuilder, crypto::RpoRandomCoin,
+    chiplets::{AuxTraceBuilder as ChipletsAuxTraceBuilder, ChipletSegmentLengths},
+    crypto::RpoRandomCoin,
     decoder::AuxTraceBuilder as DecoderAuxTraceBuilder,
     range::AuxTraceBuilder as RangeCheckerAuxTraceBuilder,
     stack::AuxTraceBuilder as StackAuxTraceBuilder,
@@ -60,6 +61,7 @@ pub struct ExecutionTrace {
     stack_outputs: StackOutputs,
     advice: AdviceProvider,
     trace_len_summary: TraceLenSummary,
+    chiplet_segment_lengths: ChipletSegmentLengths,
 }
 
 impl ExecutionTrace {
@@ -84,6 +86,7 @@ impl ExecutionTrace {
         let kernel = process.kernel().clone();
         let program_info = ProgramInfo::new(program_hash, kernel);
         let advice = mem::take(&mut process.advice);
+        let chiplet_segment_lengths = process.chiplets.segment_lengths();
         let (main_trace, aux_trace_builders, trace_len_summary) = finalize_trace(process, rng);
         let trace_info = TraceInfo::new_multi_segment(
             PADDED_TRACE_WIDTH,
@@ -102,6 +105,7 @@ impl ExecutionTrace {
             stack_outputs,
             advice,
             trace_len_summary,
+            chiplet_segment_lengths,
         }
     }
 
@@ -162,6 +166,11 @@ impl ExecutionTrace {
         &self.trace_len_summary
     }
 
+    /// Returns the number of rows occupied by each segment of the chiplets trace.
+    pub fn chiplet_segment_lengths(&self) -> ChipletSegmentLengths {
+        self.chiplet_segment_lengths
+    }
+
     /// Returns the final advice provider state.
     pub fn advice_provider(&self) -> &AdviceProvider {
         &self.advice
@@ -278,6 +287,28 @@ impl Trace for ExecutionTrace {
 // HELPER FUNCTIONS
 // ================================================================================================
 
+/// Returns the length of the execution trace which would be built from the provided process once
+/// it has finished executing, without padding and assembling the recorded trace columns.
+pub(crate) fn estimate_trace_len(process: Process) -> usize {
+    let (system, _, _, mut range, chiplets) = process.into_parts();
+
+    // the range checks required by the chiplets contribute to the length of the range trace
+    chiplets.append_range_checks(&mut range);
+
+    padded_trace_len(
+        system.clk().into(),
+        range.get_number_range_checker_rows(),
+        chiplets.trace_len(),
+    )
+}
+
+/// Returns the length of a trace which can hold the longest of the provided trace segments, padded
+/// to the next power of two while leaving space for the rows which hold random values.
+fn padded_trace_len(clk: usize, range_table_len: usize, chiplets_len: usize) -> usize {
+    let max_len = range_table_len.max(clk).max(chiplets_len);
+    (max_len + NUM_RAND_ROWS).next_power_of_two()
+}
+
 /// Converts a process into a set of execution trace columns for each component of the trace.
 ///
 /// The process includes:
@@ -305,12 +336,8 @@ fn finalize_trace(
     // Generate number of rows for the range trace.
     let range_table_len = range.get_number_range_checker_rows();
 
-    // Get the trace length required to hold all execution trace steps.
-    let max_len = range_table_len.max(clk.into()).max(chiplets.trace_len());
-
-    // Pad the trace length to the next power of two and ensure that there is space for the
-    // Rows to hold random values
-    let trace_len = (max_len + NUM_RAND_ROWS).next_power_of_two();
+    // Get the padded trace length required to hold all execution trace steps.
+    let trace_len = padded_trace_len(clk.into(), range_table_len, chiplets.trace_len());
     assert!(
         trace_len >= MIN_TRACE_LEN,
         "trace length must be at least {MIN_TRACE_LEN}, but was {trace_len}",
@@ -1,5 +1,6 @@
-use alloc::vec::Vec;
+use alloc::{sync::Arc, vec::Vec};
 
+use miden_assembly::{Assembler, DefaultSourceManager};
 use miden_core::{Kernel, ONE, Operation, Program, StackOutputs, Word, ZERO, mast::MastForest};
 use miden_utils_testing::rand::rand_array;
 
@@ -7,7 +8,9 @@ use super::{
     super::chiplets::init_state_from_words, ExecutionTrace, Felt, FieldElement, NUM_RAND_ROWS,
     Process, Trace,
 };
-use crate::{AdviceInputs, DefaultHost, ExecutionOptions, StackInputs};
+use crate::{
+    AdviceInputs, DefaultHost, ExecutionOptions, StackInputs, estimate_trace_len, execute,
+};
 
 mod chiplets;
 mod decoder;
@@ -66,3 +69,89 @@ pub fn build_trace_from_ops_with_inputs(
     process.execute(&program, &mut host).unwrap();
     ExecutionTrace::new(process, StackOutputs::default())
 }
+
+// TRACE LENGTH ESTIMATION
+// ================================================================================================
+
+#[test]
+fn estimate_trace_len_matches_trace_len() {
+    let sources = [
+        // decoder and stack only
+        "begin push.1 push.2 add drop end",
+        // a long-running loop which dominates the trace length
+        "begin push.1000 dup neq.0 while.true sub.1 dup neq.0 end drop end",
+        // the hasher and bitwise chiplets
+        "begin push.1.2.3.4 hperm dropw dropw dropw push.5.6 u32and u32xor drop end",
+        // the memory chiplet and range checker
+        "begin push.1.2.3.4 mem_storew.4 dropw mem_loadw.4 mem_load.5 drop dropw end",
+    ];
+
+    for source in sources {
+        let source_manager = Arc::new(DefaultSourceManager::default());
+        let program = Assembler::new(source_manager.clone()).assemble_program(source).unwrap();
+
+        let estimate = estimate_trace_len(
+            &program,
+            StackInputs::default(),
+            AdviceInputs::default(),
+            &mut DefaultHost::default(),
+            ExecutionOptions::default(),
+            source_manager.clone(),
+        )
+        .unwrap();
+
+        let trace = execute(
+            &program,
+            StackInputs::default(),
+            AdviceInputs::default(),
+            &mut DefaultHost::default(),
+            ExecutionOptions::default(),
+            source_manager,
+        )
+        .unwrap();
+
+        assert_eq!(estimate, trace.get_trace_len(), "mismatched trace length for: {source}");
+    }
+}
+
+// LOOP ITERATION HINTS
+// ================================================================================================
+
+#[test]
+fn loop_iteration_hint_reserves_trace_capacity() {
+    let run = |source: &str| {
+        let program = Assembler::default().assemble_program(source).unwrap();
+        let mut process = Process::new(
+            Kernel::default(),
+            StackInputs::try_from_ints([1]).unwrap(),
+            AdviceInputs::default(),
+            ExecutionOptions::default(),
+        );
+        process.execute(&program, &mut DefaultHost::default()).unwrap();
+        let capacity = process.system.trace_capacity();
+        (ExecutionTrace::new(process, StackOutputs::default()), capacity)
+    };
+
+    // the loop runs for a single iteration, far fewer than the hint suggests
+    let (hinted, hinted_capacity) = run("begin while.true(max=4096) push.0 end end");
+    let (plain, plain_capacity) = run("begin while.true push.0 end end");
+
+    // after the LOOP row, the hinted loop reserved a row for the REPEAT, SPAN, PAD and END of
+    // each iteration
+    assert_eq!(hinted_capacity, 2 + 4096 * 4);
+    assert!(plain_capacity < hinted_capacity);
+
+    // the hint does not affect the execution of the program
+    assert_eq!(hinted.program_hash(), plain.program_hash());
+    assert_eq!(hinted.get_trace_len(), plain.get_trace_len());
+    assert_eq!(hinted.last_stack_state(), plain.last_stack_state());
+    let num_cols = plain.main_trace.num_cols();
+    let num_rows = plain.get_trace_len() - NUM_RAND_ROWS;
+    for col in 0..num_cols {
+        assert_eq!(
+            hinted.main_trace.get_column(col)[..num_rows],
+            plain.main_trace.get_column(col)[..num_rows],
+            "mismatched column {col}"
+        );
+    }
+}
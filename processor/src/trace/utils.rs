@@ -135,13 +135,20 @@ impl TraceLenSummary {
 }
 
 /// Contains trace lengths of all chilplets: hash, bitwise, memory and kernel ROM trace
-/// lengths.
-#[derive(Default, Clone, Copy, Debug, PartialEq, Eq)]
+/// lengths, as well as the number of mandatory padding rows.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub struct ChipletsLengths {
     hash_chiplet_len: usize,
     bitwise_chiplet_len: usize,
     memory_chiplet_len: usize,
     kernel_rom_len: usize,
+    num_padding_rows: usize,
+}
+
+impl Default for ChipletsLengths {
+    fn default() -> Self {
+        Self::from_parts(0, 0, 0, 0)
+    }
 }
 
 impl ChipletsLengths {
@@ -151,9 +158,12 @@ impl ChipletsLengths {
             bitwise_chiplet_len: chiplets.memory_start() - chiplets.bitwise_start(),
             memory_chiplet_len: chiplets.kernel_rom_start() - chiplets.memory_start(),
             kernel_rom_len: chiplets.padding_start() - chiplets.kernel_rom_start(),
+            num_padding_rows: chiplets.num_padding_rows(),
         }
     }
 
+    /// Returns [ChipletsLengths] built from the provided chiplet trace lengths, assuming
+    /// the default number of mandatory padding rows (i.e., 1).
     pub fn from_parts(
         hash_len: usize,
         bitwise_len: usize,
@@ -165,6 +175,7 @@ impl ChipletsLengths {
             bitwise_chiplet_len: bitwise_len,
             memory_chiplet_len: memory_len,
             kernel_rom_len: kernel_len,
+            num_padding_rows: Chiplets::DEFAULT_NUM_PADDING_ROWS,
         }
     }
 
@@ -188,15 +199,20 @@ impl ChipletsLengths {
         self.kernel_rom_len
     }
 
-    /// Returns the length of the trace required to accommodate chiplet components and 1
-    /// mandatory padding row required for ensuring sufficient trace length for auxiliary connector
-    /// columns that rely on the memory chiplet.
+    /// Returns the number of mandatory padding rows at the end of the chiplet traces.
+    pub fn num_padding_rows(&self) -> usize {
+        self.num_padding_rows
+    }
+
+    /// Returns the length of the trace required to accommodate chiplet components and the
+    /// mandatory padding rows (1 by default) required for ensuring sufficient trace length for
+    /// auxiliary connector columns that rely on the memory chiplet.
     pub fn trace_len(&self) -> usize {
         self.hash_chiplet_len()
             + self.bitwise_chiplet_len()
             + self.memory_chiplet_len()
             + self.kernel_rom_len()
-            + 1
+            + self.num_padding_rows()
     }
 }
 
@@ -2,13 +2,14 @@ use std::{sync::Arc, vec};
 
 use miden_air::{Felt, ProvingOptions, RowIndex};
 use miden_assembly::{Assembler, DefaultSourceManager, utils::Serializable};
-use miden_core::{StarkField, ZERO};
+use miden_core::{SignatureKind, StarkField, ZERO, sys_events::SystemEvent};
 use miden_processor::{
-    AdviceInputs, ExecutionError, Program, ProgramInfo, StackInputs, crypto::RpoRandomCoin,
+    AdviceInputs, DefaultHost, ExecutionError, ExecutionOptions, Program, ProgramInfo, StackInputs,
+    StackOutputs, crypto::RpoRandomCoin,
 };
 use miden_stdlib::{EVENT_FALCON_SIG_TO_STACK, StdLibrary, falcon_sign};
 use miden_utils_testing::{
-    Word,
+    AcceptableOptions, Word,
     crypto::{
         MerkleStore, Rpo256,
         rpo_falcon512::{Polynomial, SecretKey},
@@ -221,6 +222,90 @@ fn falcon_execution() {
     test.expect_stack(&[])
 }
 
+/// Similar to `falcon_execution` test, but the signature is pushed onto the advice stack by the
+/// processor via the generic signature system event instead of the host.
+#[test]
+fn falcon_execution_with_signature_event() {
+    let seed = Word::default();
+    let mut rng = RpoRandomCoin::new(seed);
+    let sk = SecretKey::with_rng(&mut rng);
+    let message = rand_value::<Word>();
+    let (_, op_stack, adv_stack, store, advice_map) = generate_test(sk, message);
+
+    // the stdlib event is dispatched to the host, and so must not be taken over by the signature
+    // system event
+    let event = SystemEvent::Signature { kind: SignatureKind::RpoFalcon512 };
+    assert_ne!(event.into_event_id(), EVENT_FALCON_SIG_TO_STACK);
+    assert_eq!(SystemEvent::from_event_id(EVENT_FALCON_SIG_TO_STACK), None);
+    let source = format!(
+        "
+    use.std::crypto::dsa::rpo_falcon512
+
+    begin
+        emit.{event_id}
+        exec.rpo_falcon512::verify
+    end
+    ",
+        event_id = event.into_event_id()
+    );
+
+    let test = build_test!(&source, &op_stack, &adv_stack, store, advice_map.into_iter());
+    test.expect_stack(&[])
+}
+
+/// Similar to `falcon_execution` test, but the advice map entries are provided by a host with
+/// a registered Falcon signer instead of being generated by the test.
+#[test]
+fn falcon_execution_with_signer() {
+    let seed = Word::default();
+    let mut rng = RpoRandomCoin::new(seed);
+    let sk = SecretKey::with_rng(&mut rng);
+    let message = rand_value::<Word>();
+    let (source, op_stack, ..) = generate_test(sk.clone(), message);
+
+    // the signer is consulted only by the signature system event
+    let event_id = SignatureKind::RpoFalcon512.event_id();
+    let source =
+        source.replace(&format!("emit.{EVENT_FALCON_SIG_TO_STACK}"), &format!("emit.{event_id}"));
+
+    let program: Program = Assembler::default()
+        .with_dynamic_library(StdLibrary::default())
+        .expect("failed to load stdlib")
+        .assemble_program(source)
+        .expect("failed to compile test source");
+
+    let stack_inputs = StackInputs::try_from_ints(op_stack).expect("failed to create stack inputs");
+    let mut host = DefaultHost::default().with_falcon_signer(sk);
+    host.load_mast_forest(StdLibrary::default().mast_forest().clone())
+        .expect("failed to load mast forest");
+
+    let trace = miden_processor::execute(
+        &program,
+        stack_inputs,
+        AdviceInputs::default(),
+        &mut host,
+        ExecutionOptions::default(),
+        Arc::new(DefaultSourceManager::default()),
+    )
+    .expect("failed to execute program");
+    assert_eq!(trace.stack_outputs(), &StackOutputs::default());
+}
+
+#[test]
+fn falcon_signer_debug_omits_secret_key() {
+    let seed = Word::default();
+    let mut rng = RpoRandomCoin::new(seed);
+    let sk = SecretKey::with_rng(&mut rng);
+    let pub_key: Word = sk.public_key().into();
+    let sk_elements: Vec<Felt> = sk.to_bytes().into_iter().map(Felt::from).collect();
+
+    let host = DefaultHost::default().with_falcon_signer(sk);
+    let debug = format!("{host:?}");
+    assert!(debug.contains(&format!("{pub_key:?}")));
+    let sk_prefix = format!("{:?}", &sk_elements[..8]);
+    assert!(!debug.contains(sk_prefix.trim_end_matches(']')));
+}
+
 #[test]
 fn falcon_prove_verify() {
     let sk = SecretKey::new();
@@ -239,7 +324,7 @@ fn falcon_prove_verify() {
     host.load_mast_forest(StdLibrary::default().mast_forest().clone())
         .expect("failed to load mast forest");
 
-    let options = ProvingOptions::with_96_bit_security(false);
+    let options = ProvingOptions::for_testing();
     let (stack_outputs, proof) = miden_utils_testing::prove(
         &program,
         stack_inputs.clone(),
@@ -251,7 +336,14 @@ fn falcon_prove_verify() {
     .expect("failed to generate proof");
 
     let program_info = ProgramInfo::from(program);
-    let result = miden_utils_testing::verify(program_info, stack_inputs, stack_outputs, proof);
+    let opts = AcceptableOptions::OptionSet(vec![ProvingOptions::TESTING]);
+    let result = miden_utils_testing::verify_with_options(
+        program_info,
+        stack_inputs,
+        stack_outputs,
+        proof,
+        &opts,
+    );
 
     assert!(result.is_ok(), "error: {result:?}");
 }
@@ -14,7 +14,7 @@ use miden_core::crypto::{
 };
 // EXPORTS
 // ================================================================================================
-pub use miden_core::{Kernel, ProgramInfo, StackInputs, StackOutputs, Word};
+pub use miden_core::{Kernel, ProgramInfo, ProgramInfoDiff, StackInputs, StackOutputs, Word};
 pub use winter_verifier::{AcceptableOptions, VerifierError};
 use winter_verifier::{crypto::MerkleTree, verify as verify_proof};
 pub mod math {
@@ -47,6 +47,9 @@ pub use miden_air::ExecutionProof;
 /// - 128-bit security level, non-recursive context (RPO hash function).
 /// - 128-bit security level, recursive context (RPO hash function).
 ///
+/// Use [verify_with_options] to accept proofs generated using other parameter sets, e.g., the
+/// ones of [ProvingOptions::for_testing].
+///
 /// # Errors
 /// Returns an error if:
 /// - The provided proof does not prove a correct execution of the program.
@@ -58,6 +61,45 @@ pub fn verify(
     stack_inputs: StackInputs,
     stack_outputs: StackOutputs,
     proof: ExecutionProof,
+) -> Result<u32, VerificationError> {
+    let opts = match proof.hash_fn() {
+        HashFunction::Blake3_192 => vec![ProvingOptions::REGULAR_96_BITS],
+        HashFunction::Blake3_256 => vec![ProvingOptions::REGULAR_128_BITS],
+        HashFunction::Rpo256 | HashFunction::Rpx256 => {
+            vec![ProvingOptions::RECURSIVE_96_BITS, ProvingOptions::RECURSIVE_128_BITS]
+        },
+    };
+
+    verify_with_options(
+        program_info,
+        stack_inputs,
+        stack_outputs,
+        proof,
+        &AcceptableOptions::OptionSet(opts),
+    )
+}
+
+/// Returns the security level of the proof if the specified program was executed correctly against
+/// the specified inputs and outputs, accepting only proofs generated using one of the
+/// `acceptable_options`.
+///
+/// This is the same as [verify], except that the set of acceptable parameters is provided by the
+/// caller instead of being derived from the hash function of the proof. This is mostly useful in
+/// tests, where proofs are generated using low-security parameters such as
+/// [ProvingOptions::TESTING].
+///
+/// # Errors
+/// Returns an error if:
+/// - The provided proof does not prove a correct execution of the program.
+/// - The protocol parameters used to generate the proof are not in the set of acceptable
+///   parameters.
+#[tracing::instrument("verify_program_with_options", skip_all)]
+pub fn verify_with_options(
+    program_info: ProgramInfo,
+    stack_inputs: StackInputs,
+    stack_outputs: StackOutputs,
+    proof: ExecutionProof,
+    acceptable_options: &AcceptableOptions,
 ) -> Result<u32, VerificationError> {
     // get security level of the proof
     let security_level = proof.security_level();
@@ -66,37 +108,24 @@ pub fn verify(
     // build public inputs and try to verify the proof
     let pub_inputs = PublicInputs::new(program_info, stack_inputs, stack_outputs);
     let (hash_fn, proof) = proof.into_parts();
+    let opts = acceptable_options;
     match hash_fn {
         HashFunction::Blake3_192 => {
-            let opts = AcceptableOptions::OptionSet(vec![ProvingOptions::REGULAR_96_BITS]);
             verify_proof::<ProcessorAir, Blake3_192, WinterRandomCoin<_>, MerkleTree<_>>(
-                proof, pub_inputs, &opts,
+                proof, pub_inputs, opts,
             )
         },
         HashFunction::Blake3_256 => {
-            let opts = AcceptableOptions::OptionSet(vec![ProvingOptions::REGULAR_128_BITS]);
             verify_proof::<ProcessorAir, Blake3_256, WinterRandomCoin<_>, MerkleTree<_>>(
-                proof, pub_inputs, &opts,
-            )
-        },
-        HashFunction::Rpo256 => {
-            let opts = AcceptableOptions::OptionSet(vec![
-                ProvingOptions::RECURSIVE_96_BITS,
-                ProvingOptions::RECURSIVE_128_BITS,
-            ]);
-            verify_proof::<ProcessorAir, Rpo256, RpoRandomCoin, MerkleTree<_>>(
-                proof, pub_inputs, &opts,
-            )
-        },
-        HashFunction::Rpx256 => {
-            let opts = AcceptableOptions::OptionSet(vec![
-                ProvingOptions::RECURSIVE_96_BITS,
-                ProvingOptions::RECURSIVE_128_BITS,
-            ]);
-            verify_proof::<ProcessorAir, Rpx256, RpxRandomCoin, MerkleTree<_>>(
-                proof, pub_inputs, &opts,
+                proof, pub_inputs, opts,
             )
         },
+        HashFunction::Rpo256 => verify_proof::<ProcessorAir, Rpo256, RpoRandomCoin, MerkleTree<_>>(
+            proof, pub_inputs, opts,
+        ),
+        HashFunction::Rpx256 => verify_proof::<ProcessorAir, Rpx256, RpxRandomCoin, MerkleTree<_>>(
+            proof, pub_inputs, opts,
+        ),
     }
     .map_err(|source| VerificationError::ProgramVerificationError(program_hash, source))?;
 